@@ -0,0 +1,172 @@
+use crate::rpc::NodeStatus;
+use std::env;
+use std::path::Path;
+
+pub mod rules;
+
+const CRITICAL_CONNECTIONS: u64 = 4;
+const WARNING_CONNECTIONS: u64 = 8;
+// Blöcke kommen im Schnitt alle 10 Minuten, eine Stunde ohne Block ist auffällig
+const STALE_TIP_WARNING_SECS: i64 = 60 * 60;
+const STALE_TIP_CRITICAL_SECS: i64 = 3 * 60 * 60;
+const CLOCK_OFFSET_WARNING_SECS: i64 = 30;
+const CLOCK_OFFSET_CRITICAL_SECS: i64 = 5 * 60;
+const DISK_WARNING_RATIO: f64 = 0.85;
+const DISK_CRITICAL_RATIO: f64 = 0.95;
+
+// Reihenfolge bestimmt die Sortierung: kritische Alerts zuerst
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Critical,
+    Warning,
+    Info,
+}
+
+impl Severity {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Severity::Critical => "KRITISCH",
+            Severity::Warning => "WARNUNG",
+            Severity::Info => "INFO",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Alert {
+    pub severity: Severity,
+    pub source: &'static str,
+    pub message: String,
+}
+
+impl Alert {
+    fn new(severity: Severity, source: &'static str, message: String) -> Self {
+        Self { severity, source, message }
+    }
+}
+
+/// Grundlage für die Speicherplatz-Warnung
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DiskSpace {
+    // Tatsächlich belegtes Dateisystem unter NODE_DATADIR (in Bytes)
+    Volume { available: u64, total: u64 },
+    // Nur Blockchain-Daten gegen NODE_DISK_CAPACITY_GB
+    Capacity(u64),
+}
+
+/// Freier und gesamter Platz des Dateisystems, auf dem `path` liegt
+#[cfg(unix)]
+fn volume_space(path: &Path) -> Option<DiskSpace> {
+    use std::os::unix::ffi::OsStrExt;
+    let path = std::ffi::CString::new(path.as_os_str().as_bytes()).ok()?;
+    // SAFETY: path ist nullterminiert und stat ein passend großer, beschreibbarer Puffer
+    let mut stat: libc::statvfs = unsafe { std::mem::zeroed() };
+    if unsafe { libc::statvfs(path.as_ptr(), &mut stat) } != 0 {
+        return None;
+    }
+    let block_size = stat.f_frsize as u64;
+    Some(DiskSpace::Volume {
+        available: stat.f_bavail as u64 * block_size,
+        total: stat.f_blocks as u64 * block_size,
+    })
+}
+
+#[cfg(not(unix))]
+fn volume_space(_path: &Path) -> Option<DiskSpace> {
+    None
+}
+
+/// NODE_DATADIR (nur bei lokalem Node) oder ersatzweise NODE_DISK_CAPACITY_GB
+fn disk_space() -> Option<DiskSpace> {
+    if let Some(datadir) = env::var("NODE_DATADIR").ok().filter(|v| !v.trim().is_empty()) {
+        let space = volume_space(Path::new(datadir.trim()));
+        if space.is_none() {
+            // Wird bei jedem Frame geprüft, daher nur einmal melden
+            static WARNED: std::sync::Once = std::sync::Once::new();
+            WARNED.call_once(|| log::warn!("Freier Speicher für {} nicht ermittelbar", datadir));
+        }
+        return space;
+    }
+    env::var("NODE_DISK_CAPACITY_GB")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .filter(|gb| *gb > 0)
+        .map(|gb| DiskSpace::Capacity(gb * 1_000_000_000))
+}
+
+/// Kombiniert die Warnungen des Nodes mit eigenen Prüfungen, nach Schwere sortiert
+pub fn collect_alerts(status: &NodeStatus, now: i64) -> Vec<Alert> {
+    collect_alerts_with_disk(status, now, disk_space())
+}
+
+fn collect_alerts_with_disk(status: &NodeStatus, now: i64, disk: Option<DiskSpace>) -> Vec<Alert> {
+    let mut alerts: Vec<Alert> = status.warnings.iter()
+        .map(|warning| Alert::new(Severity::Warning, "Node", warning.clone()))
+        .collect();
+
+    // Während des IBD ist ein alter Tip normal
+    let tip_age = now - status.timestamp;
+    if status.initial_block_download {
+        alerts.push(Alert::new(Severity::Info, "Sync", "Initial Block Download läuft".to_string()));
+    } else if tip_age >= STALE_TIP_WARNING_SECS {
+        let severity = if tip_age >= STALE_TIP_CRITICAL_SECS { Severity::Critical } else { Severity::Warning };
+        alerts.push(Alert::new(
+            severity,
+            "Chain",
+            format!("Letzter Block vor {} Minuten - Tip veraltet?", tip_age / 60),
+        ));
+    }
+
+    if status.connections < CRITICAL_CONNECTIONS {
+        alerts.push(Alert::new(
+            Severity::Critical,
+            "Peers",
+            format!("Nur {} Verbindungen - Gefahr von Eclipse-Angriffen", status.connections),
+        ));
+    } else if status.connections < WARNING_CONNECTIONS {
+        alerts.push(Alert::new(
+            Severity::Warning,
+            "Peers",
+            format!("Wenige Verbindungen ({})", status.connections),
+        ));
+    }
+
+    let offset = status.time_offset.abs();
+    if offset >= CLOCK_OFFSET_WARNING_SECS {
+        let severity = if offset >= CLOCK_OFFSET_CRITICAL_SECS { Severity::Critical } else { Severity::Warning };
+        alerts.push(Alert::new(
+            severity,
+            "Uhrzeit",
+            format!("Systemuhr weicht um {} Sekunden von den Peers ab", status.time_offset),
+        ));
+    }
+
+    let disk_usage = match disk {
+        Some(DiskSpace::Volume { available, total }) if total > 0 => Some((
+            total.saturating_sub(available) as f64 / total as f64,
+            format!("{:.1} GB frei", available as f64 / 1_000_000_000.0),
+        )),
+        Some(DiskSpace::Capacity(capacity)) => Some((
+            status.size_on_disk as f64 / capacity as f64,
+            "mit Blockchain-Daten".to_string(),
+        )),
+        _ => None,
+    };
+    if let Some((ratio, detail)) = disk_usage {
+        if ratio >= DISK_WARNING_RATIO {
+            let severity = if ratio >= DISK_CRITICAL_RATIO { Severity::Critical } else { Severity::Warning };
+            alerts.push(Alert::new(
+                severity,
+                "Speicher",
+                format!("Laufwerk zu {:.0}% belegt ({})", ratio * 100.0, detail),
+            ));
+        }
+    }
+
+    // Stabil sortieren, damit die Reihenfolge innerhalb einer Stufe erhalten bleibt
+    alerts.sort_by_key(|alert| alert.severity);
+    alerts
+}
+
+#[cfg(test)]
+mod tests;
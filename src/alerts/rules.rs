@@ -0,0 +1,423 @@
+// Benutzerdefinierte Alert-Regeln mit Webhook-, Befehls- und Banner-Aktionen
+use super::{Alert, Severity};
+use crate::rpc::{MempoolStats, NodeStatus};
+use serde_json::json;
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+use std::time::Duration;
+
+const DEFAULT_RULES_FILE: &str = "alert_rules.txt";
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Metric {
+    Connections,
+    PeersInbound,
+    PeersOutbound,
+    Height,
+    Headers,
+    BlockAgeMinutes,
+    VerificationProgress,
+    MempoolTransactions,
+    MempoolMegabytes,
+    MempoolMinFee,
+    TimeOffset,
+    Warnings,
+}
+
+impl Metric {
+    const ALL: [Metric; 12] = [
+        Metric::Connections,
+        Metric::PeersInbound,
+        Metric::PeersOutbound,
+        Metric::Height,
+        Metric::Headers,
+        Metric::BlockAgeMinutes,
+        Metric::VerificationProgress,
+        Metric::MempoolTransactions,
+        Metric::MempoolMegabytes,
+        Metric::MempoolMinFee,
+        Metric::TimeOffset,
+        Metric::Warnings,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Metric::Connections => "connections",
+            Metric::PeersInbound => "peers_inbound",
+            Metric::PeersOutbound => "peers_outbound",
+            Metric::Height => "height",
+            Metric::Headers => "headers",
+            Metric::BlockAgeMinutes => "block_age_min",
+            Metric::VerificationProgress => "verification_progress",
+            Metric::MempoolTransactions => "mempool_tx",
+            Metric::MempoolMegabytes => "mempool_mb",
+            Metric::MempoolMinFee => "mempool_min_fee",
+            Metric::TimeOffset => "time_offset",
+            Metric::Warnings => "warnings",
+        }
+    }
+
+    fn parse(name: &str) -> Option<Self> {
+        Metric::ALL.into_iter().find(|metric| metric.name() == name)
+    }
+}
+
+/// Messwerte einer Aktualisierung; Mempool-Werte fehlen, wenn die Abfrage scheitert
+pub fn sample_values(status: &NodeStatus, mempool: Option<&MempoolStats>, now: i64) -> HashMap<Metric, f64> {
+    let inbound = status.peers.iter().filter(|peer| peer.inbound).count();
+    let mut values = HashMap::from([
+        (Metric::Connections, status.connections as f64),
+        (Metric::PeersInbound, inbound as f64),
+        (Metric::PeersOutbound, (status.peers.len() - inbound) as f64),
+        (Metric::Height, status.height as f64),
+        (Metric::Headers, status.headers as f64),
+        (Metric::BlockAgeMinutes, (now - status.timestamp) as f64 / 60.0),
+        (Metric::VerificationProgress, status.verification_progress),
+        (Metric::MempoolTransactions, status.mempool_size as f64),
+        (Metric::TimeOffset, status.time_offset as f64),
+        (Metric::Warnings, status.warnings.len() as f64),
+    ]);
+    // Größe vom eigenen Node, damit die Regel auch ohne mempool.space funktioniert
+    values.insert(Metric::MempoolMegabytes, status.mempool_info.bytes as f64 / 1_000_000.0);
+    if let Some(mempool) = mempool {
+        values.insert(Metric::MempoolMinFee, mempool.min_fee);
+    }
+    values
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Condition {
+    Below(f64),
+    AtMost(f64),
+    Above(f64),
+    AtLeast(f64),
+    Equals(f64),
+    // Wert ist seit der letzten Aktualisierung gesunken
+    Dropped,
+}
+
+impl Condition {
+    fn matches(&self, value: f64, previous: Option<f64>) -> bool {
+        match self {
+            Condition::Below(threshold) => value < *threshold,
+            Condition::AtMost(threshold) => value <= *threshold,
+            Condition::Above(threshold) => value > *threshold,
+            Condition::AtLeast(threshold) => value >= *threshold,
+            Condition::Equals(threshold) => (value - threshold).abs() < f64::EPSILON,
+            Condition::Dropped => previous.is_some_and(|previous| value < previous),
+        }
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            Condition::Below(threshold) => format!("< {}", threshold),
+            Condition::AtMost(threshold) => format!("<= {}", threshold),
+            Condition::Above(threshold) => format!("> {}", threshold),
+            Condition::AtLeast(threshold) => format!(">= {}", threshold),
+            Condition::Equals(threshold) => format!("== {}", threshold),
+            Condition::Dropped => "gesunken".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Action {
+    Banner,
+    Webhook(String),
+    Command(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rule {
+    pub name: String,
+    pub metric: Metric,
+    pub condition: Condition,
+    // Wie lange die Bedingung ununterbrochen gelten muss
+    pub duration: Duration,
+    // Mindestabstand zwischen zwei Auslösungen derselben Regel
+    pub cooldown: Duration,
+    pub actions: Vec<Action>,
+}
+
+/// Liest eine Regel der Form
+/// `name: metrik op wert [for 5 min] [cooldown 30 min] -> banner, webhook URL, command BEFEHL`
+pub fn parse_rule(line: &str) -> Result<Rule, String> {
+    let (name, rest) = line.split_once(':').ok_or("Regelname fehlt (name: ...)")?;
+    let (expression, actions) = rest.split_once("->").ok_or("Aktionen fehlen (-> banner, ...)")?;
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("Regelname fehlt".to_string());
+    }
+
+    let tokens: Vec<&str> = expression.split_whitespace().collect();
+    let metric_name = tokens.first().ok_or("Metrik fehlt")?;
+    let metric = Metric::parse(metric_name).ok_or_else(|| format!("Unbekannte Metrik: {}", metric_name))?;
+
+    let (condition, mut index) = match tokens.get(1).copied() {
+        Some("dropped") => (Condition::Dropped, 2),
+        Some(op) => {
+            let value = tokens.get(2)
+                .and_then(|value| value.parse::<f64>().ok())
+                .ok_or_else(|| format!("Ungültiger Vergleichswert nach {}", op))?;
+            let condition = match op {
+                "<" => Condition::Below(value),
+                "<=" => Condition::AtMost(value),
+                ">" => Condition::Above(value),
+                ">=" => Condition::AtLeast(value),
+                "==" => Condition::Equals(value),
+                _ => return Err(format!("Unbekannter Operator: {}", op)),
+            };
+            (condition, 3)
+        }
+        None => return Err("Bedingung fehlt".to_string()),
+    };
+
+    let mut duration = Duration::ZERO;
+    let mut cooldown = Duration::ZERO;
+    while let Some(&keyword) = tokens.get(index) {
+        if keyword != "for" && keyword != "cooldown" {
+            return Err(format!("Unerwartet: {}", keyword));
+        }
+        let (value, used) = parse_duration(&tokens[index + 1..])
+            .ok_or_else(|| format!("Ungültige Dauer nach {}", keyword))?;
+        if keyword == "for" {
+            duration = value;
+        } else {
+            cooldown = value;
+        }
+        index += 1 + used;
+    }
+
+    let actions = actions.split(',')
+        .map(|action| parse_action(action.trim()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Rule { name: name.to_string(), metric, condition, duration, cooldown, actions })
+}
+
+// "5m", "5min" oder "5 min"; liefert die Dauer und die Anzahl verbrauchter Tokens
+fn parse_duration(tokens: &[&str]) -> Option<(Duration, usize)> {
+    let first = tokens.first()?;
+    let split = first.find(|c: char| !c.is_ascii_digit()).unwrap_or(first.len());
+    let amount: u64 = first[..split].parse().ok()?;
+    let (unit, used) = if split < first.len() {
+        (&first[split..], 1)
+    } else {
+        (*tokens.get(1)?, 2)
+    };
+
+    let seconds = match unit {
+        "s" | "sec" => 1,
+        "m" | "min" => 60,
+        "h" => 3600,
+        _ => return None,
+    };
+    Some((Duration::from_secs(amount * seconds), used))
+}
+
+fn parse_action(action: &str) -> Result<Action, String> {
+    let (kind, argument) = action.split_once(char::is_whitespace).unwrap_or((action, ""));
+    let argument = argument.trim();
+    match kind {
+        "banner" => Ok(Action::Banner),
+        "webhook" if !argument.is_empty() => Ok(Action::Webhook(argument.to_string())),
+        "command" if !argument.is_empty() => Ok(Action::Command(argument.to_string())),
+        "webhook" | "command" => Err(format!("{} braucht ein Ziel", kind)),
+        _ => Err(format!("Unbekannte Aktion: {}", action)),
+    }
+}
+
+/// Alle Regeln einer Datei; Leerzeilen und #-Kommentare werden übersprungen
+pub fn parse_rules(content: &str) -> Result<Vec<Rule>, String> {
+    content.lines()
+        .enumerate()
+        .map(|(index, line)| (index, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(index, line)| parse_rule(line).map_err(|e| format!("Zeile {}: {}", index + 1, e)))
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NotificationKind {
+    Fired,
+    Resolved,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Notification {
+    pub rule: String,
+    pub kind: NotificationKind,
+    pub message: String,
+    pub value: f64,
+    pub actions: Vec<Action>,
+}
+
+impl Notification {
+    pub fn wants_banner(&self) -> bool {
+        self.actions.contains(&Action::Banner)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct RuleState {
+    // Seit wann die Bedingung ununterbrochen gilt (Unix-Sekunden)
+    pending_since: Option<i64>,
+    firing: bool,
+    last_fired: Option<i64>,
+    previous: Option<f64>,
+}
+
+pub struct AlertEngine {
+    rules: Vec<Rule>,
+    states: Vec<RuleState>,
+}
+
+impl AlertEngine {
+    pub fn new(rules: Vec<Rule>) -> Self {
+        let states = vec![RuleState::default(); rules.len()];
+        Self { rules, states }
+    }
+
+    /// Lädt ALERT_RULES_FILE (Standard: alert_rules.txt); ohne Datei gibt es keine Regeln
+    pub fn load() -> Result<Self, String> {
+        let path = PathBuf::from(env::var("ALERT_RULES_FILE").unwrap_or_else(|_| DEFAULT_RULES_FILE.to_string()));
+        match fs::read_to_string(&path) {
+            Ok(content) => parse_rules(&content)
+                .map(Self::new)
+                .map_err(|e| format!("{}: {}", path.display(), e)),
+            Err(_) => Ok(Self::new(Vec::new())),
+        }
+    }
+
+    /// Aktuell ausgelöste Regeln mit Banner-Aktion
+    pub fn firing_banners(&self) -> Vec<&Rule> {
+        self.rules.iter()
+            .zip(&self.states)
+            .filter(|(rule, state)| state.firing && rule.actions.contains(&Action::Banner))
+            .map(|(rule, _)| rule)
+            .collect()
+    }
+
+    /// Prüft alle Regeln gegen neue Messwerte und liefert Auslösungen und Entwarnungen
+    pub fn evaluate(&mut self, values: &HashMap<Metric, f64>, now: i64) -> Vec<Notification> {
+        let mut notifications = Vec::new();
+
+        for (rule, state) in self.rules.iter().zip(self.states.iter_mut()) {
+            // Fehlende Werte (z.B. Mempool nicht abrufbar) ändern den Zustand nicht
+            let Some(&value) = values.get(&rule.metric) else { continue };
+            let active = rule.condition.matches(value, state.previous);
+            state.previous = Some(value);
+
+            if !active {
+                state.pending_since = None;
+                if state.firing {
+                    state.firing = false;
+                    notifications.push(rule.notification(NotificationKind::Resolved, value));
+                }
+                continue;
+            }
+
+            let since = *state.pending_since.get_or_insert(now);
+            let held = now - since >= rule.duration.as_secs() as i64;
+            let cooled = state.last_fired
+                .is_none_or(|last| now - last >= rule.cooldown.as_secs() as i64);
+            if !state.firing && held && cooled {
+                state.firing = true;
+                state.last_fired = Some(now);
+                notifications.push(rule.notification(NotificationKind::Fired, value));
+            }
+        }
+
+        notifications
+    }
+}
+
+impl Rule {
+    pub fn describe(&self) -> String {
+        let mut text = format!("{} {}", self.metric.name(), self.condition.describe());
+        let secs = self.duration.as_secs();
+        if secs >= 60 {
+            text.push_str(&format!(" seit {} min", secs / 60));
+        } else if secs > 0 {
+            text.push_str(&format!(" seit {} s", secs));
+        }
+        text
+    }
+
+    /// Dauerhafte Anzeige im Dashboard, solange die Regel ausgelöst ist
+    pub fn alert(&self) -> Alert {
+        Alert::new(Severity::Warning, "Regel", format!("{}: {}", self.name, self.describe()))
+    }
+
+    fn notification(&self, kind: NotificationKind, value: f64) -> Notification {
+        let message = match kind {
+            NotificationKind::Fired => format!("🔔 Regel {}: {} (aktuell {:.2})", self.name, self.describe(), value),
+            NotificationKind::Resolved => format!("✅ Regel {} aufgehoben (aktuell {:.2})", self.name, value),
+        };
+        Notification {
+            rule: self.name.clone(),
+            kind,
+            message,
+            value,
+            actions: self.actions.clone(),
+        }
+    }
+}
+
+/// Führt Webhook- und Befehlsaktionen im Hintergrund aus; Banner übernimmt die TUI
+pub fn dispatch(notification: &Notification) {
+    let state = match notification.kind {
+        NotificationKind::Fired => "firing",
+        NotificationKind::Resolved => "resolved",
+    };
+
+    for action in &notification.actions {
+        let notification = notification.clone();
+        match action.clone() {
+            Action::Banner => {}
+            Action::Webhook(url) => {
+                std::thread::spawn(move || {
+                    let payload = json!({
+                        "rule": notification.rule,
+                        "state": state,
+                        "message": notification.message,
+                        "value": notification.value,
+                        "timestamp": chrono::Utc::now().timestamp(),
+                    });
+                    let result = reqwest::blocking::Client::builder()
+                        .timeout(WEBHOOK_TIMEOUT)
+                        .build()
+                        .and_then(|client| client.post(&url).json(&payload).send())
+                        .and_then(|response| response.error_for_status());
+                    if let Err(e) = result {
+                        log::warn!("Webhook {} fehlgeschlagen: {}", url, e);
+                    }
+                });
+            }
+            Action::Command(command) => {
+                std::thread::spawn(move || {
+                    let result = Command::new("sh")
+                        .arg("-c")
+                        .arg(&command)
+                        .env("NODEBRIDGE_ALERT_RULE", &notification.rule)
+                        .env("NODEBRIDGE_ALERT_STATE", state)
+                        .env("NODEBRIDGE_ALERT_MESSAGE", &notification.message)
+                        .env("NODEBRIDGE_ALERT_VALUE", notification.value.to_string())
+                        .status();
+                    match result {
+                        Ok(status) if !status.success() => log::warn!("Befehl {} endete mit {}", command, status),
+                        Err(e) => log::warn!("Befehl {} fehlgeschlagen: {}", command, e),
+                        Ok(_) => {}
+                    }
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,114 @@
+use super::*;
+
+fn values(metric: Metric, value: f64) -> HashMap<Metric, f64> {
+    HashMap::from([(metric, value)])
+}
+
+#[test]
+fn parses_rule_with_duration_cooldown_and_actions() {
+    let rule = parse_rule(
+        "peers_low: connections < 4 for 5 min cooldown 30m -> banner, webhook http://127.0.0.1:9000/hook, command notify-send NodeBridge"
+    ).unwrap();
+    assert_eq!(rule.name, "peers_low");
+    assert_eq!(rule.metric, Metric::Connections);
+    assert_eq!(rule.condition, Condition::Below(4.0));
+    assert_eq!(rule.duration, Duration::from_secs(300));
+    assert_eq!(rule.cooldown, Duration::from_secs(1800));
+    assert_eq!(rule.actions, vec![
+        Action::Banner,
+        Action::Webhook("http://127.0.0.1:9000/hook".to_string()),
+        Action::Command("notify-send NodeBridge".to_string()),
+    ]);
+
+    let rule = parse_rule("sync: verification_progress dropped -> banner").unwrap();
+    assert_eq!(rule.condition, Condition::Dropped);
+    assert_eq!(rule.duration, Duration::ZERO);
+}
+
+#[test]
+fn reports_invalid_lines_with_line_number() {
+    let content = "# Kommentar\n\nok: mempool_mb > 300 -> banner\nkaputt: mempool_mb ~ 300 -> banner\n";
+    assert_eq!(parse_rules(content).unwrap_err(), "Zeile 4: Unbekannter Operator: ~");
+
+    assert!(parse_rule("x: unbekannt > 1 -> banner").is_err());
+    assert!(parse_rule("x: connections > 1 for 5 jahre -> banner").is_err());
+    assert!(parse_rule("x: connections > 1 -> webhook").is_err());
+    assert!(parse_rule("x: connections > 1 banner").is_err());
+}
+
+#[test]
+fn fires_after_duration_and_resolves() {
+    let rule = parse_rule("peers_low: connections < 4 for 5m -> banner").unwrap();
+    let mut engine = AlertEngine::new(vec![rule]);
+
+    assert!(engine.evaluate(&values(Metric::Connections, 2.0), 0).is_empty());
+    assert!(engine.evaluate(&values(Metric::Connections, 2.0), 240).is_empty());
+
+    let fired = engine.evaluate(&values(Metric::Connections, 3.0), 300);
+    assert_eq!(fired.len(), 1);
+    assert_eq!(fired[0].kind, NotificationKind::Fired);
+    assert!(fired[0].wants_banner());
+    assert_eq!(engine.firing_banners().len(), 1);
+
+    // Solange die Bedingung gilt, wird nicht erneut gemeldet
+    assert!(engine.evaluate(&values(Metric::Connections, 3.0), 600).is_empty());
+
+    let resolved = engine.evaluate(&values(Metric::Connections, 9.0), 660);
+    assert_eq!(resolved.len(), 1);
+    assert_eq!(resolved[0].kind, NotificationKind::Resolved);
+    assert!(engine.firing_banners().is_empty());
+}
+
+#[test]
+fn interruption_restarts_duration() {
+    let rule = parse_rule("stale: block_age_min > 60 for 10m -> banner").unwrap();
+    let mut engine = AlertEngine::new(vec![rule]);
+
+    engine.evaluate(&values(Metric::BlockAgeMinutes, 61.0), 0);
+    engine.evaluate(&values(Metric::BlockAgeMinutes, 1.0), 300);
+    assert!(engine.evaluate(&values(Metric::BlockAgeMinutes, 61.0), 600).is_empty());
+    assert_eq!(engine.evaluate(&values(Metric::BlockAgeMinutes, 71.0), 1200).len(), 1);
+}
+
+#[test]
+fn cooldown_suppresses_flapping() {
+    let rule = parse_rule("mempool: mempool_mb > 300 cooldown 30m -> banner").unwrap();
+    let mut engine = AlertEngine::new(vec![rule]);
+
+    assert_eq!(engine.evaluate(&values(Metric::MempoolMegabytes, 310.0), 0).len(), 1);
+    assert_eq!(engine.evaluate(&values(Metric::MempoolMegabytes, 290.0), 60).len(), 1);
+    assert!(engine.evaluate(&values(Metric::MempoolMegabytes, 310.0), 120).is_empty());
+    assert_eq!(engine.evaluate(&values(Metric::MempoolMegabytes, 310.0), 1800).len(), 1);
+}
+
+#[test]
+fn dropped_compares_with_previous_value() {
+    let rule = parse_rule("sync: verification_progress dropped -> banner").unwrap();
+    let mut engine = AlertEngine::new(vec![rule]);
+
+    assert!(engine.evaluate(&values(Metric::VerificationProgress, 0.5), 0).is_empty());
+    assert!(engine.evaluate(&values(Metric::VerificationProgress, 0.6), 10).is_empty());
+    assert_eq!(engine.evaluate(&values(Metric::VerificationProgress, 0.4), 20)[0].kind, NotificationKind::Fired);
+
+    // Fehlende Werte lassen den Zustand unverändert
+    assert!(engine.evaluate(&HashMap::new(), 30).is_empty());
+    assert_eq!(engine.evaluate(&values(Metric::VerificationProgress, 0.45), 40)[0].kind, NotificationKind::Resolved);
+}
+
+#[test]
+fn example_file_is_valid() {
+    let rules = parse_rules(include_str!("../../../alert_rules.example.txt")).unwrap();
+    assert_eq!(rules.len(), 4);
+}
+
+#[test]
+fn mempool_size_without_external_stats() {
+    let status = NodeStatus {
+        mempool_info: crate::rpc::MempoolInfo { size: 50_000, bytes: 150_000_000 },
+        ..Default::default()
+    };
+    // Ohne mempool.space fehlt nur die Mindest-Feerate
+    let values = sample_values(&status, None, 0);
+    assert_eq!(values.get(&Metric::MempoolMegabytes), Some(&150.0));
+    assert!(!values.contains_key(&Metric::MempoolMinFee));
+}
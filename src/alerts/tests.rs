@@ -0,0 +1,80 @@
+use super::*;
+
+const NOW: i64 = 1_700_000_000;
+
+fn healthy_status() -> NodeStatus {
+    NodeStatus {
+        version: 270000,
+        height: 820_000,
+        timestamp: NOW - 120,
+        connections: 10,
+        verification_progress: 1.0,
+        network: "main".to_string(),
+        headers: 820_000,
+        size_on_disk: 600_000_000_000,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn healthy_node_has_no_alerts() {
+    assert!(collect_alerts_with_disk(&healthy_status(), NOW, Some(DiskSpace::Capacity(2_000_000_000_000))).is_empty());
+}
+
+#[test]
+fn alerts_are_sorted_by_severity() {
+    let mut status = healthy_status();
+    status.warnings = vec!["This is a pre-release test build".to_string()];
+    status.connections = 2;
+    status.time_offset = -45;
+
+    let alerts = collect_alerts_with_disk(&status, NOW, None);
+    let severities: Vec<Severity> = alerts.iter().map(|a| a.severity).collect();
+
+    assert_eq!(severities, vec![Severity::Critical, Severity::Warning, Severity::Warning]);
+    assert_eq!(alerts[0].source, "Peers");
+    assert_eq!(alerts[1].source, "Node");
+}
+
+#[test]
+fn stale_tip_is_ignored_during_ibd() {
+    let mut status = healthy_status();
+    status.timestamp = NOW - 4 * 3600;
+    assert_eq!(collect_alerts_with_disk(&status, NOW, None)[0].severity, Severity::Critical);
+
+    status.initial_block_download = true;
+    let alerts = collect_alerts_with_disk(&status, NOW, None);
+    assert_eq!(alerts.len(), 1);
+    assert_eq!(alerts[0].severity, Severity::Info);
+}
+
+#[test]
+fn nearly_full_disk() {
+    let alerts = collect_alerts_with_disk(&healthy_status(), NOW, Some(DiskSpace::Capacity(650_000_000_000)));
+    assert_eq!(alerts.len(), 1);
+    assert_eq!(alerts[0].source, "Speicher");
+    assert_eq!(alerts[0].severity, Severity::Warning);
+}
+
+#[test]
+fn full_volume_counts_other_data() {
+    // Blockchain-Daten allein wären unauffällig, das Laufwerk ist aber fast voll
+    let disk = DiskSpace::Volume { available: 40_000_000_000, total: 1_000_000_000_000 };
+    let alerts = collect_alerts_with_disk(&healthy_status(), NOW, Some(disk));
+    assert_eq!(alerts.len(), 1);
+    assert_eq!(alerts[0].severity, Severity::Critical);
+    assert!(alerts[0].message.contains("40.0 GB frei"));
+
+    let disk = DiskSpace::Volume { available: 500_000_000_000, total: 1_000_000_000_000 };
+    assert!(collect_alerts_with_disk(&healthy_status(), NOW, Some(disk)).is_empty());
+}
+
+#[cfg(unix)]
+#[test]
+fn reads_volume_space() {
+    let Some(DiskSpace::Volume { available, total }) = volume_space(Path::new("/")) else {
+        panic!("statvfs für / fehlgeschlagen");
+    };
+    assert!(total > 0 && available <= total);
+    assert_eq!(volume_space(Path::new("/gibt/es/nicht")), None);
+}
@@ -0,0 +1,222 @@
+use anyhow::Result;
+use chrono::{TimeZone, Utc};
+use clap::{Parser, Subcommand};
+use crate::metrics::{self, MetricsConfig};
+use crate::rpc::{AddressDetails, BitcoinRPC, BlockDetails, MempoolStats, NodeStatus, PeerInfo, Transaction};
+use num_format::{Locale, ToFormattedString};
+use serde::Serialize;
+
+// 9332 liegt neben den Bitcoin-Core-Ports und ist in Prometheus-Setups üblich
+const DEFAULT_METRICS_ADDR: &str = "127.0.0.1:9332";
+
+/// Bitcoin Node Terminal UI - ohne Unterbefehl startet die interaktive Oberfläche
+#[derive(Debug, Parser)]
+#[command(name = "nodebridge", version)]
+pub struct Cli {
+    /// Ausgabe als JSON für Skripte und Cronjobs
+    #[arg(long, global = true)]
+    pub json: bool,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Debug, Clone, PartialEq, Subcommand)]
+pub enum Command {
+    /// Node-Status: Blockhöhe, Sync, Peers und Warnungen
+    Status,
+    /// Block nach Höhe oder Hash (ohne Angabe: aktueller Block)
+    Block { id: Option<String> },
+    /// Transaktion nach TX-ID
+    Tx { txid: String },
+    /// Adressdetails (über mempool.space)
+    Address { address: String },
+    /// Verbundene Peers
+    Peers,
+    /// Mempool-Statistik und Gebühren
+    Mempool,
+    /// Prometheus-Metriken per HTTP bereitstellen (ohne TUI)
+    Metrics {
+        /// Adresse des Endpunkts, z.B. 0.0.0.0:9332 (Standard: METRICS_ADDR)
+        #[arg(long)]
+        listen: Option<String>,
+    },
+}
+
+/// Führt einen Unterbefehl aus und schreibt das Ergebnis auf stdout
+pub fn run(command: Command, json: bool) -> Result<()> {
+    // Lädt auch die .env, bevor METRICS_ADDR gelesen wird
+    let client = BitcoinRPC::new()?;
+
+    let output = match command {
+        Command::Status => output(&client.test_connection()?, json, format_status)?,
+        Command::Block { id } => {
+            let id = match id {
+                Some(id) => id,
+                None => client.test_connection()?.height.to_string(),
+            };
+            output(&client.get_block_details(&id)?, json, format_block)?
+        }
+        Command::Tx { txid } => output(&client.get_raw_transaction(&txid)?, json, format_transaction)?,
+        Command::Address { address } => {
+            let details = client.get_address_details(&address)?;
+            output(&details, json, |details| format_address(&address, details))?
+        }
+        Command::Peers => output(&client.get_peer_info()?, json, |peers| format_peers(peers))?,
+        Command::Mempool => output(&client.get_mempool_stats()?, json, format_mempool)?,
+        Command::Metrics { listen } => {
+            let config = match listen {
+                Some(addr) => MetricsConfig::with_addr(addr),
+                None => MetricsConfig::from_env()
+                    .unwrap_or_else(|| MetricsConfig::with_addr(DEFAULT_METRICS_ADDR.to_string())),
+            };
+            return metrics::serve(config);
+        }
+    };
+
+    println!("{}", output);
+    Ok(())
+}
+
+fn output<T: Serialize + ?Sized>(value: &T, json: bool, text: impl Fn(&T) -> String) -> Result<String> {
+    if json {
+        Ok(serde_json::to_string_pretty(value)?)
+    } else {
+        Ok(text(value))
+    }
+}
+
+fn format_time(timestamp: i64) -> String {
+    Utc.timestamp_opt(timestamp, 0)
+        .single()
+        .map(|time| time.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+        .unwrap_or_else(|| "-".to_string())
+}
+
+fn yes_no(value: bool) -> &'static str {
+    if value { "Ja" } else { "Nein" }
+}
+
+pub fn format_status(status: &NodeStatus) -> String {
+    let mut lines = vec![
+        format!("Version:        {}.{}.{}", status.version / 10000, (status.version % 10000) / 100, status.version % 100),
+        format!("Netzwerk:       {}", status.network),
+        format!(
+            "Blöcke:         {} (Headers: {})",
+            status.height.to_formatted_string(&Locale::de),
+            status.headers.to_formatted_string(&Locale::de)
+        ),
+        format!("Bester Block:   {}", status.block_hash),
+        format!("Blockzeit:      {}", format_time(status.timestamp)),
+        format!("Verifikation:   {:.2}%", status.verification_progress * 100.0),
+        format!("IBD:            {}", yes_no(status.initial_block_download)),
+        format!("Peers:          {}", status.connections),
+        format!("Mempool:        {} TXs", status.mempool_size.to_formatted_string(&Locale::de)),
+        format!("Speicher:       {:.2} GB (Pruned: {})", status.size_on_disk as f64 / 1e9, yes_no(status.pruned)),
+    ];
+    lines.extend(status.warnings.iter().map(|warning| format!("Warnung:        {}", warning)));
+    lines.join("\n")
+}
+
+pub fn format_block(block: &BlockDetails) -> String {
+    [
+        format!("Höhe:           {}", block.height.to_formatted_string(&Locale::de)),
+        format!("Hash:           {}", block.hash),
+        format!("Zeit:           {}", format_time(block.timestamp)),
+        format!("Pool:           {}", block.pool.as_deref().unwrap_or("Unbekannt")),
+        format!("Transaktionen:  {}", block.tx_count.to_formatted_string(&Locale::de)),
+        format!("Größe:          {} Bytes", block.size.to_formatted_string(&Locale::de)),
+        format!("Weight:         {} WU", block.weight.to_formatted_string(&Locale::de)),
+        format!("Version:        {:#x}", block.version),
+        format!("Merkle Root:    {}", block.merkle_root),
+        format!("Bits:           {}", block.bits),
+        format!("Nonce:          {}", block.nonce),
+    ].join("\n")
+}
+
+pub fn format_transaction(tx: &Transaction) -> String {
+    let mut lines = vec![
+        format!("TX-ID:          {}", tx.txid),
+        format!("Größe:          {} Bytes ({} vBytes)", tx.size, tx.weight.div_ceil(4)),
+        match (&tx.blockhash, tx.blocktime) {
+            (Some(hash), Some(time)) => format!("Bestätigt:      {} in {}", format_time(time as i64), hash),
+            _ => "Bestätigt:      Nein (Mempool)".to_string(),
+        },
+        format!("Eingänge ({}):", tx.vin.len()),
+    ];
+    lines.extend(tx.vin.iter().map(|input| format!(
+        "  {:.8} BTC  {}",
+        input.get("value").and_then(|v| v.as_f64()).unwrap_or(0.0),
+        input.get("address").and_then(|v| v.as_str()).unwrap_or("Unbekannte Adresse")
+    )));
+    lines.push(format!("Ausgänge ({}):", tx.vout.len()));
+    lines.extend(tx.vout.iter().map(|output| format!(
+        "  {:.8} BTC  {}",
+        output.get("value").and_then(|v| v.as_f64()).unwrap_or(0.0),
+        output.get("scriptPubKey")
+            .and_then(|script| script.get("address"))
+            .and_then(|v| v.as_str())
+            .unwrap_or("Unbekannte Adresse")
+    )));
+    lines.join("\n")
+}
+
+pub fn format_address(address: &str, details: &AddressDetails) -> String {
+    [
+        format!("Adresse:        {} ({})", address, details.address_type),
+        format!("Guthaben:       {:.8} BTC", details.balance),
+        format!("Empfangen:      {:.8} BTC", details.received),
+        format!("Gesendet:       {:.8} BTC", details.sent),
+        format!("Transaktionen:  {}", details.tx_count),
+        format!(
+            "UTXOs:          {} offen / {} ausgegeben",
+            details.unspent_txo_count, details.spent_txo_count
+        ),
+        format!("Im Mempool:     {}", yes_no(details.has_mempool_tx)),
+    ].join("\n")
+}
+
+pub fn format_peers(peers: &[PeerInfo]) -> String {
+    let mut lines = vec![format!(
+        "{:<40} {:<8} {:>9} {:>12} {:>12}  {}",
+        "Adresse", "Richtung", "Ping ms", "Gesendet", "Empfangen", "Client"
+    )];
+    lines.extend(peers.iter().map(|peer| format!(
+        "{:<40} {:<8} {:>9.1} {:>12} {:>12}  {}",
+        peer.addr,
+        if peer.inbound { "ein" } else { "aus" },
+        peer.latency * 1000.0,
+        peer.bytes_sent.to_formatted_string(&Locale::de),
+        peer.bytes_recv.to_formatted_string(&Locale::de),
+        peer.subver
+    )));
+    lines.push(format!("{} Peers", peers.len()));
+    lines.join("\n")
+}
+
+pub fn format_mempool(stats: &MempoolStats) -> String {
+    let mut lines = vec![
+        format!("Transaktionen:  {}", stats.tx_count.to_formatted_string(&Locale::de)),
+        format!("Größe:          {:.2} MB", stats.size as f64 / 1_000_000.0),
+        format!("Gebühren:       {:.8} BTC", stats.total_fee),
+        format!("Mindest-Fee:    {:.2} sat/vB", stats.min_fee),
+    ];
+    for (label, category) in [
+        ("Keine Priorität", &stats.no_priority),
+        ("Niedrig", &stats.low_priority),
+        ("Mittel", &stats.medium_priority),
+        ("Hoch", &stats.high_priority),
+    ] {
+        lines.push(format!(
+            "{:<16}{:>6.1} sat/vB  ({} TXs, ~{:.2} USD)",
+            format!("{}:", label),
+            category.rate,
+            category.count.to_formatted_string(&Locale::de),
+            category.usd_price
+        ));
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests;
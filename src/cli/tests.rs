@@ -0,0 +1,94 @@
+use super::*;
+use clap::Parser;
+use serde_json::json;
+
+#[test]
+fn parses_subcommands_and_json_flag() {
+    let cli = Cli::try_parse_from(["nodebridge", "block", "840000", "--json"]).unwrap();
+    assert!(cli.json);
+    assert_eq!(cli.command, Some(Command::Block { id: Some("840000".to_string()) }));
+
+    let cli = Cli::try_parse_from(["nodebridge", "--json", "peers"]).unwrap();
+    assert!(cli.json);
+    assert_eq!(cli.command, Some(Command::Peers));
+
+    let cli = Cli::try_parse_from(["nodebridge", "block"]).unwrap();
+    assert_eq!(cli.command, Some(Command::Block { id: None }));
+}
+
+#[test]
+fn no_subcommand_starts_tui() {
+    let cli = Cli::try_parse_from(["nodebridge"]).unwrap();
+    assert!(!cli.json);
+    assert_eq!(cli.command, None);
+}
+
+#[test]
+fn rejects_missing_arguments() {
+    assert!(Cli::try_parse_from(["nodebridge", "tx"]).is_err());
+    assert!(Cli::try_parse_from(["nodebridge", "unbekannt"]).is_err());
+}
+
+fn sample_block() -> BlockDetails {
+    BlockDetails {
+        height: 840_000,
+        hash: "0000000000000000000320283a032748cef8227873ff4872689bf23f1cda83a5".to_string(),
+        timestamp: 1_713_571_767,
+        tx_count: 3050,
+        size: 2_325_617,
+        weight: 3_993_281,
+        version: 0x2000_0000,
+        merkle_root: "031b417c3a1828ddf3d6527fc210daafcc9218e81f98257f88d4d43bd7a5894f".to_string(),
+        bits: "17034219".to_string(),
+        nonce: 3_932_395_645,
+        pool: Some("ViaBTC".to_string()),
+    }
+}
+
+#[test]
+fn block_text_and_json() {
+    let block = sample_block();
+    let text = output(&block, false, format_block).unwrap();
+    assert!(text.contains("Höhe:           840.000"));
+    assert!(text.contains("2024-04-20 00:09:27 UTC"));
+    assert!(text.contains("Pool:           ViaBTC"));
+
+    let value: serde_json::Value = serde_json::from_str(&output(&block, true, format_block).unwrap()).unwrap();
+    assert_eq!(value["height"], json!(840_000));
+    assert_eq!(value["tx_count"], json!(3050));
+}
+
+#[test]
+fn peers_text_lists_every_peer() {
+    let peer = PeerInfo {
+        addr: "203.0.113.5:8333".to_string(),
+        version: 70016,
+        subver: "/Satoshi:27.0.0/".to_string(),
+        latency: 0.0425,
+        bytes_sent: 1_000,
+        bytes_recv: 2_500_000,
+        inbound: true,
+        connected_time: 0,
+        ..Default::default()
+    };
+    let text = format_peers(&[peer.clone(), peer]);
+    assert_eq!(text.lines().count(), 4);
+    assert!(text.contains("42.5"));
+    assert!(text.contains("2.500.000"));
+    assert!(text.ends_with("2 Peers"));
+}
+
+#[test]
+fn transaction_size_in_vbytes() {
+    let tx = Transaction {
+        txid: "b8ba9eb64978b378e7b03e25d14062c10ea844a284d87552c808ab4f4365c958".to_string(),
+        size: 222,
+        weight: 561,
+        blocktime: None,
+        blockhash: None,
+        vin: Vec::new(),
+        vout: Vec::new(),
+    };
+    // 561 WU / 4 aufgerundet
+    assert!(format_transaction(&tx).contains("Größe:          222 Bytes (141 vBytes)"));
+}
@@ -0,0 +1,120 @@
+use anyhow::Result;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExportFormat {
+    Json,
+    Csv,
+}
+
+impl ExportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Json => "json",
+            ExportFormat::Csv => "csv",
+        }
+    }
+}
+
+/// Zielverzeichnis aus EXPORT_DIR (Standard: aktuelles Verzeichnis)
+pub fn export_dir() -> PathBuf {
+    env::var("EXPORT_DIR")
+        .ok()
+        .filter(|dir| !dir.trim().is_empty())
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("."))
+}
+
+/// Schreibt die Daten nach `<dir>/nodebridge_<name>_<zeitstempel>.<json|csv>`
+pub fn export<T: Serialize + ?Sized>(data: &T, name: &str, format: ExportFormat, dir: &Path) -> Result<PathBuf> {
+    let value = serde_json::to_value(data)?;
+    let content = match format {
+        ExportFormat::Json => serde_json::to_string_pretty(&value)?,
+        ExportFormat::Csv => to_csv(&value),
+    };
+
+    fs::create_dir_all(dir)?;
+    let path = dir.join(format!(
+        "nodebridge_{}_{}.{}",
+        name,
+        chrono::Local::now().format("%Y%m%d_%H%M%S"),
+        format.extension()
+    ));
+    fs::write(&path, content)?;
+    Ok(path)
+}
+
+/// Listen werden zu einer Zeile pro Eintrag, einzelne Objekte zu einer Zeile
+pub fn to_csv(value: &Value) -> String {
+    let rows: Vec<Map<String, Value>> = match value {
+        Value::Array(items) => items.iter().map(flatten).collect(),
+        other => vec![flatten(other)],
+    };
+
+    // Spalten aller Zeilen vereinigen, falls einzelne Einträge Felder auslassen
+    let mut columns: Vec<String> = Vec::new();
+    for row in &rows {
+        for key in row.keys() {
+            if !columns.contains(key) {
+                columns.push(key.clone());
+            }
+        }
+    }
+
+    let mut lines = vec![columns.iter().map(|c| escape(c)).collect::<Vec<_>>().join(",")];
+    for row in &rows {
+        lines.push(columns.iter()
+            .map(|column| row.get(column).map(cell).unwrap_or_default())
+            .collect::<Vec<_>>()
+            .join(","));
+    }
+    lines.join("\n") + "\n"
+}
+
+// Verschachtelte Objekte als "aussen.innen"-Spalten; Listen bleiben JSON in einer Zelle
+fn flatten(value: &Value) -> Map<String, Value> {
+    let mut row = Map::new();
+    match value {
+        Value::Object(fields) => flatten_into(&mut row, "", fields),
+        other => {
+            row.insert("value".to_string(), other.clone());
+        }
+    }
+    row
+}
+
+fn flatten_into(row: &mut Map<String, Value>, prefix: &str, fields: &Map<String, Value>) {
+    for (key, value) in fields {
+        let key = if prefix.is_empty() { key.clone() } else { format!("{}.{}", prefix, key) };
+        match value {
+            Value::Object(inner) => flatten_into(row, &key, inner),
+            other => {
+                row.insert(key, other.clone());
+            }
+        }
+    }
+}
+
+fn cell(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(text) => escape(text),
+        Value::Array(_) | Value::Object(_) => escape(&value.to_string()),
+        other => other.to_string(),
+    }
+}
+
+fn escape(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests;
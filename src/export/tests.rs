@@ -0,0 +1,40 @@
+use super::*;
+use serde_json::json;
+
+#[test]
+fn csv_from_object_flattens_nested_fields() {
+    let csv = to_csv(&json!({
+        "height": 840000,
+        "hash": "abc",
+        "mempool_info": { "size": 12 },
+        "warnings": ["a", "b"],
+    }));
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines[0], "hash,height,mempool_info.size,warnings");
+    assert_eq!(lines[1], "abc,840000,12,\"[\"\"a\"\",\"\"b\"\"]\"");
+}
+
+#[test]
+fn csv_from_list_has_one_row_per_entry() {
+    let csv = to_csv(&json!([
+        { "addr": "1.2.3.4:8333", "inbound": false },
+        { "addr": "[::1]:8333", "inbound": true, "subver": "/Satoshi, fork/" },
+    ]));
+    assert_eq!(csv, "addr,inbound,subver\n1.2.3.4:8333,false,\n[::1]:8333,true,\"/Satoshi, fork/\"\n");
+}
+
+#[test]
+fn export_writes_timestamped_file() {
+    let dir = std::env::temp_dir().join(format!("nodebridge-export-{}", std::process::id()));
+    let data = json!({ "tx_count": 3 });
+
+    let path = export(&data, "mempool", ExportFormat::Json, &dir).unwrap();
+    let name = path.file_name().unwrap().to_string_lossy().to_string();
+    assert!(name.starts_with("nodebridge_mempool_") && name.ends_with(".json"));
+    assert_eq!(serde_json::from_str::<Value>(&fs::read_to_string(&path).unwrap()).unwrap(), data);
+
+    let path = export(&data, "mempool", ExportFormat::Csv, &dir).unwrap();
+    assert_eq!(fs::read_to_string(&path).unwrap(), "tx_count\n3\n");
+
+    let _ = fs::remove_dir_all(&dir);
+}
@@ -0,0 +1,300 @@
+// Lokaler Verlauf von Node-Snapshots in SQLite
+use anyhow::Result;
+use crate::rpc::{MempoolStats, NodeStatus};
+use rusqlite::{params, Connection, OptionalExtension};
+use std::env;
+use std::path::Path;
+use std::time::Duration;
+
+const DEFAULT_HISTORY_DB: &str = "nodebridge_history.db";
+const DEFAULT_RETENTION_DAYS: u64 = 30;
+const DEFAULT_INTERVAL_SECS: u64 = 60;
+const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+// Ältere Snapshots gelten bei Vergleichen nicht mehr als Stand zum gewünschten Zeitpunkt
+const MIN_LOOKUP_TOLERANCE: Duration = Duration::from_secs(60 * 60);
+
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS snapshots (
+        timestamp INTEGER PRIMARY KEY,
+        height INTEGER NOT NULL,
+        headers INTEGER NOT NULL,
+        connections INTEGER NOT NULL,
+        peers_inbound INTEGER NOT NULL,
+        peers_outbound INTEGER NOT NULL,
+        verification_progress REAL NOT NULL,
+        difficulty REAL NOT NULL,
+        size_on_disk INTEGER NOT NULL,
+        mempool_tx INTEGER NOT NULL,
+        mempool_bytes INTEGER,
+        mempool_total_fee REAL,
+        mempool_min_fee REAL
+    );
+    CREATE TABLE IF NOT EXISTS block_arrivals (
+        height INTEGER NOT NULL,
+        hash TEXT NOT NULL,
+        block_time INTEGER NOT NULL,
+        seen_at INTEGER NOT NULL,
+        PRIMARY KEY (height, hash)
+    );
+    CREATE INDEX IF NOT EXISTS block_arrivals_seen_at ON block_arrivals (seen_at);
+";
+
+// Nicht jede Spalte wird bereits von einem Tab abgefragt
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HistoryField {
+    Height,
+    Connections,
+    PeersInbound,
+    PeersOutbound,
+    VerificationProgress,
+    Difficulty,
+    SizeOnDisk,
+    MempoolTransactions,
+    MempoolBytes,
+    MempoolTotalFee,
+    MempoolMinFee,
+}
+
+impl HistoryField {
+    // Feste Spaltennamen, daher ohne Gefahr in SQL einsetzbar
+    fn column(&self) -> &'static str {
+        match self {
+            HistoryField::Height => "height",
+            HistoryField::Connections => "connections",
+            HistoryField::PeersInbound => "peers_inbound",
+            HistoryField::PeersOutbound => "peers_outbound",
+            HistoryField::VerificationProgress => "verification_progress",
+            HistoryField::Difficulty => "difficulty",
+            HistoryField::SizeOnDisk => "size_on_disk",
+            HistoryField::MempoolTransactions => "mempool_tx",
+            HistoryField::MempoolBytes => "mempool_bytes",
+            HistoryField::MempoolTotalFee => "mempool_total_fee",
+            HistoryField::MempoolMinFee => "mempool_min_fee",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            HistoryField::Height => "Blöcke",
+            HistoryField::Connections => "Peers",
+            HistoryField::PeersInbound => "Peers eingehend",
+            HistoryField::PeersOutbound => "Peers ausgehend",
+            HistoryField::VerificationProgress => "Verifikation",
+            HistoryField::Difficulty => "Difficulty",
+            HistoryField::SizeOnDisk => "Speicher",
+            HistoryField::MempoolTransactions => "Mempool TXs",
+            HistoryField::MempoolBytes => "Mempool Größe",
+            HistoryField::MempoolTotalFee => "Mempool Gebühren",
+            HistoryField::MempoolMinFee => "Mindest-Fee",
+        }
+    }
+}
+
+/// Aktueller Wert im Vergleich zu einem früheren Snapshot
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Delta {
+    pub field: HistoryField,
+    pub current: f64,
+    pub previous: f64,
+}
+
+impl Delta {
+    pub fn change(&self) -> f64 {
+        self.current - self.previous
+    }
+
+    pub fn percent(&self) -> Option<f64> {
+        (self.previous.abs() > f64::EPSILON).then(|| self.change() / self.previous * 100.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockArrival {
+    pub height: u64,
+    pub hash: String,
+    // Zeitstempel aus dem Block-Header
+    pub block_time: i64,
+    // Zeitpunkt, zu dem NodeBridge den Block zuerst gesehen hat
+    pub seen_at: i64,
+}
+
+/// Für die Tabs aufbereitete Auswertung der letzten 24 Stunden
+#[derive(Debug, Clone, Default)]
+pub struct HistoryView {
+    pub deltas: Vec<Delta>,
+    pub blocks_24h: usize,
+    // Durchschnittlicher Abstand der Block-Zeitstempel in Sekunden
+    pub avg_block_interval: Option<f64>,
+    pub connections: Vec<u64>,
+    pub peers_inbound: Vec<u64>,
+    // Tatsächlich abgedeckter Zeitraum der Reihen in Sekunden (höchstens 24 h)
+    pub series_span: i64,
+}
+
+pub struct HistoryStore {
+    connection: Connection,
+    retention: Duration,
+    interval: Duration,
+    last_snapshot: Option<i64>,
+}
+
+impl HistoryStore {
+    /// HISTORY_DB (Standard: nodebridge_history.db, leer = deaktiviert),
+    /// HISTORY_RETENTION_DAYS und HISTORY_INTERVAL_SECS
+    pub fn from_env() -> Result<Option<Self>> {
+        let path = env::var("HISTORY_DB").unwrap_or_else(|_| DEFAULT_HISTORY_DB.to_string());
+        if path.trim().is_empty() {
+            return Ok(None);
+        }
+        let number = |var: &str, default: u64| env::var(var).ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .filter(|v| *v > 0)
+            .unwrap_or(default);
+
+        Self::open(
+            Path::new(path.trim()),
+            Duration::from_secs(number("HISTORY_RETENTION_DAYS", DEFAULT_RETENTION_DAYS) * DAY.as_secs()),
+            Duration::from_secs(number("HISTORY_INTERVAL_SECS", DEFAULT_INTERVAL_SECS)),
+        ).map(Some)
+    }
+
+    pub fn open(path: &Path, retention: Duration, interval: Duration) -> Result<Self> {
+        let connection = Connection::open(path)?;
+        connection.execute_batch(SCHEMA)?;
+        let last_snapshot = connection
+            .query_row("SELECT MAX(timestamp) FROM snapshots", [], |row| row.get(0))?;
+        Ok(Self { connection, retention, interval, last_snapshot })
+    }
+
+    /// Speichert höchstens einen Snapshot pro Intervall, neue Blöcke aber immer
+    pub fn record(&mut self, status: &NodeStatus, mempool: Option<&MempoolStats>, now: i64) -> Result<()> {
+        self.connection.execute(
+            "INSERT OR IGNORE INTO block_arrivals (height, hash, block_time, seen_at) VALUES (?1, ?2, ?3, ?4)",
+            params![status.height, status.block_hash, status.timestamp, now],
+        )?;
+
+        if self.last_snapshot.is_some_and(|last| now - last < self.interval.as_secs() as i64) {
+            return Ok(());
+        }
+
+        let inbound = status.peers.iter().filter(|peer| peer.inbound).count();
+        self.connection.execute(
+            "INSERT OR REPLACE INTO snapshots VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)",
+            params![
+                now,
+                status.height,
+                status.headers,
+                status.connections,
+                inbound,
+                status.peers.len() - inbound,
+                status.verification_progress,
+                status.difficulty,
+                status.size_on_disk,
+                status.mempool_size,
+                // Eigener Mempool statt der Gesamtgröße von mempool.space
+                status.mempool_info.bytes,
+                mempool.map(|m| m.total_fee),
+                mempool.map(|m| m.min_fee),
+            ],
+        )?;
+        self.last_snapshot = Some(now);
+        self.prune(now)
+    }
+
+    fn prune(&self, now: i64) -> Result<()> {
+        let cutoff = now - self.retention.as_secs() as i64;
+        self.connection.execute("DELETE FROM snapshots WHERE timestamp < ?1", [cutoff])?;
+        self.connection.execute("DELETE FROM block_arrivals WHERE seen_at < ?1", [cutoff])?;
+        Ok(())
+    }
+
+    /// Zeitreihe (Zeitstempel, Wert) seit `since`, älteste zuerst
+    pub fn series(&self, field: HistoryField, since: i64) -> Result<Vec<(i64, f64)>> {
+        let mut statement = self.connection.prepare(&format!(
+            "SELECT timestamp, {0} FROM snapshots WHERE timestamp >= ?1 AND {0} IS NOT NULL ORDER BY timestamp",
+            field.column()
+        ))?;
+        let rows = statement.query_map([since], |row| Ok((row.get(0)?, row.get(1)?)))?;
+        Ok(rows.collect::<rusqlite::Result<_>>()?)
+    }
+
+    /// Letzter Wert zum Zeitpunkt `at` oder kurz davor; None bei einer Lücke im Verlauf
+    pub fn value_at(&self, field: HistoryField, at: i64) -> Result<Option<f64>> {
+        // Doppeltes Intervall, mindestens aber eine Stunde
+        let tolerance = (2 * self.interval).max(MIN_LOOKUP_TOLERANCE).as_secs() as i64;
+        Ok(self.connection.query_row(
+            &format!(
+                "SELECT {0} FROM snapshots WHERE timestamp <= ?1 AND timestamp >= ?2 AND {0} IS NOT NULL \
+                 ORDER BY timestamp DESC LIMIT 1",
+                field.column()
+            ),
+            [at, at - tolerance],
+            |row| row.get(0),
+        ).optional()?)
+    }
+
+    /// Neuester Snapshot im Vergleich zum Stand vor `ago`; None, solange der Verlauf zu kurz ist
+    pub fn compare(&self, field: HistoryField, now: i64, ago: Duration) -> Result<Option<Delta>> {
+        let current = self.value_at(field, now)?;
+        let previous = self.value_at(field, now - ago.as_secs() as i64)?;
+        Ok(current.zip(previous).map(|(current, previous)| Delta { field, current, previous }))
+    }
+
+    /// Seit `since` gesehene Blöcke, neuester zuerst
+    pub fn block_arrivals(&self, since: i64) -> Result<Vec<BlockArrival>> {
+        let mut statement = self.connection.prepare(
+            "SELECT height, hash, block_time, seen_at FROM block_arrivals WHERE seen_at >= ?1 ORDER BY height DESC"
+        )?;
+        let rows = statement.query_map([since], |row| Ok(BlockArrival {
+            height: row.get(0)?,
+            hash: row.get(1)?,
+            block_time: row.get(2)?,
+            seen_at: row.get(3)?,
+        }))?;
+        Ok(rows.collect::<rusqlite::Result<_>>()?)
+    }
+
+    /// Vergleich mit vor 24 Stunden und Verläufe für die Charts
+    pub fn view(&self, now: i64) -> Result<HistoryView> {
+        let since = now - DAY.as_secs() as i64;
+        let mut deltas = Vec::new();
+        for field in [
+            HistoryField::Height,
+            HistoryField::Connections,
+            HistoryField::MempoolTransactions,
+            HistoryField::MempoolBytes,
+            HistoryField::SizeOnDisk,
+        ] {
+            deltas.extend(self.compare(field, now, DAY)?);
+        }
+
+        let blocks = self.block_arrivals(since)?;
+        let avg_block_interval = match (blocks.first(), blocks.last()) {
+            (Some(newest), Some(oldest)) if newest.height > oldest.height => Some(
+                (newest.block_time - oldest.block_time) as f64 / (newest.height - oldest.height) as f64
+            ),
+            _ => None,
+        };
+        let connections = self.series(HistoryField::Connections, since)?;
+        let series_span = match (connections.first(), connections.last()) {
+            (Some((first, _)), Some((last, _))) => last - first,
+            _ => 0,
+        };
+        let values = |series: Vec<(i64, f64)>| -> Vec<u64> {
+            series.into_iter().map(|(_, value)| value as u64).collect()
+        };
+
+        Ok(HistoryView {
+            deltas,
+            blocks_24h: blocks.len(),
+            avg_block_interval,
+            connections: values(connections),
+            peers_inbound: values(self.series(HistoryField::PeersInbound, since)?),
+            series_span,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,148 @@
+use super::*;
+use crate::rpc::{FeeCategory, MempoolInfo, PeerInfo};
+
+const NOW: i64 = 1_700_000_000;
+
+fn store() -> HistoryStore {
+    HistoryStore::open(Path::new(":memory:"), 2 * DAY, Duration::from_secs(60)).unwrap()
+}
+
+fn status(height: u64, connections: u64, mempool_size: u64) -> NodeStatus {
+    let peer = |inbound| PeerInfo {
+        addr: "203.0.113.5:8333".to_string(),
+        version: 70016,
+        subver: String::new(),
+        latency: 0.0,
+        bytes_sent: 0,
+        bytes_recv: 0,
+        inbound,
+        connected_time: 0,
+        ..Default::default()
+    };
+    NodeStatus {
+        height,
+        block_hash: format!("hash{}", height),
+        timestamp: NOW - 600 * (1000 - height as i64),
+        connections,
+        verification_progress: 1.0,
+        mempool_size,
+        // Etwa 1 kvB pro Transaktion
+        mempool_info: MempoolInfo { size: mempool_size, bytes: mempool_size * 1_000 },
+        peers: vec![peer(true), peer(false), peer(false)],
+        difficulty: 1.0,
+        headers: height,
+        size_on_disk: 1_000,
+        ..Default::default()
+    }
+}
+
+fn mempool(size: u64) -> MempoolStats {
+    let bucket = FeeCategory { count: 0, rate: 1.0, usd_price: 0.0 };
+    MempoolStats {
+        tx_count: 0,
+        size,
+        total_fee: 0.5,
+        min_fee: 1.0,
+        no_priority: bucket.clone(),
+        low_priority: bucket.clone(),
+        medium_priority: bucket.clone(),
+        high_priority: bucket,
+    }
+}
+
+#[test]
+fn respects_snapshot_interval() {
+    let mut store = store();
+    store.record(&status(900, 8, 100), None, NOW).unwrap();
+    store.record(&status(900, 9, 100), None, NOW + 30).unwrap();
+    store.record(&status(901, 10, 100), None, NOW + 60).unwrap();
+
+    let series = store.series(HistoryField::Connections, 0).unwrap();
+    assert_eq!(series, vec![(NOW, 8.0), (NOW + 60, 10.0)]);
+    assert_eq!(store.series(HistoryField::PeersInbound, 0).unwrap()[0].1, 1.0);
+
+    // Blöcke werden unabhängig vom Intervall erfasst
+    assert_eq!(store.block_arrivals(0).unwrap().len(), 2);
+}
+
+#[test]
+fn compares_with_24_hours_ago() {
+    let mut store = store();
+    let day = DAY.as_secs() as i64;
+    assert_eq!(store.compare(HistoryField::Height, NOW, DAY).unwrap(), None);
+
+    store.record(&status(856, 10, 2_000), Some(&mempool(50_000_000)), NOW - day - 30).unwrap();
+    store.record(&status(1000, 8, 3_000), Some(&mempool(75_000_000)), NOW).unwrap();
+
+    let delta = store.compare(HistoryField::Height, NOW, DAY).unwrap().unwrap();
+    assert_eq!(delta.change(), 144.0);
+
+    let delta = store.compare(HistoryField::MempoolBytes, NOW, DAY).unwrap().unwrap();
+    assert_eq!(delta.percent(), Some(50.0));
+
+    let view = store.view(NOW).unwrap();
+    assert_eq!(view.deltas.len(), 5);
+    assert_eq!(view.connections, vec![8]);
+}
+
+#[test]
+fn no_comparison_across_gap() {
+    let mut store = store();
+    let day = DAY.as_secs() as i64;
+    // Zwei Tage alter Snapshot ist kein Stand von vor 24 Stunden
+    store.record(&status(712, 10, 2_000), None, NOW - 2 * day).unwrap();
+    store.record(&status(1000, 8, 3_000), None, NOW).unwrap();
+
+    assert_eq!(store.value_at(HistoryField::Height, NOW - day).unwrap(), None);
+    assert_eq!(store.compare(HistoryField::Height, NOW, DAY).unwrap(), None);
+    assert_eq!(store.value_at(HistoryField::Height, NOW + 1800).unwrap(), Some(1000.0));
+}
+
+#[test]
+fn missing_mempool_values_are_skipped() {
+    let mut store = store();
+    store.record(&status(900, 8, 100), None, NOW).unwrap();
+    assert!(store.series(HistoryField::MempoolTotalFee, 0).unwrap().is_empty());
+    // Die Größe kommt vom eigenen Node und fehlt auch ohne mempool.space nicht
+    assert_eq!(store.value_at(HistoryField::MempoolBytes, NOW).unwrap(), Some(100_000.0));
+    assert_eq!(store.value_at(HistoryField::MempoolTransactions, NOW).unwrap(), Some(100.0));
+}
+
+#[test]
+fn prunes_after_retention() {
+    let mut store = store();
+    let day = DAY.as_secs() as i64;
+    store.record(&status(900, 8, 100), None, NOW - 3 * day).unwrap();
+    store.record(&status(1000, 8, 100), None, NOW).unwrap();
+
+    assert_eq!(store.series(HistoryField::Height, 0).unwrap(), vec![(NOW, 1000.0)]);
+    assert_eq!(store.block_arrivals(0).unwrap().len(), 1);
+}
+
+#[test]
+fn average_block_interval_from_header_times() {
+    let mut store = store();
+    for (offset, height) in (997..=1000).enumerate() {
+        store.record(&status(height, 8, 100), None, NOW + offset as i64 * 600).unwrap();
+    }
+    let view = store.view(NOW + 1800).unwrap();
+    assert_eq!(view.blocks_24h, 4);
+    assert_eq!(view.avg_block_interval, Some(600.0));
+    // Erst 30 Minuten Verlauf, nicht 24 h
+    assert_eq!(view.series_span, 1800);
+}
+
+#[test]
+fn persists_across_reopen() {
+    let path = std::env::temp_dir().join(format!("nodebridge-history-{}.db", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    {
+        let mut store = HistoryStore::open(&path, DAY, Duration::from_secs(60)).unwrap();
+        store.record(&status(900, 8, 100), None, NOW).unwrap();
+    }
+    let mut store = HistoryStore::open(&path, DAY, Duration::from_secs(60)).unwrap();
+    // Intervall gilt auch über einen Neustart hinweg
+    store.record(&status(900, 9, 100), None, NOW + 10).unwrap();
+    assert_eq!(store.series(HistoryField::Connections, 0).unwrap(), vec![(NOW, 8.0)]);
+    let _ = std::fs::remove_file(&path);
+}
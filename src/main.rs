@@ -1,7 +1,18 @@
 mod rpc;
 mod ui;
+mod watchlist;
+mod alerts;
+mod reorg;
+mod zmq;
+mod cli;
+mod export;
+mod metrics;
+mod history;
+mod pools;
 
 use anyhow::Result;
+use clap::Parser;
+use crate::cli::Cli;
 use crate::ui::UI;
 use std::io::{self, stdout, Write};
 use crossterm::{
@@ -14,12 +25,11 @@ use ratatui::{
     backend::CrosstermBackend,
     widgets::{Paragraph, Block, Borders},
     layout::Alignment,
-    style::{Style, Color as RatatuiColor},
+    style::Style,
     Terminal,
 };
 use crate::ui::BlockSearchMode;
 use log::{info};
-use env_logger;
 
 const DEFAULT_TX_ID: &str = "b8ba9eb64978b378e7b03e25d14062c10ea844a284d87552c808ab4f4365c958";
 const DEFAULT_ADDRESS: &str = "bc1p38hzyl8p5yyqnzgkcxttr6ac0wc0ae8gpv7rld79df88qkrva38s78e8wd";
@@ -27,6 +37,21 @@ const DEFAULT_ADDRESS: &str = "bc1p38hzyl8p5yyqnzgkcxttr6ac0wc0ae8gpv7rld79df88q
 fn main() -> Result<()> {
     // Initialisiere das Logging-System
     env_logger::init();
+
+    // Mit Unterbefehl ohne TUI ausführen (z.B. `nodebridge status --json`)
+    let cli = Cli::parse();
+    if let Some(command) = cli.command {
+        return cli::run(command, cli.json);
+    }
+
+    // Prometheus-Endpunkt parallel zur TUI, falls METRICS_ADDR gesetzt ist
+    dotenv::dotenv().ok();
+    if let Some(config) = metrics::MetricsConfig::from_env() {
+        if let Err(e) = metrics::spawn(config.clone()) {
+            eprintln!("Metrik-Endpunkt {} konnte nicht gestartet werden: {}", config.addr, e);
+        }
+    }
+
     info!("Bitcoin Node Terminal UI wird gestartet.");
 
     // Separate Variablen für Block und Explorer
@@ -46,7 +71,7 @@ fn main() -> Result<()> {
     terminal.draw(|f| {
         let loading = Paragraph::new("Bitcoin Node Terminal UI wird gestartet...\nVerbinde mit Node...")
             .block(Block::default().borders(Borders::ALL))
-            .style(Style::default().fg(RatatuiColor::Yellow))
+            .style(Style::default().fg(ui::theme::theme().warn))
             .alignment(Alignment::Center);
         f.render_widget(loading, f.size());
     })?;
@@ -99,7 +124,7 @@ fn get_user_selection() -> Result<(BlockSearchMode, Option<String>, Option<Strin
         SetForegroundColor(Color::Magenta),
         Print(" 3) "),
         ResetColor,
-        Print("Adresse suchen (mit Bitcoin-Adresse)\n")
+        Print("Adresse suchen (Bitcoin-Adresse, Descriptor oder xpub/ypub/zpub)\n")
     )?;
     
     execute!(
@@ -144,11 +169,11 @@ fn get_user_selection() -> Result<(BlockSearchMode, Option<String>, Option<Strin
             if block.trim().is_empty() {
                 println!("\nEs wird der aktuelle Block verwendet.");
                 std::thread::sleep(std::time::Duration::from_millis(1000));
-                return Ok((BlockSearchMode::Latest, None, None));
+                Ok((BlockSearchMode::Latest, None, None))
             } else {
                 println!("\nSuche nach Block: {}", block.trim());
                 std::thread::sleep(std::time::Duration::from_millis(1000));
-                return Ok((BlockSearchMode::Custom(block.trim().to_string()), None, None));
+                Ok((BlockSearchMode::Custom(block.trim().to_string()), None, None))
             }
         },
         "2" => {
@@ -171,11 +196,11 @@ fn get_user_selection() -> Result<(BlockSearchMode, Option<String>, Option<Strin
             if txid.is_empty() {
                 println!("\nEs wird die Standard-Transaktion verwendet.");
                 std::thread::sleep(std::time::Duration::from_millis(1000));
-                return Ok((BlockSearchMode::Latest, Some(DEFAULT_TX_ID.into()), None));
+                Ok((BlockSearchMode::Latest, Some(DEFAULT_TX_ID.into()), None))
             } else {
                 println!("\nSuche nach Transaktion: {}", txid);
                 std::thread::sleep(std::time::Duration::from_millis(1000));
-                return Ok((BlockSearchMode::Latest, Some(txid.to_string()), None));
+                Ok((BlockSearchMode::Latest, Some(txid.to_string()), None))
             }
         },
         "3" => {
@@ -190,7 +215,7 @@ fn get_user_selection() -> Result<(BlockSearchMode, Option<String>, Option<Strin
             )?;
             
             println!("Standard-Adresse: {}", DEFAULT_ADDRESS);
-            print!("\nBitcoin-Adresse eingeben (oder [Enter] für Standard-Adresse): ");
+            print!("\nAdresse, Descriptor oder xpub/ypub/zpub eingeben (oder [Enter] für Standard-Adresse): ");
             io::stdout().flush()?;
             let mut addr = String::new();
             io::stdin().read_line(&mut addr)?;
@@ -198,11 +223,11 @@ fn get_user_selection() -> Result<(BlockSearchMode, Option<String>, Option<Strin
             if addr.is_empty() {
                 println!("\nEs wird die Standard-Adresse verwendet.");
                 std::thread::sleep(std::time::Duration::from_millis(1000));
-                return Ok((BlockSearchMode::Latest, None, Some(DEFAULT_ADDRESS.into())));
+                Ok((BlockSearchMode::Latest, None, Some(DEFAULT_ADDRESS.into())))
             } else {
                 println!("\nSuche nach Adresse: {}", addr);
                 std::thread::sleep(std::time::Duration::from_millis(1000));
-                return Ok((BlockSearchMode::Latest, None, Some(addr.to_string())));
+                Ok((BlockSearchMode::Latest, None, Some(addr.to_string())))
             }
         },
         "d" => {
@@ -222,7 +247,7 @@ fn get_user_selection() -> Result<(BlockSearchMode, Option<String>, Option<Strin
             
             println!("\nProgramm wird mit Standardwerten gestartet...");
             std::thread::sleep(std::time::Duration::from_millis(1500));
-            return Ok((BlockSearchMode::Latest, Some(DEFAULT_TX_ID.into()), Some(DEFAULT_ADDRESS.into())));
+            Ok((BlockSearchMode::Latest, Some(DEFAULT_TX_ID.into()), Some(DEFAULT_ADDRESS.into())))
         },
         "q" => {
             // Beenden
@@ -248,7 +273,7 @@ fn get_user_selection() -> Result<(BlockSearchMode, Option<String>, Option<Strin
             )?;
             
             std::thread::sleep(std::time::Duration::from_millis(1500));
-            return Ok((BlockSearchMode::Latest, Some(DEFAULT_TX_ID.into()), Some(DEFAULT_ADDRESS.into())));
+            Ok((BlockSearchMode::Latest, Some(DEFAULT_TX_ID.into()), Some(DEFAULT_ADDRESS.into())))
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,231 @@
+use anyhow::Result;
+use crate::rpc::{BitcoinRPC, FeeCategory, MempoolStats, NodeStatus};
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt::Write as _;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::time::Duration;
+
+const DEFAULT_PROFILE: &str = "default";
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Adresse (METRICS_ADDR) und Profil-Label (METRICS_PROFILE) des Prometheus-Endpunkts
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetricsConfig {
+    pub addr: String,
+    pub profile: String,
+}
+
+impl MetricsConfig {
+    /// None, solange METRICS_ADDR nicht gesetzt ist
+    pub fn from_env() -> Option<Self> {
+        let addr = env::var("METRICS_ADDR").ok().filter(|addr| !addr.trim().is_empty())?;
+        Some(Self::with_addr(addr.trim().to_string()))
+    }
+
+    pub fn with_addr(addr: String) -> Self {
+        // Ohne eigenes Profil den RPC-Host verwenden, damit mehrere Nodes unterscheidbar bleiben
+        let profile = env::var("METRICS_PROFILE")
+            .or_else(|_| env::var("BTC_RPC_HOST"))
+            .ok()
+            .filter(|profile| !profile.trim().is_empty())
+            .unwrap_or_else(|| DEFAULT_PROFILE.to_string());
+        Self { addr, profile }
+    }
+}
+
+/// Momentaufnahme aller Werte für einen Scrape
+pub struct MetricsSnapshot {
+    pub status: NodeStatus,
+    pub mempool: Option<MempoolStats>,
+    // (Blöcke bis zur Anpassung, geschätzte Änderung in Prozent)
+    pub difficulty_adjustment: Option<(i64, f64)>,
+}
+
+impl MetricsSnapshot {
+    pub fn collect(client: &BitcoinRPC) -> Result<Self> {
+        Ok(Self {
+            status: client.test_connection()?,
+            mempool: client.get_mempool_stats().ok(),
+            difficulty_adjustment: client.get_difficulty_adjustment_estimate().ok(),
+        })
+    }
+}
+
+struct MetricsWriter {
+    output: String,
+    labels: String,
+}
+
+impl MetricsWriter {
+    fn new(profile: &str, network: &str) -> Self {
+        Self {
+            output: String::new(),
+            labels: format!("profile=\"{}\",network=\"{}\"", escape_label(profile), escape_label(network)),
+        }
+    }
+
+    fn gauge(&mut self, name: &str, help: &str, value: f64) {
+        self.header(name, help);
+        self.sample(name, &[], value);
+    }
+
+    fn header(&mut self, name: &str, help: &str) {
+        let _ = writeln!(self.output, "# HELP nodebridge_{} {}", name, help);
+        let _ = writeln!(self.output, "# TYPE nodebridge_{} gauge", name);
+    }
+
+    fn sample(&mut self, name: &str, extra: &[(&str, &str)], value: f64) {
+        let mut labels = self.labels.clone();
+        for (key, label) in extra {
+            let _ = write!(labels, ",{}=\"{}\"", key, escape_label(label));
+        }
+        let _ = writeln!(self.output, "nodebridge_{}{{{}}} {}", name, labels, value);
+    }
+}
+
+fn escape_label(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+fn flag(value: bool) -> f64 {
+    if value { 1.0 } else { 0.0 }
+}
+
+/// Prometheus-Textformat (Version 0.0.4); alle Werte als Gauges
+pub fn render_metrics(snapshot: &MetricsSnapshot, profile: &str) -> String {
+    let status = &snapshot.status;
+    let mut metrics = MetricsWriter::new(profile, &status.network);
+
+    metrics.gauge("up", "1, wenn der Node per RPC erreichbar ist", 1.0);
+    metrics.gauge("node_version", "Bitcoin Core Version als Zahl (z.B. 270000)", status.version as f64);
+    metrics.gauge("block_height", "Anzahl validierter Blöcke", status.height as f64);
+    metrics.gauge("headers", "Anzahl bekannter Block-Header", status.headers as f64);
+    metrics.gauge("best_block_timestamp_seconds", "Zeitstempel des besten Blocks", status.timestamp as f64);
+    metrics.gauge("verification_progress", "Verifikationsfortschritt (0-1)", status.verification_progress);
+    metrics.gauge("initial_block_download", "1 während des Initial Block Download", flag(status.initial_block_download));
+    metrics.gauge("difficulty", "Aktuelle Mining-Difficulty", status.difficulty);
+    metrics.gauge("size_on_disk_bytes", "Größe der Blockchain-Daten auf dem Laufwerk", status.size_on_disk as f64);
+    metrics.gauge("pruned", "1, wenn der Node im Pruned-Modus läuft", flag(status.pruned));
+    metrics.gauge("time_offset_seconds", "Abweichung der Node-Uhr von den Peers", status.time_offset as f64);
+    metrics.gauge("warnings", "Anzahl aktiver Node-Warnungen", status.warnings.len() as f64);
+    metrics.gauge("connections", "Anzahl Verbindungen laut getconnectioncount", status.connections as f64);
+
+    let inbound = status.peers.iter().filter(|peer| peer.inbound).count();
+    metrics.header("peers", "Verbundene Peers nach Richtung");
+    metrics.sample("peers", &[("direction", "inbound")], inbound as f64);
+    metrics.sample("peers", &[("direction", "outbound")], (status.peers.len() - inbound) as f64);
+
+    let mut by_network: BTreeMap<&str, usize> = BTreeMap::new();
+    for peer in &status.peers {
+        *by_network.entry(peer.network.as_str()).or_default() += 1;
+    }
+    metrics.header("peers_by_network", "Verbundene Peers nach Netzwerk");
+    for (peer_network, count) in by_network {
+        metrics.sample("peers_by_network", &[("peer_network", peer_network)], count as f64);
+    }
+
+    metrics.gauge("mempool_transactions", "Transaktionen im lokalen Mempool", status.mempool_size as f64);
+    if let Some(mempool) = &snapshot.mempool {
+        metrics.gauge("mempool_bytes", "Größe des Mempools in Bytes", mempool.size as f64);
+        metrics.gauge("mempool_total_fee_btc", "Summe aller Gebühren im Mempool", mempool.total_fee);
+        metrics.gauge("mempool_min_fee_sat_per_vbyte", "Mindest-Feerate des Node-Mempools", mempool.min_fee);
+
+        let buckets: [(&str, &FeeCategory); 4] = [
+            ("none", &mempool.no_priority),
+            ("low", &mempool.low_priority),
+            ("medium", &mempool.medium_priority),
+            ("high", &mempool.high_priority),
+        ];
+        metrics.header("mempool_fee_rate_sat_per_vbyte", "Empfohlene Feerate je Priorität");
+        for (priority, bucket) in buckets {
+            metrics.sample("mempool_fee_rate_sat_per_vbyte", &[("priority", priority)], bucket.rate);
+        }
+        metrics.header("mempool_fee_bucket_transactions", "Transaktionen je Gebührenstufe");
+        for (priority, bucket) in buckets {
+            metrics.sample("mempool_fee_bucket_transactions", &[("priority", priority)], bucket.count as f64);
+        }
+    }
+
+    if let Some((blocks_until, adjustment)) = snapshot.difficulty_adjustment {
+        metrics.gauge("difficulty_adjustment_blocks", "Blöcke bis zur nächsten Difficulty-Anpassung", blocks_until as f64);
+        metrics.gauge("difficulty_adjustment_percent", "Geschätzte Änderung der Difficulty in Prozent", adjustment);
+    }
+
+    metrics.output
+}
+
+/// Startet den Endpunkt im Hintergrund, z.B. parallel zur TUI
+pub fn spawn(config: MetricsConfig) -> Result<()> {
+    let listener = TcpListener::bind(&config.addr)?;
+    std::thread::spawn(move || serve_listener(listener, || scrape(&config.profile)));
+    Ok(())
+}
+
+/// Blockiert und bedient Scrapes, bis der Prozess beendet wird (headless)
+pub fn serve(config: MetricsConfig) -> Result<()> {
+    let listener = TcpListener::bind(&config.addr)?;
+    println!("Prometheus-Metriken unter http://{}/metrics", listener.local_addr()?);
+    serve_listener(listener, || scrape(&config.profile));
+    Ok(())
+}
+
+// Bei jedem Scrape frisch abfragen; ein nicht erreichbarer Node meldet nodebridge_up 0
+fn scrape(profile: &str) -> String {
+    match BitcoinRPC::new().and_then(|client| MetricsSnapshot::collect(&client)) {
+        Ok(snapshot) => render_metrics(&snapshot, profile),
+        Err(e) => {
+            log::warn!("Metriken konnten nicht abgefragt werden: {}", e);
+            let mut metrics = MetricsWriter::new(profile, "unknown");
+            metrics.gauge("up", "1, wenn der Node per RPC erreichbar ist", 0.0);
+            metrics.output
+        }
+    }
+}
+
+fn serve_listener(listener: TcpListener, body: impl Fn() -> String) {
+    for stream in listener.incoming() {
+        match stream {
+            Ok(stream) => {
+                if let Err(e) = handle_connection(stream, &body) {
+                    log::warn!("Metrik-Anfrage fehlgeschlagen: {}", e);
+                }
+            }
+            Err(e) => log::warn!("Metrik-Verbindung fehlgeschlagen: {}", e),
+        }
+    }
+}
+
+fn handle_connection(mut stream: TcpStream, body: &impl Fn() -> String) -> std::io::Result<()> {
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    let mut reader = BufReader::new(&stream);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+
+    // Header vollständig lesen, sonst kann das Schließen die Antwort abbrechen
+    let mut header = String::new();
+    while reader.read_line(&mut header)? > 0 && header.trim() != "" {
+        header.clear();
+    }
+
+    let path = request_line.split_whitespace().nth(1).unwrap_or("");
+    let (status, content_type, content) = if request_line.starts_with("GET ") && (path == "/metrics" || path.starts_with("/metrics?")) {
+        ("200 OK", "text/plain; version=0.0.4; charset=utf-8", body())
+    } else {
+        ("404 Not Found", "text/plain; charset=utf-8", "Nur /metrics verfügbar\n".to_string())
+    };
+
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        content_type,
+        content.len(),
+        content
+    )?;
+    stream.flush()
+}
+
+#[cfg(test)]
+mod tests;
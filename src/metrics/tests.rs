@@ -0,0 +1,100 @@
+use super::*;
+use crate::rpc::{FeeCategory, PeerInfo};
+use serde_json::json;
+use std::io::Read;
+
+// Ohne "network" wie bei älteren Nodes, das Netzwerk wird aus der Adresse abgeleitet
+fn peer(addr: &str, inbound: bool) -> PeerInfo {
+    PeerInfo::from_value(&json!({
+        "addr": addr,
+        "version": 70016,
+        "subver": "/Satoshi:27.0.0/",
+        "pingtime": 0.05,
+        "inbound": inbound,
+    }))
+}
+
+fn snapshot() -> MetricsSnapshot {
+    let bucket = |count, rate| FeeCategory { count, rate, usd_price: 0.0 };
+    MetricsSnapshot {
+        status: NodeStatus {
+            version: 270000,
+            height: 840_000,
+            timestamp: 1_713_571_767,
+            connections: 3,
+            verification_progress: 0.9999,
+            mempool_size: 42_000,
+            network: "main".to_string(),
+            peers: vec![
+                peer("203.0.113.5:8333", false),
+                peer("[2001:db8::1]:8333", true),
+                peer("abcdefghijklmnop.onion:8333", false),
+            ],
+            difficulty: 86_388_558_925_171.02,
+            headers: 840_000,
+            size_on_disk: 600_000_000_000,
+            warnings: vec!["Test".to_string()],
+            time_offset: -2,
+            ..Default::default()
+        },
+        mempool: Some(MempoolStats {
+            tx_count: 42_000,
+            size: 150_000_000,
+            total_fee: 1.5,
+            min_fee: 1.0,
+            no_priority: bucket(100, 1.0),
+            low_priority: bucket(200, 2.0),
+            medium_priority: bucket(300, 5.0),
+            high_priority: bucket(400, 12.5),
+        }),
+        difficulty_adjustment: Some((1008, -3.25)),
+    }
+}
+
+#[test]
+fn renders_labelled_gauges() {
+    let text = render_metrics(&snapshot(), "home\"node");
+    let labels = "profile=\"home\\\"node\",network=\"main\"";
+
+    assert!(text.contains("# TYPE nodebridge_block_height gauge\n"));
+    assert!(text.contains(&format!("nodebridge_block_height{{{}}} 840000\n", labels)));
+    assert!(text.contains(&format!("nodebridge_peers{{{},direction=\"inbound\"}} 1\n", labels)));
+    assert!(text.contains(&format!("nodebridge_peers{{{},direction=\"outbound\"}} 2\n", labels)));
+    assert!(text.contains(&format!("nodebridge_peers_by_network{{{},peer_network=\"onion\"}} 1\n", labels)));
+    assert!(text.contains(&format!("nodebridge_mempool_fee_rate_sat_per_vbyte{{{},priority=\"high\"}} 12.5\n", labels)));
+    assert!(text.contains(&format!("nodebridge_difficulty_adjustment_percent{{{}}} -3.25\n", labels)));
+    assert!(text.contains(&format!("nodebridge_warnings{{{}}} 1\n", labels)));
+}
+
+#[test]
+fn optional_sections_are_skipped() {
+    let mut snapshot = snapshot();
+    snapshot.mempool = None;
+    snapshot.difficulty_adjustment = None;
+    let text = render_metrics(&snapshot, "default");
+    assert!(text.contains("nodebridge_mempool_transactions{"));
+    assert!(!text.contains("nodebridge_mempool_bytes"));
+    assert!(!text.contains("nodebridge_difficulty_adjustment"));
+}
+
+fn request(addr: std::net::SocketAddr, path: &str) -> String {
+    let mut stream = TcpStream::connect(addr).unwrap();
+    write!(stream, "GET {} HTTP/1.1\r\nHost: localhost\r\nAccept: */*\r\n\r\n", path).unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    response
+}
+
+#[test]
+fn serves_metrics_over_http() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    std::thread::spawn(move || serve_listener(listener, || "nodebridge_up 1\n".to_string()));
+
+    let response = request(addr, "/metrics");
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+    assert!(response.contains("text/plain; version=0.0.4"));
+    assert!(response.ends_with("\r\n\r\nnodebridge_up 1\n"));
+
+    assert!(request(addr, "/").starts_with("HTTP/1.1 404"));
+}
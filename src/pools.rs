@@ -0,0 +1,102 @@
+// Zuordnung von Blöcken zu Mining-Pools anhand der Coinbase
+use anyhow::Result;
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+
+const DEFAULT_POOLS_FILE: &str = "pools.json";
+// Mitgelieferte Definitionen, falls keine eigene Datei vorhanden ist
+const BUNDLED_POOLS: &str = include_str!("../pools.json");
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Pool {
+    pub name: String,
+    #[serde(default)]
+    pub link: String,
+}
+
+/// Format der öffentlichen Pool-Listen: coinbase_tags und payout_addresses
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PoolDefinitions {
+    #[serde(default)]
+    coinbase_tags: HashMap<String, Pool>,
+    #[serde(default)]
+    payout_addresses: HashMap<String, Pool>,
+}
+
+static DEFINITIONS: Lazy<PoolDefinitions> = Lazy::new(|| {
+    PoolDefinitions::load().unwrap_or_else(|e| {
+        log::warn!("Pool-Definitionen konnten nicht geladen werden: {}", e);
+        PoolDefinitions::bundled()
+    })
+});
+
+impl PoolDefinitions {
+    pub fn parse(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    pub fn bundled() -> Self {
+        Self::parse(BUNDLED_POOLS).unwrap_or_default()
+    }
+
+    /// POOLS_FILE (Standard: pools.json); ohne Datei gelten die mitgelieferten Definitionen
+    pub fn load() -> Result<Self> {
+        let path = env::var("POOLS_FILE").unwrap_or_else(|_| DEFAULT_POOLS_FILE.to_string());
+        match fs::read_to_string(&path) {
+            Ok(content) => Self::parse(&content)
+                .map_err(|e| anyhow::anyhow!("{} ist ungültig: {}", path, e)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::bundled()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Auszahlungsadressen sind eindeutiger als Tags und haben daher Vorrang
+    pub fn identify(&self, coinbase_script: &[u8], payout_addresses: &[String]) -> Option<&Pool> {
+        if let Some(pool) = payout_addresses.iter().find_map(|address| self.payout_addresses.get(address)) {
+            return Some(pool);
+        }
+
+        // Bei mehreren Treffern gewinnt der längste Tag, damit das Ergebnis stabil bleibt
+        let script = String::from_utf8_lossy(coinbase_script);
+        self.coinbase_tags.iter()
+            .filter(|(tag, _)| script.contains(tag.as_str()))
+            .max_by(|(a, _), (b, _)| a.len().cmp(&b.len()).then(b.cmp(a)))
+            .map(|(_, pool)| pool)
+    }
+}
+
+/// Einmal geladene Definitionen für den gesamten Prozess
+pub fn definitions() -> &'static PoolDefinitions {
+    &DEFINITIONS
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PoolShare {
+    pub name: String,
+    pub blocks: usize,
+    pub percent: f64,
+}
+
+/// Anteil je Pool, größter zuerst; None zählt als "Unbekannt"
+pub fn pool_shares(pools: &[Option<String>]) -> Vec<PoolShare> {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for pool in pools {
+        *counts.entry(pool.as_deref().unwrap_or("Unbekannt")).or_default() += 1;
+    }
+
+    let mut shares: Vec<PoolShare> = counts.into_iter()
+        .map(|(name, blocks)| PoolShare {
+            name: name.to_string(),
+            blocks,
+            percent: blocks as f64 / pools.len() as f64 * 100.0,
+        })
+        .collect();
+    shares.sort_by(|a, b| b.blocks.cmp(&a.blocks).then(a.name.cmp(&b.name)));
+    shares
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,61 @@
+use super::*;
+
+const DEFINITIONS_JSON: &str = r#"{
+    "coinbase_tags": {
+        "/slush/": { "name": "Braiins Pool", "link": "https://braiins.com" },
+        "solo.ckpool.org": { "name": "Solo CK", "link": "https://solo.ckpool.org" },
+        "/ckpool.org/": { "name": "CKPool", "link": "https://ckpool.org" }
+    },
+    "payout_addresses": {
+        "1KFHE7w8BhaENAswwryaoccDb6qcT6DbYY": { "name": "F2Pool", "link": "https://www.f2pool.com" }
+    }
+}"#;
+
+#[test]
+fn identifies_pool_by_coinbase_tag() {
+    let definitions = PoolDefinitions::parse(DEFINITIONS_JSON).unwrap();
+    let script = b"\x03\x40\xd1\x0c/slush/\x00\x01";
+
+    let pool = definitions.identify(script, &[]).unwrap();
+    assert_eq!(pool.name, "Braiins Pool");
+    assert!(definitions.identify(b"\x03\x40\xd1\x0cunbekannt", &[]).is_none());
+}
+
+#[test]
+fn longest_tag_wins() {
+    let definitions = PoolDefinitions::parse(DEFINITIONS_JSON).unwrap();
+    let pool = definitions.identify(b"Mined via /solo.ckpool.org/", &[]).unwrap();
+    assert_eq!(pool.name, "Solo CK");
+}
+
+#[test]
+fn payout_address_takes_precedence() {
+    let definitions = PoolDefinitions::parse(DEFINITIONS_JSON).unwrap();
+    let addresses = vec!["1KFHE7w8BhaENAswwryaoccDb6qcT6DbYY".to_string()];
+
+    let pool = definitions.identify(b"/slush/", &addresses).unwrap();
+    assert_eq!(pool.name, "F2Pool");
+}
+
+#[test]
+fn bundled_definitions_are_valid() {
+    let definitions = PoolDefinitions::parse(BUNDLED_POOLS).unwrap();
+    assert!(!definitions.coinbase_tags.is_empty());
+    assert_eq!(definitions.identify(b"/ViaBTC/Mined by x/", &[]).unwrap().name, "ViaBTC");
+}
+
+#[test]
+fn shares_are_sorted_by_block_count() {
+    let pools = vec![
+        Some("AntPool".to_string()),
+        None,
+        Some("Foundry USA".to_string()),
+        Some("Foundry USA".to_string()),
+    ];
+
+    let shares = pool_shares(&pools);
+    assert_eq!(shares[0], PoolShare { name: "Foundry USA".to_string(), blocks: 2, percent: 50.0 });
+    assert_eq!(shares[1].name, "AntPool");
+    assert_eq!(shares[2].name, "Unbekannt");
+    assert_eq!(shares[2].percent, 25.0);
+}
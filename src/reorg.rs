@@ -0,0 +1,156 @@
+use anyhow::Result;
+use crate::rpc::{BitcoinRPC, BlockHeaderSummary};
+use std::collections::VecDeque;
+use std::env;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+
+const DEFAULT_REORG_LOG_FILE: &str = "reorg_events.log";
+const TIP_HISTORY_SIZE: usize = 10;
+// Tiefere Reorgs werden nicht weiter zurückverfolgt
+const MAX_REORG_DEPTH: usize = 100;
+
+/// Zugriff auf Header und aktive Chain, getrennt vom RPC-Client für Tests
+pub trait ChainSource {
+    fn header(&self, hash: &str) -> Result<BlockHeaderSummary>;
+    fn block_hash(&self, height: u64) -> Result<String>;
+}
+
+impl ChainSource for BitcoinRPC {
+    fn header(&self, hash: &str) -> Result<BlockHeaderSummary> {
+        self.get_block_header_summary(hash)
+    }
+
+    fn block_hash(&self, height: u64) -> Result<String> {
+        self.get_block_hash_at(height)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReorgEvent {
+    pub fork_height: u64,
+    // Jeweils (Höhe, Hash), neuester Block zuerst
+    pub disconnected: Vec<(u64, String)>,
+    pub connected: Vec<(u64, String)>,
+}
+
+impl ReorgEvent {
+    pub fn depth(&self) -> usize {
+        self.disconnected.len()
+    }
+
+    pub fn message(&self) -> String {
+        format!(
+            "Reorg erkannt! Tiefe {} ab Höhe {}: {} Block/Blöcke getrennt, {} verbunden",
+            self.depth(),
+            self.fork_height,
+            self.disconnected.len(),
+            self.connected.len()
+        )
+    }
+
+    fn log_entry(&self) -> String {
+        let blocks = |blocks: &[(u64, String)]| blocks.iter()
+            .map(|(height, hash)| format!("{}:{}", height, hash))
+            .collect::<Vec<_>>()
+            .join(",");
+        format!(
+            "{} reorg depth={} fork_height={} disconnected=[{}] connected=[{}]",
+            chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC"),
+            self.depth(),
+            self.fork_height,
+            blocks(&self.disconnected),
+            blocks(&self.connected)
+        )
+    }
+}
+
+/// Merkt sich die letzten Best-Block-Hashes und erkennt Reorgs zwischen Aktualisierungen
+pub struct ReorgMonitor {
+    tips: VecDeque<(u64, String)>,
+    log_path: PathBuf,
+}
+
+impl ReorgMonitor {
+    /// Ereignisse landen in REORG_LOG_FILE (Standard: reorg_events.log)
+    pub fn new() -> Self {
+        let path = env::var("REORG_LOG_FILE").unwrap_or_else(|_| DEFAULT_REORG_LOG_FILE.to_string());
+        Self::with_log(PathBuf::from(path))
+    }
+
+    pub fn with_log(log_path: PathBuf) -> Self {
+        Self { tips: VecDeque::with_capacity(TIP_HISTORY_SIZE), log_path }
+    }
+
+    /// Prüft einen neuen Tip; liefert ein Ereignis, wenn der vorherige Tip nicht mehr aktiv ist
+    pub fn check(&mut self, source: &impl ChainSource, height: u64, hash: &str) -> Result<Option<ReorgEvent>> {
+        let Some((_, previous)) = self.tips.back().cloned() else {
+            self.push_tip(height, hash);
+            return Ok(None);
+        };
+        if previous == hash {
+            return Ok(None);
+        }
+
+        let event = if source.header(&previous)?.in_active_chain {
+            None
+        } else {
+            Some(trace_reorg(source, &previous, height)?)
+        };
+
+        if let Some(event) = &event {
+            if let Err(e) = self.append_log(event) {
+                log::warn!("Reorg-Log konnte nicht geschrieben werden: {}", e);
+            }
+        }
+        self.push_tip(height, hash);
+        Ok(event)
+    }
+
+    fn push_tip(&mut self, height: u64, hash: &str) {
+        if self.tips.len() >= TIP_HISTORY_SIZE {
+            self.tips.pop_front();
+        }
+        self.tips.push_back((height, hash.to_string()));
+    }
+
+    fn append_log(&self, event: &ReorgEvent) -> std::io::Result<()> {
+        let mut file = OpenOptions::new().create(true).append(true).open(&self.log_path)?;
+        writeln!(file, "{}", event.log_entry())
+    }
+}
+
+impl Default for ReorgMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Vom alten Tip zurücklaufen, bis ein Block wieder auf der aktiven Chain liegt
+fn trace_reorg(source: &impl ChainSource, old_tip: &str, new_height: u64) -> Result<ReorgEvent> {
+    let mut disconnected = Vec::new();
+    let mut hash = old_tip.to_string();
+
+    let fork_height = loop {
+        let header = source.header(&hash)?;
+        if header.in_active_chain {
+            break header.height;
+        }
+        disconnected.push((header.height, hash.clone()));
+
+        match header.previous {
+            Some(previous) if disconnected.len() < MAX_REORG_DEPTH => hash = previous,
+            _ => break header.height.saturating_sub(1),
+        }
+    };
+
+    let connected = (fork_height + 1..=new_height).rev()
+        .map(|height| source.block_hash(height).map(|hash| (height, hash)))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(ReorgEvent { fork_height, disconnected, connected })
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,77 @@
+use super::*;
+use std::collections::HashMap;
+
+// Aktive Chain: 100 -> a101 -> b102 -> b103, verwaister Zweig: a101 -> a102
+struct FakeChain {
+    headers: HashMap<String, BlockHeaderSummary>,
+    active: HashMap<u64, String>,
+}
+
+impl FakeChain {
+    fn new() -> Self {
+        let header = |height: u64, previous: &str, active: bool| BlockHeaderSummary {
+            height,
+            previous: Some(previous.to_string()),
+            in_active_chain: active,
+        };
+        let headers = HashMap::from([
+            ("h100".to_string(), header(100, "h99", true)),
+            ("a101".to_string(), header(101, "h100", true)),
+            ("a102".to_string(), header(102, "a101", false)),
+            ("b102".to_string(), header(102, "a101", true)),
+            ("b103".to_string(), header(103, "b102", true)),
+        ]);
+        let active = HashMap::from([
+            (100, "h100".to_string()),
+            (101, "a101".to_string()),
+            (102, "b102".to_string()),
+            (103, "b103".to_string()),
+        ]);
+        Self { headers, active }
+    }
+}
+
+impl ChainSource for FakeChain {
+    fn header(&self, hash: &str) -> Result<BlockHeaderSummary> {
+        self.headers.get(hash).cloned().ok_or_else(|| anyhow::anyhow!("unbekannt"))
+    }
+
+    fn block_hash(&self, height: u64) -> Result<String> {
+        self.active.get(&height).cloned().ok_or_else(|| anyhow::anyhow!("unbekannt"))
+    }
+}
+
+fn monitor() -> (ReorgMonitor, PathBuf) {
+    let path = std::env::temp_dir().join(format!("nodebridge-reorg-{}-{:?}.log",
+        std::process::id(), std::thread::current().id()));
+    let _ = std::fs::remove_file(&path);
+    (ReorgMonitor::with_log(path.clone()), path)
+}
+
+#[test]
+fn chain_extension_is_not_a_reorg() {
+    let chain = FakeChain::new();
+    let (mut monitor, path) = monitor();
+
+    assert_eq!(monitor.check(&chain, 101, "a101").unwrap(), None);
+    assert_eq!(monitor.check(&chain, 103, "b103").unwrap(), None);
+    assert!(!path.exists());
+}
+
+#[test]
+fn reorg_reports_depth_and_blocks() {
+    let chain = FakeChain::new();
+    let (mut monitor, path) = monitor();
+
+    monitor.check(&chain, 102, "a102").unwrap();
+    let event = monitor.check(&chain, 103, "b103").unwrap().unwrap();
+
+    assert_eq!(event.depth(), 1);
+    assert_eq!(event.fork_height, 101);
+    assert_eq!(event.disconnected, vec![(102, "a102".to_string())]);
+    assert_eq!(event.connected, vec![(103, "b103".to_string()), (102, "b102".to_string())]);
+
+    let log = std::fs::read_to_string(&path).unwrap();
+    assert!(log.contains("depth=1 fork_height=101"));
+    let _ = std::fs::remove_file(path);
+}
@@ -0,0 +1,163 @@
+use anyhow::Result;
+use bitcoincore_rpc::RpcApi;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+// Reihenfolge der Netzwerke in der Übersicht
+pub const ADDRMAN_NETWORKS: [&str; 5] = ["ipv4", "ipv6", "onion", "i2p", "cjdns"];
+// Stichprobe je Netzwerk aus getnodeaddresses
+const SAMPLE_SIZE: u64 = 8;
+
+/// Einträge im Address-Manager für ein Netzwerk (getaddrmaninfo)
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct AddrManCount {
+    pub network: String,
+    // Noch nie erfolgreich verbunden
+    pub new: u64,
+    // Mindestens einmal erfolgreich verbunden
+    pub tried: u64,
+    pub total: u64,
+}
+
+impl AddrManCount {
+    /// Anteil der Adressen, zu denen schon eine Verbindung bestand
+    pub fn tried_percent(&self) -> f64 {
+        if self.total == 0 {
+            0.0
+        } else {
+            self.tried as f64 / self.total as f64 * 100.0
+        }
+    }
+}
+
+/// Erreichbarkeit eines Netzwerks laut getnetworkinfo
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NetworkReachability {
+    pub network: String,
+    pub reachable: bool,
+    // Mit -onlynet ausgeschlossen
+    pub limited: bool,
+    pub proxy: String,
+}
+
+/// Ein Eintrag aus getnodeaddresses
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NodeAddress {
+    pub address: String,
+    pub port: u16,
+    pub network: String,
+    pub services: u64,
+    // Zuletzt gesehen (Unix-Zeit)
+    pub time: i64,
+}
+
+impl NodeAddress {
+    pub fn from_value(entry: &Value) -> Self {
+        Self {
+            address: entry.get("address").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+            port: entry.get("port").and_then(|v| v.as_u64()).unwrap_or(0) as u16,
+            network: entry.get("network").and_then(|v| v.as_str()).unwrap_or("unknown").to_string(),
+            services: entry.get("services").and_then(|v| v.as_u64()).unwrap_or(0),
+            time: entry.get("time").and_then(|v| v.as_i64()).unwrap_or(0),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct AddrManOverview {
+    // None, wenn der Node getaddrmaninfo nicht kennt (vor Core 26)
+    pub counts: Option<Vec<AddrManCount>>,
+    pub reachability: Vec<NetworkReachability>,
+    pub samples: Vec<NodeAddress>,
+}
+
+impl AddrManOverview {
+    pub fn count(&self, network: &str) -> Option<&AddrManCount> {
+        self.counts.as_ref()?.iter().find(|count| count.network == network)
+    }
+
+    pub fn reachability(&self, network: &str) -> Option<&NetworkReachability> {
+        self.reachability.iter().find(|r| r.network == network)
+    }
+}
+
+/// Antwort von getaddrmaninfo; "all_networks" steht als Summe am Ende
+pub fn parse_addrman_info(info: &Value) -> Vec<AddrManCount> {
+    ADDRMAN_NETWORKS.iter()
+        .copied()
+        .chain(["all_networks"])
+        .filter_map(|network| {
+            let entry = info.get(network)?;
+            let number = |key: &str| entry.get(key).and_then(|v| v.as_u64()).unwrap_or(0);
+            Some(AddrManCount {
+                network: network.to_string(),
+                new: number("new"),
+                tried: number("tried"),
+                total: number("total"),
+            })
+        })
+        .collect()
+}
+
+/// Feld "networks" aus getnetworkinfo
+pub fn parse_reachability(network_info: &Value) -> Vec<NetworkReachability> {
+    network_info.get("networks")
+        .and_then(|v| v.as_array())
+        .map(|networks| networks.iter()
+            .map(|network| NetworkReachability {
+                network: network.get("name").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+                reachable: network.get("reachable").and_then(|v| v.as_bool()).unwrap_or(false),
+                limited: network.get("limited").and_then(|v| v.as_bool()).unwrap_or(false),
+                proxy: network.get("proxy").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+            })
+            .collect())
+        .unwrap_or_default()
+}
+
+impl super::BitcoinRPC {
+    pub fn get_addrman_info(&self) -> Result<Vec<AddrManCount>> {
+        let info = self.client.call::<Value>("getaddrmaninfo", &[])?;
+        Ok(parse_addrman_info(&info))
+    }
+
+    /// `count` 0 liefert alle bekannten Adressen; ein Netzwerkfilter erfordert Core 22
+    pub fn get_node_addresses(&self, count: u64, network: Option<&str>) -> Result<Vec<NodeAddress>> {
+        let mut params = vec![json!(count)];
+        if let Some(network) = network {
+            params.push(json!(network));
+        }
+        let addresses = self.client.call::<Value>("getnodeaddresses", &params)?;
+        Ok(addresses.as_array()
+            .ok_or_else(|| anyhow::anyhow!("getnodeaddresses lieferte keine Liste"))?
+            .iter()
+            .map(NodeAddress::from_value)
+            .collect())
+    }
+
+    pub fn get_addrman_overview(&self) -> Result<AddrManOverview> {
+        let network_info = self.client.call::<Value>("getnetworkinfo", &[])?;
+        let reachability = parse_reachability(&network_info);
+
+        let counts = match self.get_addrman_info() {
+            Ok(counts) => Some(counts),
+            Err(e) => {
+                log::info!("getaddrmaninfo nicht verfügbar: {}", e);
+                None
+            }
+        };
+
+        // Stichprobe nur für Netzwerke, die der Node kennt
+        let mut samples = Vec::new();
+        for network in ADDRMAN_NETWORKS {
+            if !reachability.iter().any(|r| r.network == network) {
+                continue;
+            }
+            match self.get_node_addresses(SAMPLE_SIZE, Some(network)) {
+                Ok(addresses) => samples.extend(addresses),
+                Err(e) => log::warn!("getnodeaddresses für {} fehlgeschlagen: {}", network, e),
+            }
+        }
+
+        Ok(AddrManOverview { counts, reachability, samples })
+    }
+}
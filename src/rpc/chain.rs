@@ -0,0 +1,79 @@
+use anyhow::Result;
+use bitcoincore_rpc::RpcApi;
+use serde_json::{Value, json};
+
+// Längere Zweige (z.B. ungültige Header-Ketten) werden nur bis hier verfolgt
+const MAX_BRANCH_WALK: u64 = 100;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChainTip {
+    pub height: u64,
+    pub hash: String,
+    pub branch_len: u64,
+    // active, valid-fork, valid-headers, headers-only oder invalid
+    pub status: String,
+}
+
+impl ChainTip {
+    pub fn is_active(&self) -> bool {
+        self.status == "active"
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockHeaderSummary {
+    pub height: u64,
+    pub previous: Option<String>,
+    // getblockheader meldet confirmations = -1 für Blöcke außerhalb der aktiven Chain
+    pub in_active_chain: bool,
+}
+
+impl super::BitcoinRPC {
+    pub fn get_block_header_summary(&self, hash: &str) -> Result<BlockHeaderSummary> {
+        let header = self.client.call::<Value>("getblockheader", &[json!(hash), json!(true)])?;
+        Ok(BlockHeaderSummary {
+            height: header.get("height").and_then(|v| v.as_u64()).unwrap_or(0),
+            previous: header.get("previousblockhash").and_then(|v| v.as_str()).map(|s| s.to_string()),
+            in_active_chain: header.get("confirmations").and_then(|v| v.as_i64()).unwrap_or(-1) >= 0,
+        })
+    }
+
+    pub fn get_block_hash_at(&self, height: u64) -> Result<String> {
+        Ok(self.client.get_block_hash(height)?.to_string())
+    }
+
+    /// Alle bekannten Chain-Tips (getchaintips), aktiver Tip zuerst
+    pub fn get_chain_tips(&self) -> Result<Vec<ChainTip>> {
+        let tips = self.client.call::<Value>("getchaintips", &[])?;
+        let mut tips: Vec<ChainTip> = tips.as_array()
+            .ok_or_else(|| anyhow::anyhow!("getchaintips lieferte keine Liste"))?
+            .iter()
+            .map(|tip| ChainTip {
+                height: tip.get("height").and_then(|v| v.as_u64()).unwrap_or(0),
+                hash: tip.get("hash").and_then(|v| v.as_str()).unwrap_or("").to_string(),
+                branch_len: tip.get("branchlen").and_then(|v| v.as_u64()).unwrap_or(0),
+                status: tip.get("status").and_then(|v| v.as_str()).unwrap_or("unknown").to_string(),
+            })
+            .collect();
+
+        tips.sort_by(|a, b| b.is_active().cmp(&a.is_active()).then(b.height.cmp(&a.height)));
+        Ok(tips)
+    }
+
+    /// Blöcke eines Forks vom Tip bis zur Abzweigung als (Höhe, Hash)
+    pub fn get_fork_blocks(&self, tip: &ChainTip) -> Result<Vec<(u64, String)>> {
+        let mut blocks = vec![(tip.height, tip.hash.clone())];
+        let mut hash = tip.hash.clone();
+
+        // getblockheader funktioniert auch für Header ohne Blockdaten
+        for _ in 1..tip.branch_len.min(MAX_BRANCH_WALK) {
+            let header = self.client.call::<Value>("getblockheader", &[json!(hash), json!(true)])?;
+            let Some(previous) = header.get("previousblockhash").and_then(|v| v.as_str()) else { break };
+            hash = previous.to_string();
+            let height = blocks.last().map(|(h, _)| h.saturating_sub(1)).unwrap_or(0);
+            blocks.push((height, hash.clone()));
+        }
+
+        Ok(blocks)
+    }
+}
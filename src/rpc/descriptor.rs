@@ -0,0 +1,398 @@
+use anyhow::Result;
+use bitcoincore_rpc::{Client, RpcApi, jsonrpc};
+use bitcoincore_rpc::bitcoin::base58;
+use reqwest::blocking::Client as HttpClient;
+use serde_json::{Value, json};
+use std::collections::{HashMap, HashSet};
+use std::env;
+use std::time::{Duration, Instant};
+use parking_lot::Mutex;
+use once_cell::sync::Lazy;
+
+const DEFAULT_GAP_LIMIT: u32 = 20;
+// Obergrenze beim Verlängern des Gap-Limits, damit der Scan endlich bleibt
+const MAX_DERIVED_ADDRESSES: u32 = 1000;
+const CACHE_DURATION: Duration = Duration::from_secs(600);
+// scantxoutset durchsucht das komplette UTXO-Set und braucht entsprechend lange
+const SCAN_TIMEOUT: Duration = Duration::from_secs(600);
+
+// Versionsbytes für erweiterte öffentliche Schlüssel (SLIP-132)
+const XPUB_VERSION: [u8; 4] = [0x04, 0x88, 0xB2, 0x1E];
+const TPUB_VERSION: [u8; 4] = [0x04, 0x35, 0x87, 0xCF];
+
+#[derive(Debug, Clone)]
+pub struct PortfolioAddress {
+    pub index: u32,
+    pub address: String,
+    pub is_change: bool,
+    pub balance: f64,
+    pub utxo_count: usize,
+    // Hat jemals Zahlungen erhalten (auch wenn inzwischen ausgegeben)
+    pub used: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct PortfolioUtxo {
+    pub txid: String,
+    pub vout: u32,
+    pub amount: f64,
+    pub height: Option<u64>,
+    pub address: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct Portfolio {
+    pub descriptor: String,
+    pub gap_limit: u32,
+    // Abgeleitete Adressen pro Pfad, nach Verlängerung über die letzte benutzte Adresse hinaus
+    pub derived: u32,
+    pub addresses: Vec<PortfolioAddress>,
+    pub utxos: Vec<PortfolioUtxo>,
+    pub total: f64,
+    pub source: String,
+    // false, wenn nur UTXOs bekannt sind (ohne -blockfilterindex); ausgegebene Adressen gelten dann als leer
+    pub history_known: bool,
+}
+
+#[derive(Debug, Clone)]
+pub enum PortfolioState {
+    Loading,
+    Ready(Portfolio),
+    Failed(String),
+}
+
+struct PortfolioCache {
+    state: PortfolioState,
+    last_update: Instant,
+}
+
+static PORTFOLIO_CACHE: Lazy<Mutex<HashMap<String, PortfolioCache>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Prüft, ob die Eingabe ein Descriptor oder erweiterter Public Key ist
+pub fn is_descriptor_input(input: &str) -> bool {
+    let input = input.trim();
+    input.contains('(') || slip132_script(input).is_some()
+}
+
+// Liefert die Script-Vorlage für xpub/ypub/zpub (und Testnet-Varianten)
+fn slip132_script(input: &str) -> Option<(&'static str, [u8; 4])> {
+    match input.get(..4)? {
+        "xpub" => Some(("pkh({})", XPUB_VERSION)),
+        "ypub" => Some(("sh(wpkh({}))", XPUB_VERSION)),
+        "zpub" => Some(("wpkh({})", XPUB_VERSION)),
+        "tpub" => Some(("pkh({})", TPUB_VERSION)),
+        "upub" => Some(("sh(wpkh({}))", TPUB_VERSION)),
+        "vpub" => Some(("wpkh({})", TPUB_VERSION)),
+        _ => None,
+    }
+}
+
+/// Wandelt xpub/ypub/zpub in einen Descriptor um und teilt ihn in Empfangs- und Wechselpfad
+pub fn normalize_descriptor(input: &str) -> Result<(String, Option<String>)> {
+    let input = input.trim();
+
+    let descriptor = if let Some((template, version)) = slip132_script(input) {
+        let mut data = base58::decode_check(input)
+            .map_err(|e| anyhow::anyhow!("Ungültiger erweiterter Schlüssel: {}", e))?;
+        if data.len() != 78 {
+            return Err(anyhow::anyhow!("Ungültige Schlüssellänge: {} Bytes", data.len()));
+        }
+        data[..4].copy_from_slice(&version);
+        let key = base58::encode_check(&data);
+        template.replace("{}", &format!("{}/<0;1>/*", key))
+    } else if input.contains('(') {
+        // Prüfsumme entfernen, sie wird von getdescriptorinfo neu berechnet
+        input.split('#').next().unwrap_or(input).to_string()
+    } else {
+        return Err(anyhow::anyhow!("Weder Descriptor noch xpub/ypub/zpub: {}", input));
+    };
+
+    if descriptor.contains("<0;1>") {
+        Ok((descriptor.replace("<0;1>", "0"), Some(descriptor.replace("<0;1>", "1"))))
+    } else if descriptor.contains("/0/*") {
+        // Bei Multisig betrifft der Wechselpfad jeden Schlüssel
+        let change = descriptor.replace("/0/*", "/1/*");
+        Ok((descriptor, Some(change)))
+    } else {
+        Ok((descriptor, None))
+    }
+}
+
+fn gap_limit() -> u32 {
+    env::var("DESCRIPTOR_GAP_LIMIT")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .filter(|v| *v > 0)
+        .unwrap_or(DEFAULT_GAP_LIMIT)
+}
+
+/// Benötigte Adressen pro Pfad: hinter der letzten benutzten Adresse müssen `gap_limit` unbenutzte folgen
+fn required_count(addresses: &[PortfolioAddress], gap_limit: u32) -> u32 {
+    addresses.iter()
+        .filter(|a| a.used || a.utxo_count > 0)
+        .map(|a| a.index + 1 + gap_limit)
+        .max()
+        .unwrap_or(gap_limit)
+}
+
+/// Scan-Objekte für scantxoutset/scanblocks; "range" nur bei Descriptoren mit Wildcard, sonst lehnt Core ab
+fn scan_objects(descriptors: &[(String, bool)], gap_limit: u32) -> Vec<Value> {
+    descriptors.iter()
+        .map(|(desc, _)| if desc.contains('*') {
+            json!({ "desc": desc, "range": gap_limit - 1 })
+        } else {
+            json!({ "desc": desc })
+        })
+        .collect()
+}
+
+impl super::BitcoinRPC {
+    /// Liefert das Portfolio eines Descriptors; der Scan läuft im Hintergrund
+    pub fn get_portfolio(&self, input: &str) -> Result<PortfolioState> {
+        let key = input.trim().to_string();
+
+        let mut cache = PORTFOLIO_CACHE.lock();
+        if let Some(cached) = cache.get(&key) {
+            if matches!(cached.state, PortfolioState::Loading)
+                || cached.last_update.elapsed() < CACHE_DURATION
+            {
+                return Ok(cached.state.clone());
+            }
+        }
+
+        // Eingabe vorab prüfen, damit Fehler sofort sichtbar sind
+        normalize_descriptor(&key)?;
+
+        cache.insert(key.clone(), PortfolioCache {
+            state: PortfolioState::Loading,
+            last_update: Instant::now(),
+        });
+        drop(cache);
+
+        let rpc = self.clone();
+        std::thread::spawn(move || {
+            let state = match rpc.scan_portfolio(&key) {
+                Ok(portfolio) => PortfolioState::Ready(portfolio),
+                Err(e) => PortfolioState::Failed(e.to_string()),
+            };
+            PORTFOLIO_CACHE.lock().insert(key, PortfolioCache {
+                state,
+                last_update: Instant::now(),
+            });
+        });
+
+        Ok(PortfolioState::Loading)
+    }
+
+    fn scan_portfolio(&self, input: &str) -> Result<Portfolio> {
+        let (receive, change) = normalize_descriptor(input)?;
+        let gap_limit = gap_limit();
+
+        let mut descriptors = vec![(self.descriptor_with_checksum(&receive)?, false)];
+        if let Some(change) = change {
+            descriptors.push((self.descriptor_with_checksum(&change)?, true));
+        }
+        let ranged = descriptors.iter().any(|(descriptor, _)| descriptor.contains('*'));
+
+        // Fenster verlängern, bis hinter der letzten benutzten Adresse gap_limit leere folgen
+        let mut count = gap_limit;
+        loop {
+            let (mut addresses, scripts) = self.derive_portfolio_addresses(&descriptors, count)?;
+
+            let history = match self.used_scripts(&descriptors, count, &scripts) {
+                Ok(used) => Some(used),
+                Err(e) => {
+                    self.log_debug(&format!("scanblocks nicht verfügbar, Verlauf unbekannt: {}", e));
+                    None
+                }
+            };
+
+            let (utxos, source, history) = match self.scan_utxo_set(&descriptors, count, &addresses, &scripts) {
+                Ok((utxos, source)) => (utxos, source, history),
+                Err(e) => {
+                    self.log_debug(&format!("scantxoutset fehlgeschlagen, nutze Explorer: {}", e));
+                    let (utxos, used) = self.explorer_utxos(&addresses)?;
+                    (utxos, "mempool.space".to_string(), Some(used))
+                }
+            };
+
+            for utxo in &utxos {
+                if let Some(entry) = addresses.iter_mut().find(|a| a.address == utxo.address) {
+                    entry.balance += utxo.amount;
+                    entry.utxo_count += 1;
+                }
+            }
+            for index in history.iter().flatten() {
+                addresses[*index].used = true;
+            }
+
+            let required = required_count(&addresses, gap_limit).min(MAX_DERIVED_ADDRESSES);
+            if !ranged || required <= count {
+                return Ok(Portfolio {
+                    descriptor: descriptors[0].0.clone(),
+                    gap_limit,
+                    derived: count,
+                    total: utxos.iter().map(|u| u.amount).sum(),
+                    addresses,
+                    utxos,
+                    source,
+                    history_known: history.is_some(),
+                });
+            }
+            count = required;
+        }
+    }
+
+    // Adressen über den Node ableiten (deriveaddresses); Schlüssel der Map ist das scriptPubKey als Hex
+    fn derive_portfolio_addresses(
+        &self,
+        descriptors: &[(String, bool)],
+        count: u32,
+    ) -> Result<(Vec<PortfolioAddress>, HashMap<String, usize>)> {
+        let mut addresses = Vec::new();
+        let mut scripts = HashMap::new();
+        for (descriptor, is_change) in descriptors {
+            let range = descriptor.contains('*').then_some([0, count - 1]);
+            for (index, address) in self.client.derive_addresses(descriptor, range)?.into_iter().enumerate() {
+                let address = address.assume_checked();
+                scripts.insert(hex::encode(address.script_pubkey().as_bytes()), addresses.len());
+                addresses.push(PortfolioAddress {
+                    index: index as u32,
+                    address: address.to_string(),
+                    is_change: *is_change,
+                    balance: 0.0,
+                    utxo_count: 0,
+                    used: false,
+                });
+            }
+        }
+        Ok((addresses, scripts))
+    }
+
+    /// Adressen mit Zahlungseingang laut Blockfiltern (scanblocks, erfordert -blockfilterindex)
+    fn used_scripts(
+        &self,
+        descriptors: &[(String, bool)],
+        count: u32,
+        scripts: &HashMap<String, usize>,
+    ) -> Result<HashSet<usize>> {
+        let result = self.long_running_client()?
+            .call::<Value>("scanblocks", &[json!("start"), json!(scan_objects(descriptors, count))])?;
+
+        // Blockfilter können falsch-positiv sein, daher die Outputs der Blöcke selbst prüfen
+        let mut used = HashSet::new();
+        for hash in result.get("relevant_blocks").and_then(|v| v.as_array()).into_iter().flatten() {
+            let block = self.client.call::<Value>("getblock", &[hash.clone(), json!(2)])?;
+            let outputs = block.get("tx").and_then(|v| v.as_array()).into_iter().flatten()
+                .flat_map(|tx| tx.get("vout").and_then(|v| v.as_array()).into_iter().flatten());
+            for output in outputs {
+                let script = output.get("scriptPubKey").and_then(|s| s.get("hex")).and_then(|v| v.as_str());
+                if let Some(index) = script.and_then(|script| scripts.get(script)) {
+                    used.insert(*index);
+                }
+            }
+        }
+        Ok(used)
+    }
+
+    fn descriptor_with_checksum(&self, descriptor: &str) -> Result<String> {
+        let info = self.client.call::<Value>("getdescriptorinfo", &[json!(descriptor)])?;
+        info.get("descriptor")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string())
+            .ok_or_else(|| anyhow::anyhow!("Descriptor konnte nicht geprüft werden"))
+    }
+
+    fn scan_utxo_set(
+        &self,
+        descriptors: &[(String, bool)],
+        gap_limit: u32,
+        addresses: &[PortfolioAddress],
+        scripts: &HashMap<String, usize>,
+    ) -> Result<(Vec<PortfolioUtxo>, String)> {
+        let result = self.long_running_client()?
+            .call::<Value>("scantxoutset", &[json!("start"), json!(scan_objects(descriptors, gap_limit))])?;
+
+        if !result.get("success").and_then(|v| v.as_bool()).unwrap_or(false) {
+            return Err(anyhow::anyhow!("scantxoutset wurde abgebrochen"));
+        }
+
+        let utxos = result.get("unspents")
+            .and_then(|v| v.as_array())
+            .map(|unspents| unspents.iter().map(|u| {
+                let script = u.get("scriptPubKey").and_then(|v| v.as_str()).unwrap_or("");
+                PortfolioUtxo {
+                    txid: u.get("txid").and_then(|v| v.as_str()).unwrap_or("").to_string(),
+                    vout: u.get("vout").and_then(|v| v.as_u64()).unwrap_or(0) as u32,
+                    amount: u.get("amount").and_then(|v| v.as_f64()).unwrap_or(0.0),
+                    height: u.get("height").and_then(|v| v.as_u64()),
+                    address: scripts.get(script)
+                        .map(|i| addresses[*i].address.clone())
+                        .unwrap_or_else(|| "Unbekannte Adresse".to_string()),
+                }
+            }).collect())
+            .unwrap_or_default();
+
+        let height = result.get("height").and_then(|v| v.as_u64()).unwrap_or(0);
+        Ok((utxos, format!("scantxoutset (Höhe {})", height)))
+    }
+
+    /// UTXOs und benutzte Adressen (Indizes in `addresses`) laut mempool.space
+    fn explorer_utxos(&self, addresses: &[PortfolioAddress]) -> Result<(Vec<PortfolioUtxo>, HashSet<usize>)> {
+        let client = HttpClient::new();
+        let get = |url: String| -> Result<Value> {
+            let response = client.get(&url).send()
+                .map_err(|e| anyhow::anyhow!("Netzwerk Fehler: {}", e))?;
+            if !response.status().is_success() {
+                return Err(anyhow::anyhow!("API Fehler: {}", response.status()));
+            }
+            Ok(response.json()?)
+        };
+
+        let mut utxos = Vec::new();
+        let mut used = HashSet::new();
+        for (index, entry) in addresses.iter().enumerate() {
+            let stats = get(format!("https://mempool.space/api/address/{}", entry.address))?;
+            let count = |scope: &str, key: &str| stats.get(scope)
+                .and_then(|s| s.get(key))
+                .and_then(|v| v.as_u64())
+                .unwrap_or(0);
+            let funded = count("chain_stats", "funded_txo_count") + count("mempool_stats", "funded_txo_count");
+            let spent = count("chain_stats", "spent_txo_count") + count("mempool_stats", "spent_txo_count");
+            if funded == 0 {
+                continue;
+            }
+            used.insert(index);
+            if funded == spent {
+                continue;
+            }
+
+            let data = get(format!("https://mempool.space/api/address/{}/utxo", entry.address))?;
+            for u in data.as_array().into_iter().flatten() {
+                utxos.push(PortfolioUtxo {
+                    txid: u.get("txid").and_then(|v| v.as_str()).unwrap_or("").to_string(),
+                    vout: u.get("vout").and_then(|v| v.as_u64()).unwrap_or(0) as u32,
+                    amount: u.get("value").and_then(|v| v.as_u64()).unwrap_or(0) as f64 / 100_000_000.0,
+                    height: u.get("status").and_then(|s| s.get("block_height")).and_then(|v| v.as_u64()),
+                    address: entry.address.clone(),
+                });
+            }
+        }
+
+        Ok((utxos, used))
+    }
+
+    // Client mit langem Timeout für aufwendige Aufrufe wie scantxoutset
+    fn long_running_client(&self) -> Result<Client> {
+        let (user, pass) = self.auth.clone().get_user_pass()?;
+        let transport = jsonrpc::simple_http::SimpleHttpTransport::builder()
+            .url(&self.rpc_url)?
+            .timeout(SCAN_TIMEOUT)
+            .auth(user.unwrap_or_default(), pass)
+            .build();
+        Ok(Client::from_jsonrpc(jsonrpc::client::Client::with_transport(transport)))
+    }
+}
+
+#[cfg(test)]
+mod tests;
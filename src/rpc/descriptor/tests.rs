@@ -0,0 +1,83 @@
+use super::*;
+
+// Account-Schlüssel aus den BIP84-Testvektoren
+const ZPUB: &str = "zpub6rFR7y4Q2AijBEqTUquhVz398htDFrtymD9xYYfG1m4wAcvPhXNfE3EfH1r1ADqtfSdVCToUG868RvUUkgDKf31mGDtKsAYz2oz2AGutZYs";
+
+#[test]
+fn zpub_becomes_wpkh_descriptor() {
+    let (receive, change) = normalize_descriptor(ZPUB).unwrap();
+
+    assert!(receive.starts_with("wpkh(xpub"));
+    assert!(receive.ends_with("/0/*)"));
+    assert_eq!(change.unwrap(), receive.replace("/0/*)", "/1/*)"));
+
+    // Nur die Versionsbytes dürfen sich ändern
+    let xpub = &receive["wpkh(".len()..receive.len() - "/0/*)".len()];
+    let original = base58::decode_check(ZPUB).unwrap();
+    let converted = base58::decode_check(xpub).unwrap();
+    assert_eq!(converted[..4], XPUB_VERSION);
+    assert_eq!(converted[4..], original[4..]);
+}
+
+#[test]
+fn descriptor_checksum_is_stripped_and_change_derived() {
+    let (receive, change) = normalize_descriptor("tr([d34db33f/86'/0'/0']xpubABC/0/*)#abcd1234").unwrap();
+
+    assert_eq!(receive, "tr([d34db33f/86'/0'/0']xpubABC/0/*)");
+    assert_eq!(change.as_deref(), Some("tr([d34db33f/86'/0'/0']xpubABC/1/*)"));
+}
+
+#[test]
+fn multipath_descriptor_is_split() {
+    let (receive, change) = normalize_descriptor("wpkh(xpubABC/<0;1>/*)").unwrap();
+
+    assert_eq!(receive, "wpkh(xpubABC/0/*)");
+    assert_eq!(change.as_deref(), Some("wpkh(xpubABC/1/*)"));
+}
+
+#[test]
+fn plain_address_is_not_a_descriptor() {
+    assert!(!is_descriptor_input("bc1p38hzyl8p5yyqnzgkcxttr6ac0wc0ae8gpv7rld79df88qkrva38s78e8wd"));
+    assert!(is_descriptor_input(ZPUB));
+    assert!(is_descriptor_input("addr(bc1qexample)"));
+    assert!(normalize_descriptor("bc1qexample").is_err());
+}
+
+#[test]
+fn range_only_for_ranged_descriptors() {
+    let descriptors = vec![
+        ("wpkh(xpubABC/0/*)#checksum".to_string(), false),
+        ("addr(bc1qexample)#checksum".to_string(), false),
+        ("wpkh(02f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9)#checksum".to_string(), false),
+    ];
+    let objects = scan_objects(&descriptors, 20);
+
+    assert_eq!(objects[0], json!({ "desc": "wpkh(xpubABC/0/*)#checksum", "range": 19 }));
+    assert_eq!(objects[1], json!({ "desc": "addr(bc1qexample)#checksum" }));
+    assert!(objects[2].get("range").is_none());
+}
+
+#[test]
+fn multisig_change_path_covers_every_key() {
+    let (receive, change) = normalize_descriptor("wsh(multi(2,xpubA/0/*,xpubB/0/*))").unwrap();
+
+    assert_eq!(receive, "wsh(multi(2,xpubA/0/*,xpubB/0/*))");
+    assert_eq!(change.as_deref(), Some("wsh(multi(2,xpubA/1/*,xpubB/1/*))"));
+}
+
+#[test]
+fn gap_limit_extends_past_last_used_address() {
+    let address = |index: u32, is_change: bool, used: bool, utxo_count: usize| PortfolioAddress {
+        index,
+        address: format!("bc1q{}", index),
+        is_change,
+        balance: 0.0,
+        utxo_count,
+        used,
+    };
+
+    assert_eq!(required_count(&[address(0, false, false, 0)], 20), 20);
+    // Ausgegebene Adresse zählt als benutzt, auch ohne UTXO
+    assert_eq!(required_count(&[address(5, false, true, 0), address(3, true, false, 1)], 20), 26);
+    assert_eq!(required_count(&[address(19, false, true, 1)], 20), 40);
+}
@@ -1,21 +1,35 @@
 use anyhow::Result;
+use bitcoincore_rpc::RpcApi;
+use serde::Serialize;
 use serde_json::Value;
 use reqwest::blocking::Client as HttpClient;
+use std::collections::VecDeque;
+use std::env;
+use std::path::PathBuf;
 use std::time::{Instant, Duration};
 use std::sync::Mutex;
 use once_cell::sync::Lazy;
 
-#[derive(Debug, Clone)]
+// 720 Samples entsprechen bei 30s Cache-Dauer etwa 6 Stunden
+const DEFAULT_HISTORY_SIZE: usize = 720;
+// Vergleichsabstand für die Trendanzeige in Samples
+const TREND_WINDOW: usize = 10;
+// Änderungen unter 2% gelten als stabil
+const TREND_THRESHOLD: f64 = 0.02;
+
+#[derive(Debug, Clone, Serialize)]
 pub struct MempoolStats {
     pub tx_count: u64,
     pub size: u64,  // Größe in Bytes
+    pub total_fee: f64,  // Summe aller Gebühren in BTC
+    pub min_fee: f64,  // Mindest-Feerate des Node-Mempools in sat/vB
     pub no_priority: FeeCategory,
     pub low_priority: FeeCategory,
     pub medium_priority: FeeCategory,
     pub high_priority: FeeCategory,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct FeeCategory {
     pub count: u64,
     pub rate: f64,
@@ -29,6 +43,126 @@ struct MempoolCache {
 
 static MEMPOOL_CACHE: Lazy<Mutex<Option<MempoolCache>>> = Lazy::new(|| Mutex::new(None));
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct MempoolSample {
+    pub timestamp: i64,
+    pub tx_count: u64,
+    pub vsize: u64,
+    pub total_fee: f64,
+    pub min_fee: f64,
+}
+
+impl MempoolSample {
+    fn to_line(&self) -> String {
+        format!("{},{},{},{},{}", self.timestamp, self.tx_count, self.vsize, self.total_fee, self.min_fee)
+    }
+
+    fn parse(line: &str) -> Option<Self> {
+        let mut parts = line.trim().split(',');
+        Some(Self {
+            timestamp: parts.next()?.parse().ok()?,
+            tx_count: parts.next()?.parse().ok()?,
+            vsize: parts.next()?.parse().ok()?,
+            total_fee: parts.next()?.parse().ok()?,
+            min_fee: parts.next()?.parse().ok()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Trend {
+    Rising,
+    Falling,
+    Stable,
+}
+
+impl Trend {
+    /// Vergleicht den letzten Wert mit dem Wert TREND_WINDOW Samples zuvor
+    pub fn from_series(values: &[f64]) -> Trend {
+        let Some(&current) = values.last() else { return Trend::Stable };
+        let reference = values[values.len().saturating_sub(TREND_WINDOW + 1)];
+
+        if reference == 0.0 {
+            return if current > 0.0 { Trend::Rising } else { Trend::Stable };
+        }
+        match (current - reference) / reference {
+            change if change > TREND_THRESHOLD => Trend::Rising,
+            change if change < -TREND_THRESHOLD => Trend::Falling,
+            _ => Trend::Stable,
+        }
+    }
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Trend::Rising => "↑",
+            Trend::Falling => "↓",
+            Trend::Stable => "→",
+        }
+    }
+}
+
+/// Ringpuffer der Mempool-Samples, optional in MEMPOOL_HISTORY_FILE gespeichert
+pub struct MempoolHistory {
+    samples: VecDeque<MempoolSample>,
+    capacity: usize,
+    path: Option<PathBuf>,
+}
+
+impl MempoolHistory {
+    pub fn new(capacity: usize, path: Option<PathBuf>) -> Self {
+        let mut history = Self {
+            samples: VecDeque::with_capacity(capacity),
+            capacity: capacity.max(1),
+            path: None,
+        };
+
+        // Gespeicherten Verlauf übernehmen, überzählige alte Samples fallen raus
+        if let Some(content) = path.as_ref().and_then(|p| std::fs::read_to_string(p).ok()) {
+            for sample in content.lines().filter_map(MempoolSample::parse) {
+                history.push(sample);
+            }
+        }
+        history.path = path;
+        history
+    }
+
+    fn from_env() -> Self {
+        let capacity = env::var("MEMPOOL_HISTORY_SIZE")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_HISTORY_SIZE);
+        let path = env::var("MEMPOOL_HISTORY_FILE").ok()
+            .filter(|p| !p.is_empty())
+            .map(PathBuf::from);
+        Self::new(capacity, path)
+    }
+
+    pub fn push(&mut self, sample: MempoolSample) {
+        while self.samples.len() >= self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(sample);
+
+        if let Some(path) = &self.path {
+            let content: String = self.samples.iter().map(|s| s.to_line() + "\n").collect();
+            if let Err(e) = std::fs::write(path, content) {
+                log::warn!("Mempool-Verlauf konnte nicht gespeichert werden: {}", e);
+            }
+        }
+    }
+
+    pub fn samples(&self) -> Vec<MempoolSample> {
+        self.samples.iter().cloned().collect()
+    }
+}
+
+static MEMPOOL_HISTORY: Lazy<Mutex<MempoolHistory>> = Lazy::new(|| Mutex::new(MempoolHistory::from_env()));
+
+/// Bisher gesammelte Mempool-Samples, älteste zuerst
+pub fn mempool_history() -> Vec<MempoolSample> {
+    MEMPOOL_HISTORY.lock().map(|history| history.samples()).unwrap_or_default()
+}
+
 impl super::BitcoinRPC {
     pub fn get_mempool_stats(&self) -> Result<MempoolStats> {
         const CACHE_DURATION: Duration = Duration::from_secs(30);
@@ -53,6 +187,14 @@ impl super::BitcoinRPC {
         // Basis-Statistiken
         let tx_count = mempool_data.get("count").and_then(|v| v.as_u64()).unwrap_or(0);
         let size = mempool_data.get("vsize").and_then(|v| v.as_u64()).unwrap_or(0);
+        let total_fee = mempool_data.get("total_fee").and_then(|v| v.as_f64()).unwrap_or(0.0) / 100_000_000.0;
+
+        // Mindest-Feerate kommt vom eigenen Node (mempoolminfee in BTC/kvB)
+        let min_fee = self.client.call::<Value>("getmempoolinfo", &[])
+            .ok()
+            .and_then(|info| info.get("mempoolminfee").and_then(|v| v.as_f64()))
+            .map(|fee| fee * 100_000.0)
+            .unwrap_or(0.0);
 
         self.log_debug(&format!("Mempool Details:
 - Transaktionen: {}
@@ -118,12 +260,25 @@ impl super::BitcoinRPC {
         let stats = MempoolStats {
             tx_count,
             size,
+            total_fee,
+            min_fee,
             no_priority: categories[0].clone(),
             low_priority: categories[1].clone(),
             medium_priority: categories[2].clone(),
             high_priority: categories[3].clone(),
         };
 
+        // Jede frische Abfrage landet im Verlauf
+        if let Ok(mut history) = MEMPOOL_HISTORY.lock() {
+            history.push(MempoolSample {
+                timestamp: chrono::Utc::now().timestamp(),
+                tx_count,
+                vsize: size,
+                total_fee,
+                min_fee,
+            });
+        }
+
         // Cache aktualisieren
         if let Ok(mut cache) = MEMPOOL_CACHE.lock() {
             *cache = Some(MempoolCache {
@@ -134,4 +289,7 @@ impl super::BitcoinRPC {
 
         Ok(stats)
     }
-} 
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests;
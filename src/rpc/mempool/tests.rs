@@ -0,0 +1,46 @@
+use super::*;
+
+fn sample(timestamp: i64, tx_count: u64) -> MempoolSample {
+    MempoolSample {
+        timestamp,
+        tx_count,
+        vsize: tx_count * 250,
+        total_fee: 0.5,
+        min_fee: 1.01,
+    }
+}
+
+#[test]
+fn history_drops_oldest_samples() {
+    let mut history = MempoolHistory::new(3, None);
+    for i in 0..5 {
+        history.push(sample(i, i as u64));
+    }
+
+    let timestamps: Vec<i64> = history.samples().iter().map(|s| s.timestamp).collect();
+    assert_eq!(timestamps, vec![2, 3, 4]);
+}
+
+#[test]
+fn history_is_persisted_and_reloaded() {
+    let path = std::env::temp_dir().join(format!("nodebridge-mempool-{}.csv", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+
+    let mut history = MempoolHistory::new(10, Some(path.clone()));
+    history.push(sample(1, 100));
+    history.push(sample(2, 200));
+
+    // Kleinere Kapazität beim Laden behält nur die neuesten Samples
+    let reloaded = MempoolHistory::new(1, Some(path.clone()));
+    assert_eq!(reloaded.samples(), vec![sample(2, 200)]);
+
+    let _ = std::fs::remove_file(path);
+}
+
+#[test]
+fn trend_direction() {
+    assert_eq!(Trend::from_series(&[]), Trend::Stable);
+    assert_eq!(Trend::from_series(&[100.0, 150.0]), Trend::Rising);
+    assert_eq!(Trend::from_series(&[100.0, 50.0]), Trend::Falling);
+    assert_eq!(Trend::from_series(&[100.0, 101.0]), Trend::Stable);
+}
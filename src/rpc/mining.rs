@@ -0,0 +1,330 @@
+use anyhow::Result;
+use bitcoincore_rpc::RpcApi;
+use crate::pools::{self, PoolShare};
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use serde::Serialize;
+use serde_json::{Value, json};
+use std::collections::{BTreeSet, HashMap};
+use std::env;
+
+pub const ADJUSTMENT_INTERVAL: u64 = 2016;
+pub const HALVING_INTERVAL: u64 = 210_000;
+// Zielabstand zwischen zwei Blöcken in Sekunden
+const TARGET_BLOCK_TIME: f64 = 600.0;
+const INITIAL_SUBSIDY: u64 = 50 * 100_000_000;
+// Der Konsens begrenzt jede Anpassung auf Faktor 4 in beide Richtungen
+const MAX_ADJUSTMENT_FACTOR: f64 = 4.0;
+
+// Anzahl der Transaktionen mit der höchsten Feerate in der Vorschau
+const TOP_TEMPLATE_TRANSACTIONS: usize = 10;
+
+// Standardfenster für die Pool-Verteilung (etwa ein Tag)
+const DEFAULT_POOL_SHARE_BLOCKS: u64 = 144;
+
+// Pool je Block-Hash; Blöcke ändern sich nicht, daher ohne Ablaufzeit
+static BLOCK_POOLS: Lazy<Mutex<HashMap<String, Option<String>>>> = Lazy::new(|| {
+    Mutex::new(HashMap::new())
+});
+
+// Zeitfenster für getnetworkhashps; -1 = seit der letzten Difficulty-Anpassung
+const HASHRATE_WINDOWS: [(&str, i64); 3] = [
+    ("120 Blöcke", 120),
+    ("Epoche", -1),
+    ("1 Tag", 144),
+];
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DifficultyAdjustment {
+    pub blocks_until: i64,
+    // Geschätzte Änderung in Prozent
+    pub percent: f64,
+    // Durchschnittlicher Blockabstand der laufenden Epoche in Sekunden
+    pub avg_block_time: f64,
+    // Geschätzter Zeitpunkt der Anpassung (Unix-Zeit)
+    pub estimated_time: i64,
+}
+
+impl DifficultyAdjustment {
+    /// Hochrechnung aus dem Zeitstempel des ersten Epochen-Blocks und dem aktuellen Tip
+    pub fn estimate(height: u64, epoch_start_time: i64, tip_time: i64) -> Self {
+        let blocks_since = height % ADJUSTMENT_INTERVAL;
+        let blocks_until = (ADJUSTMENT_INTERVAL - blocks_since) as i64;
+
+        // Direkt nach einer Anpassung gibt es noch keinen Messwert
+        let avg_block_time = if blocks_since > 0 && tip_time > epoch_start_time {
+            (tip_time - epoch_start_time) as f64 / blocks_since as f64
+        } else {
+            TARGET_BLOCK_TIME
+        };
+        let factor = (TARGET_BLOCK_TIME / avg_block_time)
+            .clamp(1.0 / MAX_ADJUSTMENT_FACTOR, MAX_ADJUSTMENT_FACTOR);
+
+        Self {
+            blocks_until,
+            percent: (factor - 1.0) * 100.0,
+            avg_block_time,
+            estimated_time: tip_time + (blocks_until as f64 * avg_block_time) as i64,
+        }
+    }
+
+    pub fn next_difficulty(&self, difficulty: f64) -> f64 {
+        difficulty * (1.0 + self.percent / 100.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Halving {
+    pub height: u64,
+    pub blocks_until: u64,
+    // Block-Subsidy in Satoshi
+    pub current_subsidy: u64,
+    pub next_subsidy: u64,
+    pub estimated_time: i64,
+}
+
+impl Halving {
+    /// Nächste Halbierung nach `height`, geschätzt mit 10 Minuten pro Block
+    pub fn after(height: u64, tip_time: i64) -> Self {
+        let next = (height / HALVING_INTERVAL + 1) * HALVING_INTERVAL;
+        let blocks_until = next - height;
+        Self {
+            height: next,
+            blocks_until,
+            current_subsidy: block_subsidy(height),
+            next_subsidy: block_subsidy(next),
+            estimated_time: tip_time + (blocks_until as f64 * TARGET_BLOCK_TIME) as i64,
+        }
+    }
+}
+
+/// Block-Subsidy in Satoshi wie in GetBlockSubsidy von Bitcoin Core
+pub fn block_subsidy(height: u64) -> u64 {
+    let halvings = height / HALVING_INTERVAL;
+    if halvings >= 64 {
+        0
+    } else {
+        INITIAL_SUBSIDY >> halvings
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MiningStats {
+    pub height: u64,
+    pub difficulty: f64,
+    // (Zeitfenster, Hashrate in H/s)
+    pub hashrates: Vec<(String, f64)>,
+    pub adjustment: DifficultyAdjustment,
+    pub halving: Halving,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TemplateTransaction {
+    pub txid: String,
+    // Gebühr in Satoshi
+    pub fee: u64,
+    pub weight: u64,
+    // Feerate des Pakets mit Vorfahren bzw. CPFP-Kindern, mit dem die TX ins Template kam
+    pub package_feerate: f64,
+}
+
+/// Feerate in sat/vB aus Gebühr (sat) und Gewicht (WU)
+fn feerate(fee: u64, weight: u64) -> f64 {
+    if weight == 0 {
+        0.0
+    } else {
+        fee as f64 * 4.0 / weight as f64
+    }
+}
+
+/// Vorschau des nächsten Blocks, den der Node minen würde (getblocktemplate)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BlockTemplate {
+    pub height: u64,
+    pub tx_count: usize,
+    // Gebühren und Coinbase-Wert (Subsidy + Gebühren) in Satoshi
+    pub total_fees: u64,
+    pub coinbase_value: u64,
+    // Gewicht aller Transaktionen ohne Coinbase
+    pub weight: u64,
+    pub weight_limit: u64,
+    // Niedrigste und höchste Paket-Feerate im Template in sat/vB
+    pub min_feerate: Option<f64>,
+    pub max_feerate: Option<f64>,
+    pub top_transactions: Vec<TemplateTransaction>,
+}
+
+impl BlockTemplate {
+    pub fn from_value(template: &Value) -> Result<Self> {
+        let entries = template.get("transactions")
+            .and_then(|v| v.as_array())
+            .ok_or_else(|| anyhow::anyhow!("getblocktemplate lieferte keine Transaktionsliste"))?;
+        let mut transactions: Vec<TemplateTransaction> = entries.iter()
+            .map(|tx| TemplateTransaction {
+                txid: tx.get("txid").and_then(|v| v.as_str()).unwrap_or("").to_string(),
+                fee: tx.get("fee").and_then(|v| v.as_u64()).unwrap_or(0),
+                weight: tx.get("weight").and_then(|v| v.as_u64()).unwrap_or(0),
+                package_feerate: 0.0,
+            })
+            .collect();
+
+        // "depends" nennt 1-basierte Indizes früherer Template-Einträge; Vorfahren transitiv sammeln
+        let mut ancestors: Vec<BTreeSet<usize>> = Vec::with_capacity(entries.len());
+        for (index, tx) in entries.iter().enumerate() {
+            let mut set = BTreeSet::new();
+            for parent in tx.get("depends").and_then(|v| v.as_array()).into_iter().flatten() {
+                if let Some(parent) = parent.as_u64().map(|p| p as usize).filter(|p| (1..=index).contains(p)) {
+                    set.insert(parent - 1);
+                    set.extend(ancestors[parent - 1].iter().copied());
+                }
+            }
+            ancestors.push(set);
+        }
+        let ancestor_feerates: Vec<f64> = ancestors.iter().enumerate()
+            .map(|(index, set)| {
+                let package = set.iter().chain([&index]).map(|i| &transactions[*i]);
+                let (fee, weight) = package.fold((0, 0), |(fee, weight), tx| (fee + tx.fee, weight + tx.weight));
+                feerate(fee, weight)
+            })
+            .collect();
+        // Eltern zählen mit der Rate des besten Kindes, das sie ins Template gezogen hat
+        for (index, tx) in transactions.iter_mut().enumerate() {
+            tx.package_feerate = ancestor_feerates[index];
+        }
+        for (index, set) in ancestors.iter().enumerate() {
+            for ancestor in set {
+                let rate = transactions[*ancestor].package_feerate.max(ancestor_feerates[index]);
+                transactions[*ancestor].package_feerate = rate;
+            }
+        }
+
+        let feerates = transactions.iter().map(|tx| tx.package_feerate);
+        let mut top_transactions = transactions.clone();
+        top_transactions.sort_by(|a, b| b.package_feerate.total_cmp(&a.package_feerate));
+        top_transactions.truncate(TOP_TEMPLATE_TRANSACTIONS);
+
+        Ok(Self {
+            height: template.get("height").and_then(|v| v.as_u64()).unwrap_or(0),
+            tx_count: transactions.len(),
+            total_fees: transactions.iter().map(|tx| tx.fee).sum(),
+            coinbase_value: template.get("coinbasevalue").and_then(|v| v.as_u64()).unwrap_or(0),
+            weight: transactions.iter().map(|tx| tx.weight).sum(),
+            weight_limit: template.get("weightlimit").and_then(|v| v.as_u64()).unwrap_or(4_000_000),
+            min_feerate: feerates.clone().reduce(f64::min),
+            max_feerate: feerates.reduce(f64::max),
+            top_transactions,
+        })
+    }
+
+    pub fn weight_percent(&self) -> f64 {
+        self.weight as f64 / self.weight_limit.max(1) as f64 * 100.0
+    }
+}
+
+impl super::BitcoinRPC {
+    /// Geschätzte Netzwerk-Hashrate über die letzten `blocks` Blöcke (-1 = aktuelle Epoche)
+    pub fn get_network_hashps(&self, blocks: i64) -> Result<f64> {
+        Ok(self.client.call::<f64>("getnetworkhashps", &[json!(blocks)])?)
+    }
+
+    pub fn get_difficulty_adjustment(&self) -> Result<DifficultyAdjustment> {
+        let height = self.client.get_block_count()?;
+        let epoch_start = self.client.get_block_hash(height - height % ADJUSTMENT_INTERVAL)?;
+        let epoch_start_time = self.client.get_block_header_info(&epoch_start)?.time as i64;
+        let tip = self.client.get_block_hash(height)?;
+        let tip_time = self.client.get_block_header_info(&tip)?.time as i64;
+
+        Ok(DifficultyAdjustment::estimate(height, epoch_start_time, tip_time))
+    }
+
+    pub fn get_block_template(&self) -> Result<BlockTemplate> {
+        let template = self.client.call::<Value>("getblocktemplate", &[json!({"rules": ["segwit"]})])?;
+        BlockTemplate::from_value(&template)
+    }
+
+    /// Pool laut Coinbase-Tag oder Auszahlungsadresse; None, wenn unbekannt
+    pub fn get_block_pool(&self, hash: &str) -> Result<Option<String>> {
+        if let Some(pool) = BLOCK_POOLS.lock().get(hash) {
+            return Ok(pool.clone());
+        }
+
+        let block = self.client.call::<Value>("getblock", &[json!(hash), json!(1)])?;
+        let coinbase_txid = block.get("tx")
+            .and_then(|v| v.get(0))
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Block {} enthält keine Coinbase", hash))?;
+        // Mit Block-Hash funktioniert getrawtransaction auch ohne txindex
+        let coinbase = self.client.call::<Value>(
+            "getrawtransaction",
+            &[json!(coinbase_txid), json!(true), json!(hash)],
+        )?;
+
+        let script = coinbase.get("vin")
+            .and_then(|v| v.get(0))
+            .and_then(|v| v.get("coinbase"))
+            .and_then(|v| v.as_str())
+            .and_then(|v| hex::decode(v).ok())
+            .unwrap_or_default();
+        let addresses: Vec<String> = coinbase.get("vout")
+            .and_then(|v| v.as_array())
+            .map(|outputs| outputs.iter()
+                .filter_map(|output| output.get("scriptPubKey")
+                    .and_then(|script| script.get("address"))
+                    .and_then(|v| v.as_str())
+                    .map(|address| address.to_string()))
+                .collect())
+            .unwrap_or_default();
+
+        let pool = pools::definitions().identify(&script, &addresses).map(|pool| pool.name.clone());
+        BLOCK_POOLS.lock().insert(hash.to_string(), pool.clone());
+        Ok(pool)
+    }
+
+    /// Verteilung der letzten POOL_SHARE_BLOCKS Blöcke (Standard: 144) auf Pools
+    pub fn get_pool_shares(&self) -> Result<(u64, Vec<PoolShare>)> {
+        let blocks = env::var("POOL_SHARE_BLOCKS").ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .filter(|v| *v > 0)
+            .unwrap_or(DEFAULT_POOL_SHARE_BLOCKS);
+        let height = self.client.get_block_count()?;
+
+        // Einzelne fehlerhafte Blöcke überspringen statt die ganze Verteilung zu verwerfen
+        let pools: Vec<Option<String>> = (height.saturating_sub(blocks - 1)..=height)
+            .filter_map(|h| {
+                let pool = self.client.get_block_hash(h)
+                    .map_err(anyhow::Error::from)
+                    .and_then(|hash| self.get_block_pool(&hash.to_string()));
+                match pool {
+                    Ok(pool) => Some(pool),
+                    Err(e) => {
+                        log::warn!("Pool für Block {} nicht ermittelbar: {}", h, e);
+                        None
+                    }
+                }
+            })
+            .collect();
+        Ok((pools.len() as u64, pools::pool_shares(&pools)))
+    }
+
+    /// Hashrate, Difficulty-Anpassung und Halving für den Mining-Tab
+    pub fn get_mining_stats(&self) -> Result<MiningStats> {
+        let height = self.client.get_block_count()?;
+        let tip = self.client.get_block_hash(height)?;
+        let tip_time = self.client.get_block_header_info(&tip)?.time as i64;
+
+        let hashrates = HASHRATE_WINDOWS.iter()
+            .map(|(label, blocks)| Ok((label.to_string(), self.get_network_hashps(*blocks)?)))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(MiningStats {
+            height,
+            difficulty: self.client.get_difficulty()?,
+            hashrates,
+            adjustment: self.get_difficulty_adjustment()?,
+            halving: Halving::after(height, tip_time),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,108 @@
+use super::*;
+
+#[test]
+fn subsidy_halves_every_interval() {
+    assert_eq!(block_subsidy(0), 5_000_000_000);
+    assert_eq!(block_subsidy(209_999), 5_000_000_000);
+    assert_eq!(block_subsidy(210_000), 2_500_000_000);
+    assert_eq!(block_subsidy(840_000), 312_500_000);
+    assert_eq!(block_subsidy(64 * HALVING_INTERVAL), 0);
+}
+
+#[test]
+fn halving_counts_down_to_next_interval() {
+    let halving = Halving::after(840_000, 1_700_000_000);
+    assert_eq!(halving.height, 1_050_000);
+    assert_eq!(halving.blocks_until, 210_000);
+    assert_eq!(halving.current_subsidy, 312_500_000);
+    assert_eq!(halving.next_subsidy, 156_250_000);
+    assert_eq!(halving.estimated_time, 1_700_000_000 + 210_000 * 600);
+
+    let halving = Halving::after(1_049_999, 0);
+    assert_eq!(halving.blocks_until, 1);
+}
+
+#[test]
+fn fast_blocks_raise_difficulty() {
+    // 1008 Blöcke in je 500 Sekunden
+    let adjustment = DifficultyAdjustment::estimate(2016 * 400 + 1008, 0, 1008 * 500);
+    assert_eq!(adjustment.blocks_until, 1008);
+    assert_eq!(adjustment.avg_block_time, 500.0);
+    assert!((adjustment.percent - 20.0).abs() < 1e-9);
+    assert_eq!(adjustment.estimated_time, 1008 * 500 + 1008 * 500);
+    assert!((adjustment.next_difficulty(100.0) - 120.0).abs() < 1e-9);
+}
+
+#[test]
+fn adjustment_is_clamped_to_factor_four() {
+    let adjustment = DifficultyAdjustment::estimate(2016 + 100, 0, 100 * 6000);
+    assert!((adjustment.percent + 75.0).abs() < 1e-9);
+
+    let adjustment = DifficultyAdjustment::estimate(2016 + 100, 0, 100 * 10);
+    assert!((adjustment.percent - 300.0).abs() < 1e-9);
+}
+
+#[test]
+fn epoch_start_has_no_estimate() {
+    let adjustment = DifficultyAdjustment::estimate(2016 * 10, 1_000, 1_000);
+    assert_eq!(adjustment.blocks_until, 2016);
+    assert_eq!(adjustment.percent, 0.0);
+    assert_eq!(adjustment.avg_block_time, 600.0);
+}
+
+#[test]
+fn template_summarizes_transactions() {
+    let template = json!({
+        "height": 840_001,
+        "coinbasevalue": 312_560_000,
+        "weightlimit": 4_000_000,
+        "transactions": [
+            { "txid": "aa", "fee": 10_000, "weight": 800 },
+            { "txid": "bb", "fee": 50_000, "weight": 2_000 },
+            { "txid": "cc", "fee": 0, "weight": 400 },
+        ],
+    });
+
+    let template = BlockTemplate::from_value(&template).unwrap();
+    assert_eq!(template.height, 840_001);
+    assert_eq!(template.tx_count, 3);
+    assert_eq!(template.total_fees, 60_000);
+    assert_eq!(template.coinbase_value, 312_560_000);
+    assert_eq!(template.weight, 3_200);
+    assert_eq!(template.min_feerate, Some(0.0));
+    assert_eq!(template.max_feerate, Some(100.0));
+    assert!((template.weight_percent() - 0.08).abs() < 1e-9);
+
+    let order: Vec<&str> = template.top_transactions.iter().map(|tx| tx.txid.as_str()).collect();
+    assert_eq!(order, vec!["bb", "aa", "cc"]);
+}
+
+#[test]
+fn cpfp_parent_counts_with_package_feerate() {
+    let template = json!({
+        "height": 840_001,
+        "transactions": [
+            { "txid": "parent", "fee": 0, "weight": 800, "depends": [] },
+            { "txid": "child", "fee": 40_000, "weight": 800, "depends": [1] },
+            { "txid": "single", "fee": 5_000, "weight": 400, "depends": [] },
+        ],
+    });
+
+    let template = BlockTemplate::from_value(&template).unwrap();
+    let rate = |txid: &str| template.top_transactions.iter().find(|tx| tx.txid == txid).unwrap().package_feerate;
+    // 40.000 sat für 400 vB Paket
+    assert_eq!(rate("child"), 100.0);
+    assert_eq!(rate("parent"), 100.0);
+    assert_eq!(rate("single"), 50.0);
+    assert_eq!(template.min_feerate, Some(50.0));
+    assert_eq!(template.max_feerate, Some(100.0));
+}
+
+#[test]
+fn empty_template_has_no_feerates() {
+    let template = BlockTemplate::from_value(&json!({ "height": 1, "transactions": [] })).unwrap();
+    assert_eq!(template.tx_count, 0);
+    assert_eq!(template.min_feerate, None);
+    assert_eq!(template.max_feerate, None);
+    assert!(BlockTemplate::from_value(&json!({ "height": 1 })).is_err());
+}
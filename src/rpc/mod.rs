@@ -5,7 +5,7 @@ use bitcoincore_rpc::{Client, Auth, RpcApi};
 use bitcoincore_rpc::bitcoin::{self, BlockHash};
 use dotenv::dotenv;
 use anyhow::Result;
-use hex;
+use serde::Serialize;
 use serde_json::{Value, json};
 use std::fs::OpenOptions;
 use std::io::Write;
@@ -16,13 +16,35 @@ use reqwest::blocking::Client as HttpClient;
 use log::{info};
 
 // Re-export wichtiger Typen
-pub use self::mempool::MempoolStats;
+pub use self::mempool::{FeeCategory, MempoolSample, MempoolStats, Trend, mempool_history};
+pub use self::wallet::WalletTransaction;
+pub use self::descriptor::{Portfolio, PortfolioState, is_descriptor_input};
+pub use self::psbt::{PsbtFinalization, PsbtInspection, read_psbt};
+pub use self::chain::{BlockHeaderSummary, ChainTip};
+pub use self::rawtx::{MempoolAcceptResult, max_broadcast_feerate, read_raw_tx};
+pub use self::mining::{BlockTemplate, MiningStats};
+pub use self::traffic::NetTotals;
+pub use self::addrman::{ADDRMAN_NETWORKS, AddrManOverview};
+pub use self::peers::{client_distribution, peer_breakdown};
+pub use self::user_agent::{parse_user_agent, user_agent_flags};
 
 // Module
 mod mempool;
+mod wallet;
+mod descriptor;
+mod psbt;
+mod rawtx;
+mod chain;
+mod mining;
+mod traffic;
+mod peers;
+mod user_agent;
+mod addrman;
 
 pub struct BitcoinRPC {
     client: Client,
+    rpc_url: String,
+    auth: Auth,
 }
 
 impl Clone for BitcoinRPC {
@@ -32,13 +54,15 @@ impl Clone for BitcoinRPC {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct MempoolInfo {
     #[allow(dead_code)]
     pub size: u64,
+    // Summe der vsize aller TXs im lokalen Mempool
+    pub bytes: u64,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct NodeStatus {
     pub version: u64,
     pub height: u64,
@@ -53,14 +77,18 @@ pub struct NodeStatus {
     pub difficulty: f64,
     pub chain_work: String,
     pub initial_block_download: bool,
+    pub headers: u64,
     pub size_on_disk: u64,
     pub pruned: bool,
+    // Warnungen aus getblockchaininfo und getnetworkinfo
+    pub warnings: Vec<String>,
+    // Abweichung der Node-Uhr von den Peers in Sekunden
+    pub time_offset: i64,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct PeerInfo {
     pub addr: String,
-    #[allow(dead_code)]
     pub version: u64,
     pub subver: String,
     pub latency: f64,
@@ -68,9 +96,20 @@ pub struct PeerInfo {
     pub bytes_recv: u64,
     pub inbound: bool,
     pub connected_time: u64,
+    // ipv4, ipv6, onion, i2p, cjdns oder not_publicly_routable
+    pub network: String,
+    // outbound-full-relay, block-relay-only, inbound, manual, feeler oder addr-fetch
+    pub connection_type: String,
+    pub services: Vec<String>,
+    // v1 oder v2 (BIP324)
+    pub transport_protocol_type: String,
+    pub relaytxes: bool,
+    // -1, solange noch kein Block synchronisiert wurde
+    pub synced_blocks: i64,
+    pub permissions: Vec<String>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct BlockDetails {
     pub height: u64,
     pub hash: String,
@@ -82,9 +121,11 @@ pub struct BlockDetails {
     pub merkle_root: String,
     pub bits: String,
     pub nonce: u32,
+    // Mining-Pool laut pools.json, None wenn unbekannt
+    pub pool: Option<String>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct AddressDetails {
     pub tx_count: usize,
     pub received: f64,
@@ -99,12 +140,17 @@ pub struct AddressDetails {
 
 pub trait BitcoinRPCInterface: Clone {
     fn get_address_details(&self, address: &str) -> Result<AddressDetails>;
+    fn get_portfolio(&self, input: &str) -> Result<PortfolioState>;
 }
 
 impl BitcoinRPCInterface for BitcoinRPC {
     fn get_address_details(&self, address: &str) -> Result<AddressDetails> {
         self.get_address_details(address)
     }
+
+    fn get_portfolio(&self, input: &str) -> Result<PortfolioState> {
+        self.get_portfolio(input)
+    }
 }
 
 // Cache-Struktur für Adressdetails
@@ -118,7 +164,15 @@ static ADDRESS_DETAILS_CACHE: Lazy<Mutex<HashMap<String, AddressDetailsCache>>>
     Mutex::new(HashMap::new())
 });
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
+pub enum TxStatus {
+    Unconfirmed,
+    Confirmed(u64),
+    // Weder im Mempool noch in der Chain (ersetzt, verworfen oder unbekannt)
+    Missing,
+}
+
+#[derive(Debug, Clone, Serialize)]
 pub struct Transaction {
     #[allow(dead_code)]
     pub txid: String,
@@ -130,22 +184,37 @@ pub struct Transaction {
     pub vout: Vec<Value>,
 }
 
+/// Warnungen kommen je nach Core-Version als String oder (ab v28) als Array
+fn parse_warnings(value: Option<&Value>) -> Vec<String> {
+    match value {
+        Some(Value::String(warning)) if !warning.trim().is_empty() => vec![warning.trim().to_string()],
+        Some(Value::Array(items)) => items.iter()
+            .filter_map(|item| item.as_str())
+            .map(|warning| warning.trim().to_string())
+            .filter(|warning| !warning.is_empty())
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
 impl BitcoinRPC {
     pub fn new() -> anyhow::Result<Self> {
         dotenv().ok();
         
-        let host = env::var("BTC_RPC_HOST").expect("BTC_RPC_HOST must be set");
-        let port = env::var("BTC_RPC_PORT").expect("BTC_RPC_PORT must be set");
+        let host = env::var("BTC_RPC_HOST").map_err(|_| anyhow::anyhow!("BTC_RPC_HOST muss gesetzt sein"))?;
+        let port = env::var("BTC_RPC_PORT").map_err(|_| anyhow::anyhow!("BTC_RPC_PORT muss gesetzt sein"))?;
         let rpc_url = format!("http://{}:{}", host, port);
         
-        let rpc_user = env::var("BTC_RPC_USER").expect("BTC_RPC_USER must be set");
-        let rpc_pass = env::var("BTC_RPC_PASSWORD").expect("BTC_RPC_PASSWORD must be set");
+        let rpc_user = env::var("BTC_RPC_USER").map_err(|_| anyhow::anyhow!("BTC_RPC_USER muss gesetzt sein"))?;
+        let rpc_pass = env::var("BTC_RPC_PASSWORD").map_err(|_| anyhow::anyhow!("BTC_RPC_PASSWORD muss gesetzt sein"))?;
         
         let auth = Auth::UserPass(rpc_user, rpc_pass);
-        let client = Client::new(&rpc_url, auth)?;
+        let client = Client::new(&rpc_url, auth.clone())?;
         
         Ok(Self { 
             client,
+            rpc_url,
+            auth,
         })
     }
 
@@ -166,16 +235,13 @@ impl BitcoinRPC {
         check_timeout(start, timeout)?;
         match self.client.version() {
             Ok(version) => {
-                let height = self.client.get_block_count()? as u64;
+                let height = self.client.get_block_count()?;
                 let block_hash = self.client.get_best_block_hash()?;
                 let block_info = self.client.get_block_header_info(&block_hash)?;
                 
                 let connections = self.client.get_connection_count().unwrap_or(0) as u64;
 
-                let difficulty = match self.client.get_difficulty() {
-                    Ok(diff) => diff,
-                    Err(_) => 0.0
-                };
+                let difficulty = self.client.get_difficulty().unwrap_or(0.0);
 
                 let chain_work = match self.client.get_block_header_info(&block_hash) {
                     Ok(info) => {
@@ -187,7 +253,8 @@ impl BitcoinRPC {
                     }
                 };
 
-                let (verification_progress, network, size_on_disk, pruned) = match self.client.call::<serde_json::Value>("getblockchaininfo", &[]) {
+                let mut warnings = Vec::new();
+                let (verification_progress, network, size_on_disk, pruned, headers, initial_block_download) = match self.client.call::<serde_json::Value>("getblockchaininfo", &[]) {
                     Ok(info) => {
                         let size = info.get("size_on_disk")
                             .and_then(|v| v.as_u64())
@@ -205,17 +272,41 @@ impl BitcoinRPC {
                         let is_pruned = info.get("pruned")
                             .and_then(|v| v.as_bool())
                             .unwrap_or(false);
+
+                        let headers = info.get("headers")
+                            .and_then(|v| v.as_u64())
+                            .unwrap_or(height);
+
+                        let ibd = info.get("initialblockdownload")
+                            .and_then(|v| v.as_bool())
+                            .unwrap_or(false);
                             
-                        (progress, network, size, is_pruned)
+                        warnings.extend(parse_warnings(info.get("warnings")));
+                            
+                        (progress, network, size, is_pruned, headers, ibd)
                     },
                     Err(_) => (
                         1.0,
                         "unknown".to_string(),
                         0,
                         false,
+                        height,
+                        false,
                     )
                 };
 
+                let time_offset = match self.client.call::<serde_json::Value>("getnetworkinfo", &[]) {
+                    Ok(info) => {
+                        for warning in parse_warnings(info.get("warnings")) {
+                            if !warnings.contains(&warning) {
+                                warnings.push(warning);
+                            }
+                        }
+                        info.get("timeoffset").and_then(|v| v.as_i64()).unwrap_or(0)
+                    },
+                    Err(_) => 0,
+                };
+
                 let mempool_size = self.client.get_mempool_info()
                     .map(|info| info.size as u64)
                     .unwrap_or(0);
@@ -237,9 +328,12 @@ impl BitcoinRPC {
                     peers,
                     difficulty,
                     chain_work,
-                    initial_block_download: false,
+                    initial_block_download,
+                    headers,
                     size_on_disk,
                     pruned,
+                    warnings,
+                    time_offset,
                 })
             },
             Err(e) => {
@@ -251,25 +345,11 @@ impl BitcoinRPC {
         }
     }
 
-    pub fn get_peer_info(&self) -> Result<Vec<PeerInfo>> {
-        let peers = self.client.get_peer_info()?;
-        
-        Ok(peers.into_iter().map(|p| PeerInfo {
-            addr: p.addr,
-            version: p.version as u64,
-            subver: p.subver,
-            latency: p.pingtime.unwrap_or(0.0),
-            bytes_sent: p.bytessent as u64,
-            bytes_recv: p.bytesrecv as u64,
-            inbound: p.inbound,
-            connected_time: p.conntime as u64,
-        }).collect())
-    }
-
     pub fn get_mempool_info(&self) -> anyhow::Result<MempoolInfo> {
         let info = self.client.get_mempool_info()?;
         Ok(MempoolInfo {
             size: info.size as u64,
+            bytes: info.bytes as u64,
         })
     }
 
@@ -291,6 +371,7 @@ impl BitcoinRPC {
                 merkle_root: block.merkleroot.to_string(),
                 bits: block.bits,
                 nonce: block.nonce,
+                pool: self.get_block_pool(&block_hash.to_string()).ok().flatten(),
             })
         } else {
             // Block nach Hash suchen
@@ -312,6 +393,7 @@ impl BitcoinRPC {
                 merkle_root: block.merkleroot.to_string(),
                 bits: block.bits,
                 nonce: block.nonce,
+                pool: self.get_block_pool(&block_hash.to_string()).ok().flatten(),
             })
         }
     }
@@ -508,7 +590,7 @@ impl BitcoinRPC {
                         .and_then(|out| {
                             let hex_bytes = hex::decode(&out.script_pub_key.hex).unwrap_or_default();
                             let script = bitcoin::Script::from_bytes(&hex_bytes);
-                            bitcoin::Address::from_script(&script, bitcoin::Network::Bitcoin)
+                            bitcoin::Address::from_script(script, bitcoin::Network::Bitcoin)
                                 .ok()
                                 .map(|addr| addr.to_string())
                         })
@@ -529,6 +611,37 @@ impl BitcoinRPC {
         })
     }
 
+    pub fn get_tx_status(&self, txid: &str, tip_height: u64) -> Result<TxStatus> {
+        let tx_id = bitcoin::Txid::from_str(txid)?;
+
+        // Mempool-TXs findet der Node immer, bestätigte nur mit txindex
+        if let Ok(info) = self.client.get_raw_transaction_info(&tx_id, None) {
+            return Ok(match info.confirmations {
+                Some(n) if n > 0 => TxStatus::Confirmed(n as u64),
+                _ => TxStatus::Unconfirmed,
+            });
+        }
+
+        // Fallback ohne txindex: mempool.space
+        let url = format!("https://mempool.space/api/tx/{}/status", txid);
+        let response = HttpClient::new().get(&url).send()
+            .map_err(|e| anyhow::anyhow!("Netzwerk Fehler: {}", e))?;
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(TxStatus::Missing);
+        }
+        if !response.status().is_success() {
+            return Err(anyhow::anyhow!("API Fehler: {}", response.status()));
+        }
+
+        let data: Value = response.json()?;
+        let confirmed = data.get("confirmed").and_then(|v| v.as_bool()).unwrap_or(false);
+        match data.get("block_height").and_then(|v| v.as_u64()) {
+            Some(height) if confirmed => Ok(TxStatus::Confirmed(tip_height.saturating_sub(height) + 1)),
+            _ => Ok(TxStatus::Unconfirmed),
+        }
+    }
+
     #[allow(dead_code)]
     pub fn connect_rpc() -> Result<Client> {
         info!("Verbindung zum Bitcoin RPC-Client wird hergestellt.");
@@ -543,31 +656,12 @@ impl BitcoinRPC {
         Ok(client)
     }
 
+    /// (Blöcke bis zur Anpassung, geschätzte Änderung in Prozent)
     pub fn get_difficulty_adjustment_estimate(&self) -> Result<(i64, f64)> {
-        let height = self.client.get_block_count()? as u64;
-        let last_adjustment = height - (height % 2016);
-        let blocks_until = 2016 - (height % 2016) as i64;
-        
-        // Hole Zeitstempel des letzten Anpassungsblocks
-        let last_adjustment_hash = self.client.get_block_hash(last_adjustment)?;
-        let last_adjustment_time = self.client.get_block_header_info(&last_adjustment_hash)?.time;
-        
-        // Hole aktuellen Block-Zeitstempel
-        let current_hash = self.client.get_best_block_hash()?;
-        let current_time = self.client.get_block_header_info(&current_hash)?.time;
-        
-        // Berechne durchschnittliche Blockzeit
-        let elapsed_time = current_time - last_adjustment_time;
-        let blocks_since = height - last_adjustment;
-        let avg_block_time = elapsed_time as f64 / blocks_since as f64;
-        
-        // Extrapoliere auf 2016 Blöcke
-        let expected_time = 2016.0 * 600.0; // 2016 Blöcke * 10 Minuten
-        let projected_time = avg_block_time * 2016.0;
-        
-        // Berechne geschätzte Anpassung
-        let adjustment = (expected_time / projected_time - 1.0) * 100.0;
-        
-        Ok((blocks_until, adjustment))
+        let adjustment = self.get_difficulty_adjustment()?;
+        Ok((adjustment.blocks_until, adjustment.percent))
     }
-} 
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,90 @@
+use anyhow::Result;
+use bitcoincore_rpc::RpcApi;
+use serde_json::Value;
+use std::collections::HashMap;
+use super::PeerInfo;
+use super::user_agent::parse_user_agent;
+
+/// Netzwerk eines Peers anhand seiner Adresse (für Nodes vor Core 22 ohne "network")
+pub fn peer_network(addr: &str) -> &'static str {
+    let host = addr.rsplit_once(':').map(|(host, _)| host).unwrap_or(addr);
+    if host.ends_with(".onion") {
+        "onion"
+    } else if host.ends_with(".i2p") {
+        "i2p"
+    } else if host.starts_with('[') || host.contains(':') {
+        // CJDNS-Adressen liegen im IPv6-Bereich fc00::/8
+        if host.trim_start_matches('[').starts_with("fc") { "cjdns" } else { "ipv6" }
+    } else {
+        "ipv4"
+    }
+}
+
+impl PeerInfo {
+    /// Ein Eintrag aus getpeerinfo; fehlende Felder älterer Core-Versionen werden ergänzt
+    pub fn from_value(peer: &Value) -> Self {
+        let text = |key: &str| peer.get(key).and_then(|v| v.as_str()).map(|s| s.to_string());
+        let list = |key: &str| peer.get(key)
+            .and_then(|v| v.as_array())
+            .map(|items| items.iter().filter_map(|v| v.as_str()).map(|s| s.to_string()).collect())
+            .unwrap_or_default();
+
+        let addr = text("addr").unwrap_or_default();
+        let inbound = peer.get("inbound").and_then(|v| v.as_bool()).unwrap_or(false);
+        Self {
+            network: text("network").unwrap_or_else(|| peer_network(&addr).to_string()),
+            connection_type: text("connection_type")
+                .unwrap_or_else(|| if inbound { "inbound" } else { "unknown" }.to_string()),
+            services: list("servicesnames"),
+            transport_protocol_type: text("transport_protocol_type").unwrap_or_else(|| "v1".to_string()),
+            // Fehlt relaytxes, leitet der Peer Transaktionen weiter
+            relaytxes: peer.get("relaytxes").and_then(|v| v.as_bool()).unwrap_or(true),
+            synced_blocks: peer.get("synced_blocks").and_then(|v| v.as_i64()).unwrap_or(-1),
+            permissions: list("permissions"),
+            version: peer.get("version").and_then(|v| v.as_u64()).unwrap_or(0),
+            subver: text("subver").unwrap_or_default(),
+            latency: peer.get("pingtime").and_then(|v| v.as_f64()).unwrap_or(0.0),
+            bytes_sent: peer.get("bytessent").and_then(|v| v.as_u64()).unwrap_or(0),
+            bytes_recv: peer.get("bytesrecv").and_then(|v| v.as_u64()).unwrap_or(0),
+            inbound,
+            connected_time: peer.get("conntime").and_then(|v| v.as_u64()).unwrap_or(0),
+            addr,
+        }
+    }
+}
+
+fn count_sorted(values: impl Iterator<Item = String>) -> Vec<(String, usize)> {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for value in values {
+        *counts.entry(value).or_default() += 1;
+    }
+
+    let mut breakdown: Vec<(String, usize)> = counts.into_iter().collect();
+    breakdown.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    breakdown
+}
+
+/// Anzahl Peers je Wert von `key`, häufigster zuerst
+pub fn peer_breakdown(peers: &[PeerInfo], key: impl Fn(&PeerInfo) -> &str) -> Vec<(String, usize)> {
+    count_sorted(peers.iter().map(|peer| key(peer).to_string()))
+}
+
+/// Anzahl Peers je Client (z.B. "Core 27"); nicht lesbare User-Agents zählen als "Unbekannt"
+pub fn client_distribution(peers: &[PeerInfo]) -> Vec<(String, usize)> {
+    count_sorted(peers.iter().map(|peer| {
+        parse_user_agent(&peer.subver)
+            .map(|client| client.label())
+            .unwrap_or_else(|| "Unbekannt".to_string())
+    }))
+}
+
+impl super::BitcoinRPC {
+    pub fn get_peer_info(&self) -> Result<Vec<PeerInfo>> {
+        let peers = self.client.call::<Value>("getpeerinfo", &[])?;
+        Ok(peers.as_array()
+            .ok_or_else(|| anyhow::anyhow!("getpeerinfo lieferte keine Liste"))?
+            .iter()
+            .map(PeerInfo::from_value)
+            .collect())
+    }
+}
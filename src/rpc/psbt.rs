@@ -0,0 +1,179 @@
+use anyhow::Result;
+use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+use bitcoincore_rpc::RpcApi;
+use serde_json::{Value, json};
+use std::path::Path;
+
+const PSBT_MAGIC: &[u8] = b"psbt\xff";
+
+#[derive(Debug, Clone)]
+pub struct PsbtInput {
+    pub prevout: String,
+    pub amount: Option<f64>,
+    pub address: Option<String>,
+    pub is_final: bool,
+    pub next_role: String,
+    pub partial_signatures: usize,
+    pub missing_signatures: Vec<String>,
+    pub missing_pubkeys: Vec<String>,
+    pub missing_scripts: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PsbtOutput {
+    pub amount: f64,
+    pub address: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct PsbtInspection {
+    pub txid: String,
+    pub inputs: Vec<PsbtInput>,
+    pub outputs: Vec<PsbtOutput>,
+    pub fee: Option<f64>,
+    // sat/vB
+    pub feerate: Option<f64>,
+    pub vsize: Option<u64>,
+    pub next_role: String,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PsbtFinalization {
+    pub complete: bool,
+    pub psbt: Option<String>,
+    pub hex: Option<String>,
+}
+
+/// Liest eine PSBT als Base64-Text oder aus einer Datei (Base64 oder binär)
+pub fn read_psbt(input: &str) -> Result<String> {
+    let input = input.trim();
+    let path = Path::new(input);
+
+    let base64 = if path.is_file() {
+        let data = std::fs::read(path)?;
+        if data.starts_with(PSBT_MAGIC) {
+            BASE64.encode(&data)
+        } else {
+            String::from_utf8(data)
+                .map_err(|_| anyhow::anyhow!("Datei enthält weder Base64 noch eine binäre PSBT"))?
+                .split_whitespace()
+                .collect()
+        }
+    } else {
+        input.split_whitespace().collect()
+    };
+
+    let raw = BASE64.decode(&base64)
+        .map_err(|e| anyhow::anyhow!("Ungültiges Base64: {}", e))?;
+    if !raw.starts_with(PSBT_MAGIC) {
+        return Err(anyhow::anyhow!("Keine PSBT (Magic Bytes fehlen)"));
+    }
+
+    Ok(base64)
+}
+
+fn string_list(value: Option<&Value>) -> Vec<String> {
+    value.and_then(|v| v.as_array())
+        .map(|items| items.iter().filter_map(|i| i.as_str().map(|s| s.to_string())).collect())
+        .unwrap_or_default()
+}
+
+impl super::BitcoinRPC {
+    /// Dekodiert und analysiert eine PSBT (decodepsbt + analyzepsbt)
+    pub fn inspect_psbt(&self, psbt: &str) -> Result<PsbtInspection> {
+        let decoded = self.client.call::<Value>("decodepsbt", &[json!(psbt)])?;
+        let analysis = self.client.call::<Value>("analyzepsbt", &[json!(psbt)])?;
+
+        let tx = decoded.get("tx")
+            .ok_or_else(|| anyhow::anyhow!("decodepsbt lieferte keine Transaktion"))?;
+        let empty = Vec::new();
+        let vin = tx.get("vin").and_then(|v| v.as_array()).unwrap_or(&empty);
+        let psbt_inputs = decoded.get("inputs").and_then(|v| v.as_array()).unwrap_or(&empty);
+        let analyzed_inputs = analysis.get("inputs").and_then(|v| v.as_array()).unwrap_or(&empty);
+
+        let inputs = vin.iter().enumerate().map(|(i, input)| {
+            let prev_txid = input.get("txid").and_then(|v| v.as_str()).unwrap_or("");
+            let prev_vout = input.get("vout").and_then(|v| v.as_u64()).unwrap_or(0);
+            let psbt_input = psbt_inputs.get(i);
+            let analyzed = analyzed_inputs.get(i);
+            let missing = analyzed.and_then(|a| a.get("missing"));
+
+            // Segwit-Inputs tragen witness_utxo, Legacy-Inputs die komplette Vorgänger-TX
+            let prev_output = psbt_input.and_then(|p| p.get("witness_utxo")).cloned().or_else(|| {
+                psbt_input
+                    .and_then(|p| p.get("non_witness_utxo"))
+                    .and_then(|tx| tx.get("vout"))
+                    .and_then(|v| v.get(prev_vout as usize))
+                    .map(|out| json!({
+                        "amount": out.get("value").cloned().unwrap_or(Value::Null),
+                        "scriptPubKey": out.get("scriptPubKey").cloned().unwrap_or(Value::Null),
+                    }))
+            });
+
+            let mut missing_scripts = Vec::new();
+            for script in ["redeemscript", "witnessscript"] {
+                if missing.and_then(|m| m.get(script)).is_some() {
+                    missing_scripts.push(script.to_string());
+                }
+            }
+
+            PsbtInput {
+                prevout: format!("{}:{}", prev_txid, prev_vout),
+                amount: prev_output.as_ref().and_then(|o| o.get("amount")).and_then(|v| v.as_f64()),
+                address: prev_output.as_ref()
+                    .and_then(|o| o.get("scriptPubKey"))
+                    .and_then(|s| s.get("address"))
+                    .and_then(|v| v.as_str())
+                    .map(|s| s.to_string()),
+                is_final: analyzed.and_then(|a| a.get("is_final")).and_then(|v| v.as_bool()).unwrap_or(false),
+                next_role: analyzed.and_then(|a| a.get("next")).and_then(|v| v.as_str()).unwrap_or("").to_string(),
+                partial_signatures: psbt_input
+                    .and_then(|p| p.get("partial_signatures"))
+                    .and_then(|v| v.as_object())
+                    .map(|sigs| sigs.len())
+                    .unwrap_or(0),
+                missing_signatures: string_list(missing.and_then(|m| m.get("signatures"))),
+                missing_pubkeys: string_list(missing.and_then(|m| m.get("pubkeys"))),
+                missing_scripts,
+            }
+        }).collect();
+
+        let outputs = tx.get("vout").and_then(|v| v.as_array()).unwrap_or(&empty).iter().map(|out| {
+            let script = out.get("scriptPubKey");
+            PsbtOutput {
+                amount: out.get("value").and_then(|v| v.as_f64()).unwrap_or(0.0),
+                address: script.and_then(|s| s.get("address"))
+                    .or_else(|| script.and_then(|s| s.get("type")))
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("Unbekannt")
+                    .to_string(),
+            }
+        }).collect();
+
+        Ok(PsbtInspection {
+            txid: tx.get("txid").and_then(|v| v.as_str()).unwrap_or("").to_string(),
+            inputs,
+            outputs,
+            fee: decoded.get("fee").or_else(|| analysis.get("fee")).and_then(|v| v.as_f64()),
+            // estimated_feerate ist in BTC/kvB angegeben
+            feerate: analysis.get("estimated_feerate").and_then(|v| v.as_f64()).map(|r| r * 100_000.0),
+            vsize: analysis.get("estimated_vsize").and_then(|v| v.as_u64()),
+            next_role: analysis.get("next").and_then(|v| v.as_str()).unwrap_or("").to_string(),
+            error: analysis.get("error").and_then(|v| v.as_str()).map(|s| s.to_string()),
+        })
+    }
+
+    /// Finalisiert eine PSBT; mit `extract` wird bei Vollständigkeit der TX-Hex geliefert
+    pub fn finalize_psbt(&self, psbt: &str, extract: bool) -> Result<PsbtFinalization> {
+        let result = self.client.call::<Value>("finalizepsbt", &[json!(psbt), json!(extract)])?;
+        Ok(PsbtFinalization {
+            complete: result.get("complete").and_then(|v| v.as_bool()).unwrap_or(false),
+            psbt: result.get("psbt").and_then(|v| v.as_str()).map(|s| s.to_string()),
+            hex: result.get("hex").and_then(|v| v.as_str()).map(|s| s.to_string()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,36 @@
+use super::*;
+
+fn sample_psbt() -> Vec<u8> {
+    let mut data = PSBT_MAGIC.to_vec();
+    data.extend_from_slice(&[0x01, 0x00, 0x00]);
+    data
+}
+
+#[test]
+fn reads_base64_text() {
+    let encoded = BASE64.encode(sample_psbt());
+    assert_eq!(read_psbt(&format!("  {}\n", encoded)).unwrap(), encoded);
+}
+
+#[test]
+fn rejects_non_psbt_data() {
+    assert!(read_psbt("kein base64!").is_err());
+    assert!(read_psbt(&BASE64.encode(b"0200000001")).is_err());
+}
+
+#[test]
+fn reads_binary_and_text_files() {
+    let dir = std::env::temp_dir();
+    let binary = dir.join(format!("nodebridge-{}.psbt", std::process::id()));
+    let text = dir.join(format!("nodebridge-{}.psbt.txt", std::process::id()));
+    let encoded = BASE64.encode(sample_psbt());
+
+    std::fs::write(&binary, sample_psbt()).unwrap();
+    std::fs::write(&text, format!("{}\n", encoded)).unwrap();
+
+    assert_eq!(read_psbt(binary.to_str().unwrap()).unwrap(), encoded);
+    assert_eq!(read_psbt(text.to_str().unwrap()).unwrap(), encoded);
+
+    let _ = std::fs::remove_file(binary);
+    let _ = std::fs::remove_file(text);
+}
@@ -0,0 +1,161 @@
+use anyhow::Result;
+use bitcoincore_rpc::RpcApi;
+use serde_json::{Value, json};
+use std::env;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+use super::Transaction;
+
+const COINBASE_TXID: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+const DEFAULT_MAX_FEERATE: f64 = 500.0;
+const DEFAULT_AUDIT_LOG: &str = "broadcast_audit.log";
+
+#[derive(Debug, Clone)]
+pub struct MempoolAcceptResult {
+    pub allowed: bool,
+    pub reject_reason: Option<String>,
+    pub vsize: Option<u64>,
+    pub fee: Option<f64>,
+    // sat/vB
+    pub effective_feerate: Option<f64>,
+}
+
+/// Liest eine Roh-Transaktion als Hex-Text oder aus einer Datei
+pub fn read_raw_tx(input: &str) -> Result<String> {
+    let input = input.trim();
+    let path = Path::new(input);
+
+    let hex_str: String = if path.is_file() {
+        std::fs::read_to_string(path)
+            .map_err(|_| anyhow::anyhow!("Datei enthält keinen Hex-Text"))?
+            .split_whitespace()
+            .collect()
+    } else {
+        input.split_whitespace().collect()
+    };
+
+    if hex_str.is_empty() {
+        return Err(anyhow::anyhow!("Keine Transaktion angegeben"));
+    }
+    hex::decode(&hex_str)
+        .map_err(|e| anyhow::anyhow!("Ungültiger Hex-String: {}", e))?;
+
+    Ok(hex_str.to_lowercase())
+}
+
+/// Obergrenze für die Feerate beim Senden in sat/vB (BROADCAST_MAX_FEERATE)
+pub fn max_broadcast_feerate() -> f64 {
+    env::var("BROADCAST_MAX_FEERATE")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .filter(|v: &f64| *v > 0.0)
+        .unwrap_or(DEFAULT_MAX_FEERATE)
+}
+
+// Jeder Sendeversuch wird mit Ergebnis im Audit-Log festgehalten
+fn write_audit_log(hex: &str, max_feerate: f64, result: &Result<String>) -> std::io::Result<()> {
+    let path = env::var("BROADCAST_AUDIT_LOG").unwrap_or_else(|_| DEFAULT_AUDIT_LOG.to_string());
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    let outcome = match result {
+        Ok(txid) => format!("OK txid={}", txid),
+        Err(e) => format!("FEHLER {}", e),
+    };
+    writeln!(
+        file,
+        "{} sendrawtransaction maxfeerate={} sat/vB {} hex={}",
+        chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC"),
+        max_feerate,
+        outcome,
+        hex
+    )
+}
+
+impl super::BitcoinRPC {
+    /// Sendet eine Roh-Transaktion; `max_feerate` (sat/vB) schützt vor überhöhten Gebühren
+    pub fn send_raw_transaction(&self, hex: &str, max_feerate: f64) -> Result<String> {
+        // sendrawtransaction erwartet die Obergrenze in BTC/kvB
+        let result = self.client
+            .call::<Value>("sendrawtransaction", &[json!(hex), json!(max_feerate / 100_000.0)])
+            .map_err(anyhow::Error::from)
+            .and_then(|txid| txid.as_str()
+                .map(|s| s.to_string())
+                .ok_or_else(|| anyhow::anyhow!("sendrawtransaction lieferte keine TXID")));
+
+        if let Err(e) = write_audit_log(hex, max_feerate, &result) {
+            log::warn!("Audit-Log konnte nicht geschrieben werden: {}", e);
+        }
+        result
+    }
+
+    /// Dekodiert eine Roh-Transaktion (decoderawtransaction) ins Format von get_raw_transaction
+    pub fn decode_raw_transaction(&self, hex: &str) -> Result<Transaction> {
+        let tx = self.client.call::<Value>("decoderawtransaction", &[json!(hex)])?;
+        let empty = Vec::new();
+
+        let vin = tx.get("vin").and_then(|v| v.as_array()).unwrap_or(&empty).iter().map(|input| {
+            // Coinbase-Inputs haben keinen Prevout
+            let Some(prev_txid) = input.get("txid").and_then(|v| v.as_str()) else {
+                return json!({ "txid": COINBASE_TXID });
+            };
+            let prev_vout = input.get("vout").and_then(|v| v.as_u64()).unwrap_or(0);
+
+            // Prevout nachschlagen (klappt für Mempool-TXs immer, sonst nur mit txindex)
+            let prev_output = self.client
+                .call::<Value>("getrawtransaction", &[json!(prev_txid), json!(true)])
+                .ok()
+                .and_then(|prev| prev.get("vout").and_then(|v| v.get(prev_vout as usize)).cloned());
+
+            json!({
+                "txid": prev_txid,
+                "vout": prev_vout,
+                "value": prev_output.as_ref().and_then(|o| o.get("value")).and_then(|v| v.as_f64()).unwrap_or(0.0),
+                "address": prev_output.as_ref()
+                    .and_then(|o| o.get("scriptPubKey"))
+                    .and_then(|s| s.get("address"))
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("Unbekannte Adresse"),
+            })
+        }).collect();
+
+        Ok(Transaction {
+            txid: tx.get("txid").and_then(|v| v.as_str()).unwrap_or("").to_string(),
+            size: tx.get("size").and_then(|v| v.as_u64()).unwrap_or(0) as u32,
+            weight: tx.get("weight").and_then(|v| v.as_u64()).unwrap_or(0) as u32,
+            blocktime: None,
+            blockhash: None,
+            vin,
+            vout: tx.get("vout").and_then(|v| v.as_array()).cloned().unwrap_or_default(),
+        })
+    }
+
+    /// Prüft eine Roh-Transaktion gegen die Mempool-Policy des Nodes (testmempoolaccept)
+    pub fn test_mempool_accept(&self, hex: &str) -> Result<MempoolAcceptResult> {
+        let results = self.client.call::<Value>("testmempoolaccept", &[json!([hex])])?;
+        let result = results.get(0)
+            .ok_or_else(|| anyhow::anyhow!("testmempoolaccept lieferte kein Ergebnis"))?;
+        let fees = result.get("fees");
+
+        // Abgelehnte TXs liefern keine vsize, dann aus der dekodierten TX nehmen
+        let vsize = result.get("vsize").and_then(|v| v.as_u64()).or_else(|| {
+            self.client.call::<Value>("decoderawtransaction", &[json!(hex)]).ok()
+                .and_then(|tx| tx.get("vsize").and_then(|v| v.as_u64()))
+        });
+
+        Ok(MempoolAcceptResult {
+            allowed: result.get("allowed").and_then(|v| v.as_bool()).unwrap_or(false),
+            reject_reason: result.get("reject-reason")
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string()),
+            vsize,
+            fee: fees.and_then(|f| f.get("base")).and_then(|v| v.as_f64()),
+            // effective-feerate ist in BTC/kvB angegeben
+            effective_feerate: fees.and_then(|f| f.get("effective-feerate"))
+                .and_then(|v| v.as_f64())
+                .map(|r| r * 100_000.0),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,25 @@
+use super::*;
+
+const RAW_TX: &str = "0200000001aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa0000000000ffffffff0100e1f505000000001600140000000000000000000000000000000000000000000000000000";
+
+#[test]
+fn reads_hex_text() {
+    assert_eq!(read_raw_tx(&format!("  {}\n", RAW_TX.to_uppercase())).unwrap(), RAW_TX);
+}
+
+#[test]
+fn rejects_invalid_hex() {
+    assert!(read_raw_tx("").is_err());
+    assert!(read_raw_tx("02000000zz").is_err());
+    assert!(read_raw_tx("020").is_err());
+}
+
+#[test]
+fn reads_hex_file() {
+    let path = std::env::temp_dir().join(format!("nodebridge-{}.tx", std::process::id()));
+    std::fs::write(&path, format!("{}\n", RAW_TX)).unwrap();
+
+    assert_eq!(read_raw_tx(path.to_str().unwrap()).unwrap(), RAW_TX);
+
+    let _ = std::fs::remove_file(path);
+}
@@ -0,0 +1,236 @@
+use super::*;
+use super::peers::peer_network;
+use super::addrman::{AddrManCount, NodeAddress, parse_addrman_info, parse_reachability};
+
+#[test]
+fn warnings_as_string_or_array() {
+    assert_eq!(parse_warnings(Some(&json!("Unknown new rules activated"))), vec!["Unknown new rules activated"]);
+    assert!(parse_warnings(Some(&json!(""))).is_empty());
+    assert_eq!(
+        parse_warnings(Some(&json!(["This is a pre-release test build", " "]))),
+        vec!["This is a pre-release test build"]
+    );
+    assert!(parse_warnings(None).is_empty());
+}
+
+#[test]
+fn net_totals_with_upload_target() {
+    let totals = NetTotals::from_value(&json!({
+        "totalbytesrecv": 7_500_000_000u64,
+        "totalbytessent": 12_000_000_000u64,
+        "timemillis": 1_713_571_767_000i64,
+        "uploadtarget": {
+            "timeframe": 86400,
+            "target": 5_000_000_000u64,
+            "target_reached": false,
+            "serve_historical_blocks": true,
+            "bytes_left_in_cycle": 1_250_000_000u64,
+            "time_left_in_cycle": 3600
+        }
+    }));
+
+    assert_eq!(totals.total_bytes_recv, 7_500_000_000);
+    assert_eq!(totals.total_bytes_sent, 12_000_000_000);
+    assert_eq!(totals.time_millis, 1_713_571_767_000);
+    assert!(totals.upload_target.is_limited());
+    assert_eq!(totals.upload_target.bytes_left_in_cycle, 1_250_000_000);
+
+    let unlimited = NetTotals::from_value(&json!({ "uploadtarget": { "target": 0 } }));
+    assert!(!unlimited.upload_target.is_limited());
+    assert!(unlimited.upload_target.serve_historical_blocks);
+}
+
+#[test]
+fn detects_peer_networks() {
+    assert_eq!(peer_network("203.0.113.5:8333"), "ipv4");
+    assert_eq!(peer_network("[2001:db8::1]:8333"), "ipv6");
+    assert_eq!(peer_network("[fc32:17ea::1]:8333"), "cjdns");
+    assert_eq!(peer_network("abcdefghijklmnop.onion:8333"), "onion");
+    assert_eq!(peer_network("ukeu3k5o.b32.i2p:0"), "i2p");
+}
+
+#[test]
+fn peer_info_from_getpeerinfo() {
+    let peer = PeerInfo::from_value(&json!({
+        "addr": "ukeu3k5oycgaauneqgtnvselmt4yemvoilkln7jpvamvfx7dnkdq.b32.i2p:0",
+        "network": "i2p",
+        "connection_type": "block-relay-only",
+        "servicesnames": ["NETWORK", "WITNESS", "NETWORK_LIMITED"],
+        "transport_protocol_type": "v2",
+        "relaytxes": false,
+        "synced_blocks": 840_000,
+        "permissions": ["noban"],
+        "version": 70016,
+        "subver": "/Satoshi:27.0.0/",
+        "pingtime": 0.25,
+        "bytessent": 1_000,
+        "bytesrecv": 2_000,
+        "inbound": false,
+        "conntime": 1_713_571_767
+    }));
+
+    assert_eq!(peer.network, "i2p");
+    assert_eq!(peer.connection_type, "block-relay-only");
+    assert_eq!(peer.services, vec!["NETWORK", "WITNESS", "NETWORK_LIMITED"]);
+    assert_eq!(peer.transport_protocol_type, "v2");
+    assert!(!peer.relaytxes);
+    assert_eq!(peer.synced_blocks, 840_000);
+    assert_eq!(peer.permissions, vec!["noban"]);
+    assert_eq!(peer.latency, 0.25);
+    assert_eq!(peer.bytes_recv, 2_000);
+}
+
+#[test]
+fn peer_info_fallbacks_for_old_nodes() {
+    let peer = PeerInfo::from_value(&json!({
+        "addr": "abcdefghijklmnop.onion:8333",
+        "inbound": true
+    }));
+
+    assert_eq!(peer.network, "onion");
+    assert_eq!(peer.connection_type, "inbound");
+    assert_eq!(peer.transport_protocol_type, "v1");
+    assert!(peer.relaytxes);
+    assert_eq!(peer.synced_blocks, -1);
+}
+
+#[test]
+fn breakdown_counts_and_sorts() {
+    let peer = |network: &str, connection_type: &str| PeerInfo {
+        network: network.to_string(),
+        connection_type: connection_type.to_string(),
+        ..Default::default()
+    };
+    let peers = vec![
+        peer("ipv4", "outbound-full-relay"),
+        peer("onion", "inbound"),
+        peer("ipv4", "block-relay-only"),
+        peer("i2p", "outbound-full-relay"),
+    ];
+
+    assert_eq!(
+        peer_breakdown(&peers, |p| &p.network),
+        vec![("ipv4".to_string(), 2), ("i2p".to_string(), 1), ("onion".to_string(), 1)]
+    );
+    assert_eq!(peer_breakdown(&peers, |p| &p.connection_type)[0], ("outbound-full-relay".to_string(), 2));
+}
+
+#[test]
+fn parses_user_agents() {
+    let core = parse_user_agent("/Satoshi:27.0.0/").unwrap();
+    assert_eq!(core.implementation, "Bitcoin Core");
+    assert_eq!(core.version, "27.0.0");
+    assert_eq!(core.label(), "Core 27");
+    assert_eq!(parse_user_agent("/Satoshi:0.21.1/").unwrap().core_major, Some(21));
+
+    let knots = parse_user_agent("/Satoshi:25.1.0/Knots:20231115/").unwrap();
+    assert_eq!((knots.implementation.as_str(), knots.version.as_str()), ("Knots", "20231115"));
+    assert_eq!(knots.core_major, Some(25));
+    let old_knots = parse_user_agent("/Satoshi:0.20.1(knots20200815)/").unwrap();
+    assert_eq!((old_knots.implementation.as_str(), old_knots.version.as_str()), ("Knots", "20200815"));
+
+    let btcd = parse_user_agent("/btcwire:0.5.0/btcd:0.24.2/").unwrap();
+    assert_eq!(btcd.label(), "btcd 0.24.2");
+    assert_eq!(parse_user_agent("/bitcoinj:0.16.2/Bitcoin Wallet:9.30/").unwrap().implementation, "bitcoinj");
+
+    assert!(parse_user_agent("").is_none());
+    assert!(parse_user_agent("Satoshi 27").is_none());
+    assert!(parse_user_agent("/Satoshi:/").is_none());
+}
+
+#[test]
+fn flags_suspicious_user_agents() {
+    let peer = |subver: &str, version: u64| PeerInfo {
+        subver: subver.to_string(),
+        version,
+        ..Default::default()
+    };
+    // Eigener Node: Core 27
+    let node = 270000;
+
+    assert!(user_agent_flags(&peer("/Satoshi:27.1.0/", 70016), node).is_empty());
+    assert!(user_agent_flags(&peer("/Satoshi:28.0.0/", 70016), node).is_empty());
+    assert!(user_agent_flags(&peer("/btcwire:0.5.0/btcd:0.24.2/", 70016), node).is_empty());
+
+    assert_eq!(user_agent_flags(&peer("", 70016), node), vec!["Kein User-Agent"]);
+    assert_eq!(user_agent_flags(&peer("hello", 70016), node).len(), 1);
+    assert!(user_agent_flags(&peer("/Satoshi:0.16.3/", 70015), node)[0].starts_with("Veraltete Version"));
+    assert!(user_agent_flags(&peer("/Satoshi:99.0.0/", 70016), node)[0].contains("gefälscht"));
+    assert!(user_agent_flags(&peer("/Satoshi:26.0.0/", 70015), node)[0].starts_with("Protokollversion 70015"));
+    assert!(user_agent_flags(&peer("/Bitcoin ABC:0.28.0(EB32.0)/", 70015), node)
+        .contains(&"Client einer anderen Chain".to_string()));
+    assert!(!user_agent_flags(&peer(&format!("/Satoshi:27.0.0/{}/", "x".repeat(100)), 70016), node).is_empty());
+
+    // Auch ohne bekannte Node-Version fallen unmögliche Versionen auf
+    assert!(user_agent_flags(&peer("/Satoshi:99.0.0/", 70016), 0)[0].contains("gefälscht"));
+}
+
+#[test]
+fn newer_peers_than_outdated_node_are_not_flagged() {
+    let peer = |subver: &str| PeerInfo { subver: subver.to_string(), version: 70016, ..Default::default() };
+    // Eigener Node: Core 25, Peers mit aktuellen Releases
+    let node = 250000;
+
+    for subver in ["/Satoshi:27.0.0/", "/Satoshi:28.1.0/", "/Satoshi:29.0.0/"] {
+        assert!(user_agent_flags(&peer(subver), node).is_empty(), "{}", subver);
+    }
+    assert!(user_agent_flags(&peer("/Satoshi:99.0.0/"), node)[0].contains("gefälscht"));
+}
+
+#[test]
+fn client_distribution_by_label() {
+    let peer = |subver: &str| PeerInfo { subver: subver.to_string(), ..Default::default() };
+    let peers = vec![
+        peer("/Satoshi:27.0.0/"),
+        peer("/Satoshi:27.1.0/"),
+        peer("/Satoshi:25.1.0/Knots:20231115/"),
+        peer(""),
+    ];
+
+    assert_eq!(client_distribution(&peers), vec![
+        ("Core 27".to_string(), 2),
+        ("Knots 20231115".to_string(), 1),
+        ("Unbekannt".to_string(), 1),
+    ]);
+}
+
+#[test]
+fn addrman_info_per_network() {
+    let counts = parse_addrman_info(&json!({
+        "onion": { "new": 300, "tried": 100, "total": 400 },
+        "ipv4": { "new": 50_000, "tried": 2_500, "total": 52_500 },
+        "all_networks": { "new": 50_300, "tried": 2_600, "total": 52_900 }
+    }));
+
+    // Feste Reihenfolge, fehlende Netzwerke entfallen, Summe zuletzt
+    let networks: Vec<&str> = counts.iter().map(|c| c.network.as_str()).collect();
+    assert_eq!(networks, vec!["ipv4", "onion", "all_networks"]);
+    assert_eq!(counts[1].tried_percent(), 25.0);
+    assert_eq!(AddrManCount::default().tried_percent(), 0.0);
+}
+
+#[test]
+fn reachability_and_node_addresses() {
+    let reachability = parse_reachability(&json!({
+        "networks": [
+            { "name": "ipv4", "limited": false, "reachable": true, "proxy": "" },
+            { "name": "onion", "limited": true, "reachable": false, "proxy": "127.0.0.1:9050" }
+        ]
+    }));
+    assert_eq!(reachability.len(), 2);
+    assert!(reachability[0].reachable);
+    assert!(reachability[1].limited);
+    assert_eq!(reachability[1].proxy, "127.0.0.1:9050");
+    assert!(parse_reachability(&json!({})).is_empty());
+
+    let address = NodeAddress::from_value(&json!({
+        "time": 1_713_571_767,
+        "services": 3081,
+        "address": "2001:db8::1",
+        "port": 8333,
+        "network": "ipv6"
+    }));
+    assert_eq!(address.port, 8333);
+    assert_eq!(address.network, "ipv6");
+    assert_eq!(address.services, 3081);
+}
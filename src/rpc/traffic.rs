@@ -0,0 +1,63 @@
+use anyhow::Result;
+use bitcoincore_rpc::RpcApi;
+use serde::Serialize;
+use serde_json::Value;
+
+/// Upload-Limit laut -maxuploadtarget (0 = unbegrenzt)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UploadTarget {
+    // Länge des Messzeitraums in Sekunden
+    pub timeframe: u64,
+    pub target: u64,
+    pub target_reached: bool,
+    pub serve_historical_blocks: bool,
+    pub bytes_left_in_cycle: u64,
+    pub time_left_in_cycle: u64,
+}
+
+impl UploadTarget {
+    pub fn is_limited(&self) -> bool {
+        self.target > 0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NetTotals {
+    // Seit dem Start des Nodes
+    pub total_bytes_recv: u64,
+    pub total_bytes_sent: u64,
+    // Zeitpunkt der Messung laut Node in Millisekunden
+    pub time_millis: i64,
+    pub upload_target: UploadTarget,
+}
+
+impl NetTotals {
+    pub fn from_value(totals: &Value) -> Self {
+        let target = totals.get("uploadtarget").cloned().unwrap_or(Value::Null);
+        let number = |value: &Value, key: &str| value.get(key).and_then(|v| v.as_u64()).unwrap_or(0);
+        let flag = |value: &Value, key: &str| value.get(key).and_then(|v| v.as_bool()).unwrap_or(false);
+
+        Self {
+            total_bytes_recv: number(totals, "totalbytesrecv"),
+            total_bytes_sent: number(totals, "totalbytessent"),
+            time_millis: totals.get("timemillis").and_then(|v| v.as_i64()).unwrap_or(0),
+            upload_target: UploadTarget {
+                timeframe: number(&target, "timeframe"),
+                target: number(&target, "target"),
+                target_reached: flag(&target, "target_reached"),
+                serve_historical_blocks: target.get("serve_historical_blocks")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(true),
+                bytes_left_in_cycle: number(&target, "bytes_left_in_cycle"),
+                time_left_in_cycle: number(&target, "time_left_in_cycle"),
+            },
+        }
+    }
+}
+
+impl super::BitcoinRPC {
+    pub fn get_net_totals(&self) -> Result<NetTotals> {
+        let totals = self.client.call::<Value>("getnettotals", &[])?;
+        Ok(NetTotals::from_value(&totals))
+    }
+}
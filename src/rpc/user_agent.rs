@@ -0,0 +1,123 @@
+use super::PeerInfo;
+
+// Core-Versionen vor 22 (0.x) erhalten keine Sicherheitsupdates mehr
+const ANCIENT_CORE_MAJOR: u32 = 22;
+// Neueste bekannte Core-Release; Versionen deutlich darüber sind unplausibel
+const LATEST_CORE_MAJOR: u32 = 30;
+// Ab Core 0.21 melden Peers mindestens Protokollversion 70016 (wtxid-Relay)
+const WTXID_RELAY_CORE_MAJOR: u32 = 21;
+const WTXID_RELAY_PROTOCOL: u64 = 70016;
+// Core selbst schickt deutlich kürzere User-Agents
+const MAX_USER_AGENT_LEN: usize = 80;
+// User-Agents von Clients anderer Chains (Bitcoin Cash, BSV)
+const FOREIGN_CHAIN_AGENTS: [&str; 5] = ["Bitcoin ABC", "Bitcoin Cash Node", "BCH Unlimited", "BitcoinUnlimited", "Bitcoin SV"];
+
+/// Implementierung und Version aus dem User-Agent (subver) eines Peers
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClientVersion {
+    pub implementation: String,
+    pub version: String,
+    // Hauptversion von Bitcoin Core bzw. der Core-Basis von Knots (0.21 -> 21)
+    pub core_major: Option<u32>,
+}
+
+impl ClientVersion {
+    /// Kurzform für Verteilungen, z.B. "Core 27" oder "btcd 0.24.2"
+    pub fn label(&self) -> String {
+        match (self.implementation.as_str(), self.core_major) {
+            ("Bitcoin Core", Some(major)) => format!("Core {}", major),
+            _ => format!("{} {}", self.implementation, self.version),
+        }
+    }
+}
+
+fn core_major(version: &str) -> Option<u32> {
+    let mut parts = version.split('.');
+    match parts.next()?.parse::<u32>().ok()? {
+        0 => parts.next()?.parse().ok(),
+        major => Some(major),
+    }
+}
+
+/// Zerlegt z.B. "/Satoshi:25.1.0/Knots:20231115/" in (Name, Version, Kommentar)
+fn segments(subver: &str) -> Option<Vec<(&str, &str, &str)>> {
+    let inner = subver.strip_prefix('/')?.strip_suffix('/')?;
+    inner.split('/')
+        .map(|segment| {
+            let (name, rest) = segment.split_once(':')?;
+            let (version, comment) = match rest.split_once('(') {
+                Some((version, comment)) => (version, comment.trim_end_matches(')')),
+                None => (rest, ""),
+            };
+            (!name.is_empty() && !version.is_empty()).then_some((name, version, comment))
+        })
+        .collect()
+}
+
+pub fn parse_user_agent(subver: &str) -> Option<ClientVersion> {
+    let segments = segments(subver)?;
+    let satoshi = segments.iter().find(|(name, _, _)| *name == "Satoshi");
+    let base_major = satoshi.and_then(|(_, version, _)| core_major(version));
+
+    // Knots meldet sich als eigenes Segment oder als Kommentar am Satoshi-Segment
+    if let Some((_, version, _)) = segments.iter().find(|(name, _, _)| *name == "Knots") {
+        return Some(ClientVersion { implementation: "Knots".to_string(), version: version.to_string(), core_major: base_major });
+    }
+    if let Some((_, _, comment)) = satoshi.filter(|(_, _, comment)| comment.starts_with("knots")) {
+        let version = comment.trim_start_matches("knots").to_string();
+        return Some(ClientVersion { implementation: "Knots".to_string(), version, core_major: base_major });
+    }
+    if let Some((_, version, _)) = segments.iter().find(|(name, _, _)| *name == "btcd") {
+        return Some(ClientVersion { implementation: "btcd".to_string(), version: version.to_string(), core_major: None });
+    }
+    if let Some((_, version, _)) = satoshi {
+        return Some(ClientVersion { implementation: "Bitcoin Core".to_string(), version: version.to_string(), core_major: base_major });
+    }
+
+    let (name, version, _) = segments.first()?;
+    Some(ClientVersion { implementation: name.to_string(), version: version.to_string(), core_major: None })
+}
+
+/// Gründe, warum ein Peer auffällt; leer, wenn der User-Agent unauffällig ist
+pub fn user_agent_flags(peer: &PeerInfo, node_version: u64) -> Vec<String> {
+    let subver = peer.subver.as_str();
+    if subver.is_empty() {
+        return vec!["Kein User-Agent".to_string()];
+    }
+
+    let mut flags = Vec::new();
+    if subver.chars().any(|c| c.is_control()) {
+        flags.push("Steuerzeichen im User-Agent".to_string());
+    }
+    if subver.len() > MAX_USER_AGENT_LEN {
+        flags.push(format!("Ungewöhnlich langer User-Agent ({} Zeichen)", subver.len()));
+    }
+    if FOREIGN_CHAIN_AGENTS.iter().any(|agent| subver.contains(agent)) {
+        flags.push("Client einer anderen Chain".to_string());
+    }
+
+    let Some(client) = parse_user_agent(subver) else {
+        flags.push("Ungewöhnliches Format (erwartet /Name:Version/)".to_string());
+        return flags;
+    };
+
+    if client.implementation == "Bitcoin Core" || client.implementation == "Knots" {
+        let Some(major) = client.core_major else {
+            flags.push(format!("Unplausible Versionsnummer: {}", client.version));
+            return flags;
+        };
+        if major < ANCIENT_CORE_MAJOR {
+            flags.push(format!("Veraltete Version ({}), ohne Sicherheitsupdates", client.label()));
+        }
+        // Mehr als eine Release über der neuesten bekannten ist kaum möglich;
+        // ein neuerer eigener Node hebt die Grenze an
+        let latest_major = LATEST_CORE_MAJOR.max((node_version / 10_000) as u32);
+        if major > latest_major + 1 {
+            flags.push(format!("Version {} neuer als bekannt (gefälscht?)", client.version));
+        }
+        if major >= WTXID_RELAY_CORE_MAJOR && peer.version > 0 && peer.version < WTXID_RELAY_PROTOCOL {
+            flags.push(format!("Protokollversion {} passt nicht zu {}", peer.version, client.label()));
+        }
+    }
+    flags
+}
@@ -0,0 +1,159 @@
+use anyhow::Result;
+use bitcoincore_rpc::{Client, RpcApi};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use parking_lot::Mutex;
+use once_cell::sync::Lazy;
+
+#[derive(Debug, Clone)]
+pub struct WalletInfo {
+    pub name: String,
+    pub tx_count: u64,
+    pub keypool_size: u64,
+    pub descriptors: bool,
+    pub private_keys_enabled: bool,
+    pub scanning: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct WalletBalances {
+    pub trusted: f64,
+    pub untrusted_pending: f64,
+    pub immature: f64,
+}
+
+impl WalletBalances {
+    pub fn total(&self) -> f64 {
+        self.trusted + self.untrusted_pending + self.immature
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct WalletTransaction {
+    pub txid: String,
+    pub category: String,
+    pub amount: f64,
+    pub confirmations: i64,
+    pub label: String,
+    pub time: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct WalletOverview {
+    pub info: WalletInfo,
+    pub balances: WalletBalances,
+    pub transactions: Vec<WalletTransaction>,
+}
+
+struct WalletListCache {
+    wallets: Vec<String>,
+    last_update: Instant,
+}
+
+struct WalletCache {
+    overview: WalletOverview,
+    last_update: Instant,
+}
+
+const CACHE_DURATION: Duration = Duration::from_secs(30);
+const TRANSACTION_LIMIT: u64 = 100;
+
+static WALLET_LIST_CACHE: Lazy<Mutex<Option<WalletListCache>>> = Lazy::new(|| Mutex::new(None));
+static WALLET_CACHE: Lazy<Mutex<HashMap<String, WalletCache>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+impl super::BitcoinRPC {
+    /// Liefert die Namen aller geladenen Wallets (`listwallets`)
+    pub fn list_wallets(&self) -> Result<Vec<String>> {
+        let mut cache = WALLET_LIST_CACHE.lock();
+        if let Some(cached) = &*cache {
+            if cached.last_update.elapsed() < CACHE_DURATION {
+                return Ok(cached.wallets.clone());
+            }
+        }
+
+        let wallets = self.client.list_wallets()?;
+        *cache = Some(WalletListCache {
+            wallets: wallets.clone(),
+            last_update: Instant::now(),
+        });
+        Ok(wallets)
+    }
+
+    /// Lädt Info, Guthaben und die letzten Transaktionen einer Wallet
+    pub fn get_wallet_overview(&self, wallet: &str) -> Result<WalletOverview> {
+        let mut cache = WALLET_CACHE.lock();
+        if let Some(cached) = cache.get(wallet) {
+            if cached.last_update.elapsed() < CACHE_DURATION {
+                return Ok(cached.overview.clone());
+            }
+        }
+
+        let client = self.wallet_client(wallet)?;
+
+        let info = client.call::<Value>("getwalletinfo", &[])?;
+        let info = WalletInfo {
+            name: info.get("walletname")
+                .and_then(|v| v.as_str())
+                .unwrap_or(wallet)
+                .to_string(),
+            tx_count: info.get("txcount").and_then(|v| v.as_u64()).unwrap_or(0),
+            keypool_size: info.get("keypoolsize").and_then(|v| v.as_u64()).unwrap_or(0),
+            descriptors: info.get("descriptors").and_then(|v| v.as_bool()).unwrap_or(false),
+            private_keys_enabled: info.get("private_keys_enabled")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(true),
+            // "scanning" ist entweder false oder ein Objekt mit Fortschritt
+            scanning: info.get("scanning").map(|v| v.is_object()).unwrap_or(false),
+        };
+
+        let balances = client.call::<Value>("getbalances", &[])?;
+        let mine = balances.get("mine")
+            .ok_or_else(|| anyhow::anyhow!("Keine Guthaben für Wallet '{}' gefunden", wallet))?;
+        let balances = WalletBalances {
+            trusted: mine.get("trusted").and_then(|v| v.as_f64()).unwrap_or(0.0),
+            untrusted_pending: mine.get("untrusted_pending").and_then(|v| v.as_f64()).unwrap_or(0.0),
+            immature: mine.get("immature").and_then(|v| v.as_f64()).unwrap_or(0.0),
+        };
+
+        let transactions = client.call::<Value>(
+            "listtransactions",
+            &[json!("*"), json!(TRANSACTION_LIMIT), json!(0), json!(true)],
+        )?;
+        // listtransactions liefert die älteste Transaktion zuerst
+        let transactions = transactions.as_array()
+            .map(|entries| entries.iter().rev().map(|tx| WalletTransaction {
+                txid: tx.get("txid").and_then(|v| v.as_str()).unwrap_or("").to_string(),
+                category: tx.get("category").and_then(|v| v.as_str()).unwrap_or("").to_string(),
+                amount: tx.get("amount").and_then(|v| v.as_f64()).unwrap_or(0.0),
+                confirmations: tx.get("confirmations").and_then(|v| v.as_i64()).unwrap_or(0),
+                label: tx.get("label").and_then(|v| v.as_str()).unwrap_or("").to_string(),
+                time: tx.get("time").and_then(|v| v.as_i64()).unwrap_or(0),
+            }).collect())
+            .unwrap_or_default();
+
+        let overview = WalletOverview { info, balances, transactions };
+
+        cache.insert(wallet.to_string(), WalletCache {
+            overview: overview.clone(),
+            last_update: Instant::now(),
+        });
+
+        Ok(overview)
+    }
+
+    // Wallet-RPCs müssen an den Endpunkt /wallet/<name> gehen
+    fn wallet_client(&self, wallet: &str) -> Result<Client> {
+        let url = format!("{}/wallet/{}", self.rpc_url, encode_wallet_name(wallet));
+        Ok(Client::new(&url, self.auth.clone())?)
+    }
+}
+
+fn encode_wallet_name(name: &str) -> String {
+    name.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (b as char).to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
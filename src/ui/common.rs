@@ -6,7 +6,39 @@ pub use ratatui::{
     Frame,
 };
 pub use chrono::{DateTime, Utc, TimeZone};
-pub use crate::ui::Tab; 
+pub use crate::ui::Tab;
+pub use crate::ui::theme::theme;
+
+/// Datenreihe für eine Sparkline (wird erst beim Zeichnen an die Breite angepasst)
+pub struct Chart {
+    pub title: String,
+    pub data: Vec<u64>,
+    pub color: Color,
+}
+
+/// Fasst eine Reihe auf höchstens `width` Werte zusammen (Maximum je Abschnitt),
+/// damit die Sparkline den gesamten Zeitraum statt nur die neuesten Werte zeigt
+pub fn downsample(data: &[u64], width: usize) -> Vec<u64> {
+    if data.len() <= width {
+        return data.to_vec();
+    }
+    (0..width)
+        .filter_map(|bucket| {
+            let start = bucket * data.len() / width;
+            let end = (bucket + 1) * data.len() / width;
+            data[start..end].iter().max().copied()
+        })
+        .collect()
+}
+
+/// Zeitraum für Chart-Titel, z.B. "letzte 1.5 h" oder "letzte 20 min"
+pub fn format_span(seconds: i64) -> String {
+    if seconds >= 3600 {
+        format!("letzte {:.1} h", seconds as f64 / 3600.0)
+    } else {
+        format!("letzte {} min", seconds / 60)
+    }
+}
 
 /// Gemeinsame Funktion für das Anzeigen von Fehlermeldungen
 #[allow(dead_code)]
@@ -18,4 +50,7 @@ pub fn show_error(message: &str) {
 #[allow(dead_code)]
 pub fn show_success(message: &str) {
     println!("✅ {}", message);
-} 
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,23 @@
+use super::*;
+
+#[test]
+fn short_series_is_unchanged() {
+    assert_eq!(downsample(&[1, 2, 3], 10), vec![1, 2, 3]);
+}
+
+#[test]
+fn long_series_covers_whole_range() {
+    let data: Vec<u64> = (0..100).collect();
+    let sampled = downsample(&data, 10);
+    assert_eq!(sampled.len(), 10);
+    // Erster und letzter Abschnitt bleiben erhalten
+    assert_eq!(sampled[0], 9);
+    assert_eq!(sampled[9], 99);
+}
+
+#[test]
+fn spikes_survive_downsampling() {
+    let mut data = vec![1; 1000];
+    data[500] = 50;
+    assert!(downsample(&data, 7).contains(&50));
+}
@@ -1,7 +1,7 @@
 // Für Header, Footer und andere wiederverwendbare Komponenten
 use super::common::*;
 use std::time::Duration;
-use ratatui::widgets::Tabs;
+use ratatui::widgets::{Tabs, Wrap};
 use ratatui::prelude::Alignment;
 
 pub fn create_header(version: u64) -> Paragraph<'static> {
@@ -11,37 +11,75 @@ pub fn create_header(version: u64) -> Paragraph<'static> {
         version % 100
     );
     Paragraph::new(version_str)
-        .style(Style::default().fg(Color::Cyan))
+        .style(Style::default().fg(theme().label))
         .block(Block::default()
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::Cyan))
+            .border_style(Style::default().fg(theme().label))
             .title(" Node Info "))
 }
 
-pub fn create_footer(update_interval: Duration, is_updating: bool, spinner_state: usize) -> Paragraph<'static> {
+pub fn create_footer(since_update: Duration, is_updating: bool, spinner_state: usize, zmq_active: bool) -> Paragraph<'static> {
     let spinner = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
     
     let status_text = if is_updating {
         format!("{} Aktualisiere...", spinner[spinner_state])
     } else {
-        format!("Letzte Aktualisierung: vor {}s", update_interval.as_secs())
+        format!("Letzte Aktualisierung: vor {}s", since_update.as_secs())
+    };
+    // Mit ZMQ kommen neue Blöcke sofort, sonst nur per Polling
+    let source = if zmq_active {
+        Span::styled(" | ZMQ", Style::default().fg(theme().ok))
+    } else {
+        Span::styled(" | Polling", Style::default().fg(theme().muted))
     };
 
     Paragraph::new(vec![
         Line::from(vec![
-            Span::styled("H", Style::default().fg(Color::Yellow)),
+            Span::styled("H", Style::default().fg(theme().title)),
             Span::raw(": Hilfe | "),
-            Span::styled("Q", Style::default().fg(Color::Yellow)),
+            Span::styled("Q", Style::default().fg(theme().title)),
             Span::raw(": Beenden | "),
-            Span::styled("1-9", Style::default().fg(Color::Yellow)),
+            Span::styled("0-9", Style::default().fg(theme().title)),
             Span::raw(": Tabs | "),
-            Span::styled(status_text, Style::default().fg(Color::Blue))
+            Span::styled("Tab", Style::default().fg(theme().title)),
+            Span::raw(": Weiter | "),
+            Span::styled(status_text, Style::default().fg(theme().accent)),
+            source,
         ])
     ])
     .alignment(Alignment::Center)
     .block(Block::default()
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::DarkGray)))
+        .border_style(Style::default().fg(theme().muted)))
+}
+
+pub fn create_export_dialog() -> Paragraph<'static> {
+    Paragraph::new(vec![
+        Line::from(vec![
+            Span::styled("💾 Daten des aktuellen Tabs exportieren",
+                Style::default().fg(theme().title).add_modifier(Modifier::BOLD))
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("J", Style::default().fg(theme().ok).add_modifier(Modifier::BOLD)),
+            Span::raw(": JSON | "),
+            Span::styled("C", Style::default().fg(theme().ok).add_modifier(Modifier::BOLD)),
+            Span::raw(": CSV | "),
+            Span::styled("Esc", Style::default().fg(theme().error).add_modifier(Modifier::BOLD)),
+            Span::raw(": Abbrechen"),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(
+            "Datei mit Zeitstempel im EXPORT_DIR (Standard: aktuelles Verzeichnis)",
+            Style::default().fg(theme().muted)
+        )),
+    ])
+    .alignment(Alignment::Center)
+    .wrap(Wrap { trim: true })
+    .block(Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme().title))
+        .title(" Export "))
 }
 
 pub fn create_tabs(current_tab: &Tab) -> Tabs<'static> {
@@ -55,10 +93,16 @@ pub fn create_tabs(current_tab: &Tab) -> Tabs<'static> {
         "Peer List",
         "Mining",
         "Security",
+        "Wallet",
+        "Watchlist",
+        "PSBT",
+        "Chain Tips",
+        "Traffic",
+        "Addrman",
     ];
     
     let tabs = titles.iter().map(|t| {
-        Line::from(Span::styled(*t, Style::default().fg(Color::White)))
+        Line::from(Span::styled(*t, Style::default().fg(theme().value)))
     }).collect();
 
     Tabs::new(tabs)
@@ -73,20 +117,26 @@ pub fn create_tabs(current_tab: &Tab) -> Tabs<'static> {
             Tab::PeerList => 6,
             Tab::Mining => 7,
             Tab::Security => 8,
+            Tab::Wallet => 9,
+            Tab::Watchlist => 10,
+            Tab::Psbt => 11,
+            Tab::ChainTips => 12,
+            Tab::Traffic => 13,
+            Tab::AddrMan => 14,
         })
-        .style(Style::default().fg(Color::White))
+        .style(Style::default().fg(theme().value))
         .highlight_style(Style::default()
-            .fg(Color::Yellow)
+            .fg(theme().title)
             .add_modifier(Modifier::BOLD))
 }
 
 #[allow(dead_code)]
 /// Erstellt einen Statusblock mit Farbkodierung
-pub fn status_block(status: &str) -> Block {
+pub fn status_block(status: &str) -> Block<'_> {
     match status {
-        "success" => Block::default().borders(Borders::ALL).title("Erfolg").style(Style::default().fg(Color::Green)),
-        "warning" => Block::default().borders(Borders::ALL).title("Warnung").style(Style::default().fg(Color::Yellow)),
-        "error" => Block::default().borders(Borders::ALL).title("Fehler").style(Style::default().fg(Color::Red)),
-        _ => Block::default().borders(Borders::ALL).title("Status").style(Style::default().fg(Color::White)),
+        "success" => Block::default().borders(Borders::ALL).title("Erfolg").style(Style::default().fg(theme().ok)),
+        "warning" => Block::default().borders(Borders::ALL).title("Warnung").style(Style::default().fg(theme().warn)),
+        "error" => Block::default().borders(Borders::ALL).title("Fehler").style(Style::default().fg(theme().error)),
+        _ => Block::default().borders(Borders::ALL).title("Status").style(Style::default().fg(theme().value)),
     }
 } 
\ No newline at end of file
@@ -14,6 +14,12 @@ pub fn create_help(tab: &Tab, _scroll: usize) -> Paragraph<'static> {
         Tab::PeerList => create_peer_list_help(),
         Tab::Mining => create_mining_help(),
         Tab::Security => create_security_help(),
+        Tab::Wallet => create_wallet_help(),
+        Tab::Watchlist => create_watchlist_help(),
+        Tab::Psbt => create_psbt_help(),
+        Tab::ChainTips => create_chain_tips_help(),
+        Tab::Traffic => create_traffic_help(),
+        Tab::AddrMan => create_addrman_help(),
     };
 
     Paragraph::new(content)
@@ -29,12 +35,12 @@ fn create_block_help() -> Vec<Line<'static>> {
     vec![
         Line::from(vec![
             Span::styled("Block Details - Anatomie eines Bitcoin Blocks", 
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().title).add_modifier(Modifier::BOLD))
         ]),
         Line::from(""),
         Line::from(vec![
             Span::styled("📦 Block Identifikation", 
-                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
         ]),
         Line::from(" • Blockhöhe: Position in der Blockchain"),
         Line::from("   → Genesis = Block 0, jeder neue Block +1"),
@@ -42,10 +48,11 @@ fn create_block_help() -> Vec<Line<'static>> {
         Line::from("   → Berechnet aus allen Block-Daten"),
         Line::from(" • Zeitstempel: Erstellungszeit des Blocks"),
         Line::from("   → Muss zwischen Medianzeit der letzten Blöcke und 2h in Zukunft liegen"),
+        Line::from(" • Pool: Miner laut Coinbase-Tag oder Auszahlungsadresse (pools.json)"),
         Line::from(""),
         Line::from(vec![
             Span::styled("📊 Block Größen", 
-                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
         ]),
         Line::from(" • Transaktionen: Anzahl der enthaltenen TXs"),
         Line::from("   → Erste TX ist immer die Mining-Belohnung"),
@@ -56,7 +63,7 @@ fn create_block_help() -> Vec<Line<'static>> {
         Line::from(""),
         Line::from(vec![
             Span::styled("⛏️ Mining Informationen", 
-                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
         ]),
         Line::from(" • Version: Protokoll-Version des Blocks"),
         Line::from("   → Zeigt unterstützte Bitcoin-Funktionen"),
@@ -69,16 +76,208 @@ fn create_block_help() -> Vec<Line<'static>> {
     ]
 }
 
-#[allow(dead_code)]
-/// Zeigt die Hilfe für das Wallet-Modul an
-pub fn wallet_help() {
-    println!("Wallet-Integration hilft Ihnen, verschiedene Wallets zu verwalten und Transaktionen zu signieren.");
+/// Hilfe für den Wallet-Tab
+fn create_wallet_help() -> Vec<Line<'static>> {
+    vec![
+        Line::from(vec![
+            Span::styled("Wallet - Geladene Bitcoin Core Wallets", 
+                Style::default().fg(theme().title).add_modifier(Modifier::BOLD))
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("👛 Auswahl", 
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
+        ]),
+        Line::from(" • Zeigt alle mit loadwallet geladenen Wallets (listwallets)"),
+        Line::from(" • ←/→: Zwischen Wallets wechseln"),
+        Line::from(" • ↑/↓: Transaktionsliste scrollen"),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("💰 Guthaben (getbalances)", 
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
+        ]),
+        Line::from(" • Verfügbar: Bestätigte bzw. eigene Beträge (trusted)"),
+        Line::from(" • Ausstehend: Unbestätigte fremde Eingänge (untrusted_pending)"),
+        Line::from(" • Unreif: Mining-Belohnungen mit < 100 Bestätigungen (immature)"),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("📜 Transaktionen (listtransactions)", 
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
+        ]),
+        Line::from(" • Die letzten 100 Einträge, neueste zuerst"),
+        Line::from(" • Kategorie: receive, send, generate, immature, orphan"),
+        Line::from(" • Gelb: Unbestätigt | Konflikt: Transaktion wurde ersetzt"),
+    ]
 }
 
 #[allow(dead_code)]
 /// Zeigt die Hilfe für den Export-Modul an
 pub fn export_help() {
     println!("Export-Funktionen ermöglichen das Exportieren von Daten in verschiedenen Formaten wie CSV und JSON.");
+    println!("Taste E im Dashboard, Block-, TX-, Adress-, Mempool-, Netzwerk-, Peer-, Traffic- oder Addrman-Tab, dann J (JSON) oder C (CSV).");
+}
+
+/// Hilfe für den Watchlist-Tab
+fn create_watchlist_help() -> Vec<Line<'static>> {
+    vec![
+        Line::from(vec![
+            Span::styled("Watchlist - Transaktionen & Adressen beobachten", 
+                Style::default().fg(theme().title).add_modifier(Modifier::BOLD))
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("👁 Einträge", 
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
+        ]),
+        Line::from(" • A: TXID oder Adresse eingeben und mit Enter hinzufügen"),
+        Line::from(" • D: Ausgewählten Eintrag entfernen"),
+        Line::from(" • Enter: Eintrag im TX- bzw. Adress-Tab öffnen"),
+        Line::from(" • W im TX-/Adress-Tab: Aktuellen Eintrag beobachten"),
+        Line::from(" • Gespeichert in watchlist.txt (WATCHLIST_FILE in .env)"),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("🔔 Benachrichtigungen", 
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
+        ]),
+        Line::from(" • TX bestätigt: Erste Bestätigung einer beobachteten TX"),
+        Line::from(" • TX ersetzt: TX ist aus dem Mempool verschwunden (RBF/verworfen)"),
+        Line::from(" • Zahlungseingang: Adresse hat neue Beträge empfangen"),
+        Line::from(" • Geprüft wird bei jeder Aktualisierung (R)"),
+    ]
+}
+
+/// Hilfe für den Chain-Tips-Tab
+fn create_chain_tips_help() -> Vec<Line<'static>> {
+    vec![
+        Line::from(vec![
+            Span::styled("Chain Tips - Konkurrierende Chains & Forks", 
+                Style::default().fg(theme().title).add_modifier(Modifier::BOLD))
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("🌿 Status (getchaintips)", 
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
+        ]),
+        Line::from(" • active: Die Chain, der der Node folgt"),
+        Line::from(" • valid-fork: Vollständig validierter Zweig, nicht aktiv"),
+        Line::from(" • valid-headers: Blöcke vorhanden, aber nicht vollständig validiert"),
+        Line::from(" • headers-only: Nur Header bekannt, Blöcke fehlen"),
+        Line::from(" • invalid: Zweig enthält mindestens einen ungültigen Block"),
+        Line::from(" • Zweiglänge: Blöcke seit der Abzweigung von der aktiven Chain"),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("🧭 Navigation", 
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
+        ]),
+        Line::from(" • ↑/↓: Tip auswählen | ←/→: Blöcke im Fork-Zweig durchgehen"),
+        Line::from(" • Enter: Ausgewählten Block in Block Details öffnen"),
+        Line::from(" • Neue valide Forks werden bei Aktualisierung (R) gemeldet und markiert"),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("🔀 Reorgs", 
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
+        ]),
+        Line::from(" • Bei jeder Aktualisierung: Liegt der vorherige Tip noch auf der aktiven Chain?"),
+        Line::from(" • Meldung mit Tiefe sowie getrennten und verbundenen Blöcken"),
+        Line::from(" • Protokoll in reorg_events.log (REORG_LOG_FILE in .env)"),
+    ]
+}
+
+/// Hilfe für den Traffic-Tab
+fn create_traffic_help() -> Vec<Line<'static>> {
+    vec![
+        Line::from(vec![
+            Span::styled("Netzwerk-Traffic",
+                Style::default().fg(theme().title).add_modifier(Modifier::BOLD))
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("📶 Durchsatz",
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
+        ]),
+        Line::from(" • Aus getnettotals bei jeder Aktualisierung gemessen"),
+        Line::from(" • Rate = Differenz der Byte-Zähler / Zeit seit der letzten Messung"),
+        Line::from(" • Charts: Download und Upload der letzten Stunde"),
+        Line::from(" • Summen zählen seit dem Start des Nodes"),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("🎯 Upload-Limit",
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
+        ]),
+        Line::from(" • Gesetzt mit -maxuploadtarget in bitcoin.conf"),
+        Line::from(" • Ist das Limit erreicht, liefert der Node keine historischen Blöcke mehr"),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("👥 Peers",
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
+        ]),
+        Line::from(" • Durchsatz je Peer aus bytesrecv/bytessent von getpeerinfo"),
+        Line::from(" • Neue Peers erscheinen ab der zweiten Messung"),
+        Line::from(" • E: Zählerstände als JSON oder CSV exportieren"),
+    ]
+}
+
+/// Hilfe für den Address-Manager-Tab
+fn create_addrman_help() -> Vec<Line<'static>> {
+    vec![
+        Line::from(vec![
+            Span::styled("Address-Manager - Was der Node über das Netzwerk weiß",
+                Style::default().fg(theme().title).add_modifier(Modifier::BOLD))
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("📇 Adressbestand (getaddrmaninfo)",
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
+        ]),
+        Line::from(" • Neu: Von anderen Peers gehört, noch nie verbunden"),
+        Line::from(" • Getestet: Mindestens einmal erfolgreich verbunden"),
+        Line::from(" • Erfordert Bitcoin Core 26 oder neuer"),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("🌍 Erreichbarkeit",
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
+        ]),
+        Line::from(" • Erreichbar: Node kann das Netzwerk nutzen (getnetworkinfo)"),
+        Line::from(" • onlynet: Durch -onlynet ausgeschlossen"),
+        Line::from(" • Proxy: Tor-/I2P-Proxy für dieses Netzwerk"),
+        Line::from(" • Gelb: Erreichbar, aber aktuell ohne verbundene Peers"),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("🔎 Stichprobe",
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
+        ]),
+        Line::from(" • Zufällige Adressen je Netzwerk aus getnodeaddresses"),
+        Line::from(" • Aktualisierung höchstens einmal pro Minute"),
+        Line::from(" • E: Alle bekannten Adressen als JSON oder CSV exportieren"),
+    ]
+}
+
+/// Hilfe für den PSBT-Tab
+fn create_psbt_help() -> Vec<Line<'static>> {
+    vec![
+        Line::from(vec![
+            Span::styled("PSBT - Partially Signed Bitcoin Transactions prüfen", 
+                Style::default().fg(theme().title).add_modifier(Modifier::BOLD))
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("🧾 Laden & Analysieren", 
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
+        ]),
+        Line::from(" • I: Base64-PSBT einfügen oder Dateipfad angeben, Enter lädt"),
+        Line::from(" • Dateien dürfen Base64-Text oder binäre PSBTs (.psbt) enthalten"),
+        Line::from(" • Analyse über decodepsbt und analyzepsbt"),
+        Line::from(" • Pro Input: Prevout, Betrag, fehlende Signaturen/Pubkeys/Scripts"),
+        Line::from(" • Nächste Rolle: Updater → Signer → Finalizer → Extractor"),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("✅ Aktionen", 
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
+        ]),
+        Line::from(" • F: PSBT finalisieren (finalizepsbt)"),
+        Line::from(" • X: Finalisieren und TX-Hex extrahieren"),
+        Line::from(" • ↑/↓: Scrollen | Esc: PSBT verwerfen"),
+    ]
 }
 
 // Für das Dashboard-Tab
@@ -86,14 +285,14 @@ fn create_dashboard_help() -> Vec<Line<'static>> {
     vec![
         Line::from(vec![
             Span::styled("Dashboard - Ihr Bitcoin Node auf einen Blick", 
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().title).add_modifier(Modifier::BOLD))
         ]),
         Line::from(""),
         Line::from("Willkommen! Hier sehen Sie den Status Ihres Bitcoin Nodes:"),
         Line::from(""),
         Line::from(vec![
             Span::styled("⚡ Netzwerk", 
-                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
         ]),
         Line::from(" • Mainnet: Das produktive Bitcoin-Netzwerk"),
         Line::from(" • Testnet: Testnetzwerk für Entwicklung (kostenlose Testnet-BTC)"),
@@ -103,7 +302,7 @@ fn create_dashboard_help() -> Vec<Line<'static>> {
         Line::from(""),
         Line::from(vec![
             Span::styled("📦 Blockchain", 
-                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
         ]),
         Line::from(" • Blöcke: Anzahl validierter Blöcke in der Blockchain"),
         Line::from("   → Jeder Block enthält mehrere Transaktionen"),
@@ -116,31 +315,65 @@ fn create_dashboard_help() -> Vec<Line<'static>> {
         Line::from(""),
         Line::from(vec![
             Span::styled("🔄 Status", 
-                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
         ]),
         Line::from(" • Fortschritt: Initial Block Download (IBD) Status"),
         Line::from("   → 100% = Node ist vollständig synchronisiert"),
+        Line::from(" • Rate: Blöcke/s und Prozent/h über die letzten Aktualisierungen"),
+        Line::from("   → ETA = geschätzter Zeitpunkt der vollständigen Verifikation"),
         Line::from(" • Speicher: Blockchain-Größe auf der Festplatte"),
         Line::from("   → Full Node benötigt aktuell etwa 500+ GB"),
         Line::from(" • Pruned: Node-Modus für reduzierten Speicherbedarf"),
         Line::from("   → 'Ja' = Nur neuere Blöcke werden vorgehalten"),
         Line::from("   → 'Nein' = Vollständige Blockchain wird gespeichert"),
         Line::from(""),
+        Line::from(vec![
+            Span::styled("🚨 Alerts", 
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
+        ]),
+        Line::from(" • Warnungen des Nodes (getblockchaininfo/getnetworkinfo)"),
+        Line::from(" • Eigene Prüfungen: Veralteter Tip, wenige Peers, Uhrzeit-Abweichung"),
+        Line::from(" • Laufwerk: NODE_DATADIR (lokaler Node) oder NODE_DISK_CAPACITY_GB in .env"),
+        Line::from(" • Rot = Kritisch | Gelb = Warnung | Blau = Info"),
+        Line::from(" • Eigene Regeln in alert_rules.txt (Vorlage: alert_rules.example.txt)"),
+        Line::from("   → Aktionen: banner, webhook URL, command BEFEHL"),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("📈 Vergleich zu vor 24 h", 
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
+        ]),
+        Line::from(" • Snapshots werden lokal in SQLite gespeichert (HISTORY_DB)"),
+        Line::from(" • Änderungen erst, wenn der Verlauf 24 h zurückreicht"),
+        Line::from(" • Grün = gestiegen | Rot = gesunken"),
+        Line::from(""),
         Line::from(vec![
             Span::styled("💭 Mempool", 
-                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
         ]),
         Line::from(" • Unbestätigte Transaktionen im lokalen Mempool"),
         Line::from(" • Größerer Mempool = höhere Netzwerk-Auslastung"),
         Line::from(" • Details zu Gebühren (sat/vB) im Mempool-Tab"),
+        Line::from(" • Neue TXs (live): tx/s der letzten Minute, nur mit ZMQ"),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("📡 ZMQ", 
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
+        ]),
+        Line::from(" • ZMQ_HASHBLOCK / ZMQ_RAWTX / ZMQ_SEQUENCE in .env setzen"),
+        Line::from("   → Entsprechen zmqpubhashblock, zmqpubrawtx, zmqpubsequence"),
+        Line::from(" • Neue Blöcke lösen sofort eine Aktualisierung aus"),
+        Line::from(" • Ohne ZMQ wird im eingestellten Intervall abgefragt (Polling)"),
         Line::from(""),
         Line::from(vec![
             Span::styled("⌨️ Steuerung", 
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().title).add_modifier(Modifier::BOLD))
         ]),
         Line::from(" • H: Diese Hilfe zeigen/verstecken"),
         Line::from(" • Q: Programm beenden"),
-        Line::from(" • 1-9: Schnell zwischen Tabs wechseln"),
+        Line::from(" • 0-9: Schnell zwischen Tabs wechseln"),
+        Line::from(" • Tab / Shift+Tab: Nächster / vorheriger Tab"),
+        Line::from(" • R: Sofort aktualisieren"),
+        Line::from(" • E: Daten des Tabs als JSON oder CSV exportieren"),
     ]
 }
 
@@ -148,12 +381,12 @@ fn create_tx_help() -> Vec<Line<'static>> {
     vec![
         Line::from(vec![
             Span::styled("Transaktionsdetails (TX)", 
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().title).add_modifier(Modifier::BOLD))
         ]),
         Line::from(""),
         Line::from(vec![
             Span::styled("💰 Inputs & Outputs (UTXO-Modell)", 
-                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
         ]),
         Line::from(" • Inputs (Herkunft)                    • Outputs (Ziel)"),
         Line::from("   → Verweisen auf frühere UTXOs          → Neue UTXOs (Unspent Transaction Outputs)"),
@@ -170,7 +403,7 @@ fn create_tx_help() -> Vec<Line<'static>> {
         Line::from(""),
         Line::from(vec![
             Span::styled("📏 Größenangaben", 
-                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
         ]),
         Line::from(" • Bytes (physikalisch)                 • Weight Units (WU)"),
         Line::from("   → Tatsächliche Größe der TX           → Interne SegWit-Berechnung"),
@@ -183,11 +416,25 @@ fn create_tx_help() -> Vec<Line<'static>> {
         Line::from(""),
         Line::from(vec![
             Span::styled("🕒 Status", 
-                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
         ]),
         Line::from(" • Bestätigung                          • Block"),
         Line::from("   → Bestätigt = In einem Block           → Blockhash = Block-ID"),
         Line::from("   → Unbestätigt = Im Mempool            → Anzahl Bestätigungen seit Aufnahme"),
+        Line::from(""),
+        Line::from(" • W: Transaktion zur Watchlist hinzufügen"),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("🧪 Roh-Transaktionen prüfen", 
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
+        ]),
+        Line::from(" • L: Raw-TX als Hex einfügen oder aus Datei laden (decoderawtransaction)"),
+        Line::from(" • T: Gegen die Mempool-Policy des Nodes prüfen (testmempoolaccept)"),
+        Line::from("   → Zeigt Ablehnungsgrund, effektive Feerate und vsize"),
+        Line::from("   → Die Transaktion wird dabei NICHT gesendet"),
+        Line::from(" • S: Senden (sendrawtransaction) nach Bestätigung mit J"),
+        Line::from("   → Blockiert bei Ablehnung oder Feerate über BROADCAST_MAX_FEERATE"),
+        Line::from("   → Jeder Versuch landet in broadcast_audit.log"),
     ]
 }
 
@@ -195,12 +442,12 @@ fn create_address_help() -> Vec<Line<'static>> {
     vec![
         Line::from(vec![
             Span::styled("Adressdetails", 
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().title).add_modifier(Modifier::BOLD))
         ]),
         Line::from(""),
         Line::from(vec![
             Span::styled("📋 Allgemeine Information", 
-                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
         ]),
         Line::from(" • Adresstyp: P2PKH, P2SH, P2WPKH, P2WSH oder P2TR"),
         Line::from(" • Erste Aktivität: Zeitpunkt der ersten Transaktion"),
@@ -208,7 +455,7 @@ fn create_address_help() -> Vec<Line<'static>> {
         Line::from(""),
         Line::from(vec![
             Span::styled("💰 Finanzen", 
-                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
         ]),
         Line::from(" • Aktueller Kontostand in BTC"),
         Line::from(" • Gesamtbetrag empfangen"),
@@ -216,7 +463,7 @@ fn create_address_help() -> Vec<Line<'static>> {
         Line::from(""),
         Line::from(vec![
             Span::styled("📊 Statistiken", 
-                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
         ]),
         Line::from(" • Anzahl Transaktionen"),
         Line::from(" • Empfangene UTXOs"),
@@ -225,11 +472,24 @@ fn create_address_help() -> Vec<Line<'static>> {
         Line::from(""),
         Line::from(vec![
             Span::styled("⚡ Mempool", 
-                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
         ]),
         Line::from(" • Unbestätigte Transaktionen"),
         Line::from(" • Eingehende Beträge"),
         Line::from(" • Ausgehende Beträge"),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("📂 Descriptor / xpub Portfolio", 
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
+        ]),
+        Line::from(" • Statt einer Adresse kann ein Descriptor oder xpub/ypub/zpub eingegeben werden"),
+        Line::from(" • xpub → pkh, ypub → sh(wpkh), zpub → wpkh"),
+        Line::from(" • Empfangs- (/0/*) und Wechseladressen (/1/*) bis zum Gap-Limit (DESCRIPTOR_GAP_LIMIT, Standard 20)"),
+        Line::from("   → Nach der letzten benutzten Adresse folgen immer so viele unbenutzte"),
+        Line::from(" • Guthaben über scantxoutset, Fallback: mempool.space"),
+        Line::from(" • Benutzte Adressen über scanblocks (erfordert -blockfilterindex)"),
+        Line::from(""),
+        Line::from(" • W: Adresse zur Watchlist hinzufügen"),
     ]
 }
 
@@ -237,12 +497,12 @@ fn create_mempool_help() -> Vec<Line<'static>> {
     vec![
         Line::from(vec![
             Span::styled("Mempool Übersicht", 
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().title).add_modifier(Modifier::BOLD))
         ]),
         Line::from(""),
         Line::from(vec![
             Span::styled("📊 Statistiken", 
-                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
         ]),
         Line::from(" • Anzahl Transaktionen: Aktuell unbestätigte TXs"),
         Line::from(" • Gesamtgröße: Speicherbedarf in MB"),
@@ -250,7 +510,7 @@ fn create_mempool_help() -> Vec<Line<'static>> {
         Line::from(""),
         Line::from(vec![
             Span::styled("💰 Gebührenkategorien", 
-                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
         ]),
         Line::from(" • Niedrig: 1-5 sat/vB"),
         Line::from(" • Mittel: 6-20 sat/vB"),
@@ -258,10 +518,19 @@ fn create_mempool_help() -> Vec<Line<'static>> {
         Line::from(""),
         Line::from(vec![
             Span::styled("⏳ Schätzungen", 
-                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
         ]),
         Line::from(" • Nächster Block: Wahrscheinliche TXs"),
         Line::from(" • Wartezeit: Geschätzt pro Kategorie"),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("📈 Verlauf", 
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
+        ]),
+        Line::from(" • Sparklines: Transaktionen, vsize, Gebühren, Mindest-Feerate"),
+        Line::from(" • Ein Sample pro Abfrage (höchstens alle 30 Sekunden)"),
+        Line::from(" • ↑ steigend | ↓ fallend | → stabil (verglichen mit 10 Samples zuvor)"),
+        Line::from(" • MEMPOOL_HISTORY_FILE in .env speichert den Verlauf dauerhaft"),
     ]
 }
 
@@ -269,20 +538,29 @@ fn create_network_help() -> Vec<Line<'static>> {
     vec![
         Line::from(vec![
             Span::styled("Netzwerk Übersicht", 
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().title).add_modifier(Modifier::BOLD))
         ]),
         Line::from(""),
         Line::from(vec![
             Span::styled("🌐 Verbindungen", 
-                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
         ]),
         Line::from(" • Eingehend: Verbindungen zu Ihrem Node"),
         Line::from(" • Ausgehend: Verbindungen zu anderen Nodes"),
         Line::from(" • Gesamt: Summe aller Verbindungen"),
+        Line::from(" • Charts: Peers und eingehende Verbindungen der letzten 24 h"),
+        Line::from("   → Aus dem lokalen Verlauf (HISTORY_DB in .env)"),
+        Line::from(" • Netzwerke: ipv4, ipv6, onion (Tor), i2p und cjdns"),
+        Line::from(" • Verbindungstypen: outbound-full-relay, block-relay-only, inbound,"),
+        Line::from("   manual, feeler und addr-fetch"),
+        Line::from(" • Verschlüsselt (v2): Peers mit BIP324-Transport"),
+        Line::from(" • Client-Versionen: Verteilung nach Implementierung und Version"),
+        Line::from("   → Aus dem User-Agent, z.B. /Satoshi:27.0.0/ = Core 27"),
+        Line::from(" • Auffällige User-Agents: Details in der Peer-Liste"),
         Line::from(""),
         Line::from(vec![
             Span::styled("📡 Datenverkehr", 
-                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
         ]),
         Line::from(" • Gesendet: Ausgehende Daten"),
         Line::from(" • Empfangen: Eingehende Daten"),
@@ -290,7 +568,7 @@ fn create_network_help() -> Vec<Line<'static>> {
         Line::from(""),
         Line::from(vec![
             Span::styled("🔒 Version", 
-                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
         ]),
         Line::from(" • Protokoll: Bitcoin P2P Version"),
         Line::from(" • User Agent: Client-Identifikation"),
@@ -302,12 +580,12 @@ fn create_peer_list_help() -> Vec<Line<'static>> {
     vec![
         Line::from(vec![
             Span::styled("Peer Liste", 
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().title).add_modifier(Modifier::BOLD))
         ]),
         Line::from(""),
         Line::from(vec![
             Span::styled("👥 Verbindungen", 
-                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
         ]),
         Line::from(" • IP/Port: Netzwerkadresse des Peers"),
         Line::from(" • Version: Bitcoin Core Version"),
@@ -315,7 +593,7 @@ fn create_peer_list_help() -> Vec<Line<'static>> {
         Line::from(""),
         Line::from(vec![
             Span::styled("📊 Statistiken", 
-                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
         ]),
         Line::from(" • Ping: Verbindungsqualität in ms"),
         Line::from(" • Gesendet: Ausgehende Bytes"),
@@ -323,11 +601,22 @@ fn create_peer_list_help() -> Vec<Line<'static>> {
         Line::from(""),
         Line::from(vec![
             Span::styled("⏱️ Zeitangaben", 
-                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
         ]),
         Line::from(" • Verbunden seit: Dauer der Verbindung"),
         Line::from(" • Letzter Block: Zeitpunkt des letzten Blocks"),
         Line::from(" • Synchronisation: Fortschritt in %"),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("⚠️ Auffällige User-Agents", 
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
+        ]),
+        Line::from(" • ↑/↓: Peer auswählen, der Grund steht oben in den Details"),
+        Line::from(" • Fehlender oder ungewöhnlich formatierter User-Agent"),
+        Line::from(" • Veraltete Core-Versionen (0.x) ohne Sicherheitsupdates"),
+        Line::from(" • Version über der neuesten bekannten Release + 1 oder unpassende"),
+        Line::from("   Protokollversion: möglicherweise gefälscht"),
+        Line::from(" • Clients anderer Chains (z.B. Bitcoin Cash)"),
     ]
 }
 
@@ -335,20 +624,21 @@ fn create_mining_help() -> Vec<Line<'static>> {
     vec![
         Line::from(vec![
             Span::styled("Mining Information", 
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().title).add_modifier(Modifier::BOLD))
         ]),
         Line::from(""),
         Line::from(vec![
             Span::styled("⛏️ Mining Status", 
-                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
         ]),
         Line::from(" • Schwierigkeit: Aktuelle Mining-Difficulty"),
-        Line::from(" • Hashrate: Geschätzte Netzwerk-Hashrate"),
-        Line::from(" • Nächste Anpassung: Blocks/Zeit bis Difficulty-Change"),
+        Line::from(" • Hashrate: getnetworkhashps über 120 Blöcke, die Epoche und 1 Tag"),
+        Line::from(" • Nächste Anpassung: Blocks/Datum bis Difficulty-Change"),
+        Line::from("   → Hochgerechnet aus der Ø Blockzeit der laufenden Epoche"),
         Line::from(""),
         Line::from(vec![
             Span::styled("📈 Block Statistiken", 
-                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
         ]),
         Line::from(" • Letzte Anpassung: Änderung in %"),
         Line::from(" • Durchschnittliche Blockzeit"),
@@ -356,11 +646,28 @@ fn create_mining_help() -> Vec<Line<'static>> {
         Line::from(""),
         Line::from(vec![
             Span::styled("💰 Belohnungen", 
-                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
         ]),
-        Line::from(" • Block Subsidy: Aktuelle Block-Belohnung"),
-        Line::from(" • Nächste Halbierung: Blocks/Zeit"),
+        Line::from(" • Block Subsidy: Aktuelle und nächste Block-Belohnung"),
+        Line::from(" • Nächste Halbierung: Blocks/Datum (alle 210.000 Blöcke)"),
         Line::from(" • Durchschnittliche Gebühren/Block"),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("🧱 Nächster Block", 
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
+        ]),
+        Line::from(" • Vorschau per getblocktemplate (segwit): was der Node minen würde"),
+        Line::from(" • TXs, Gebühren, Weight, Feerate-Spanne und Coinbase-Wert"),
+        Line::from(" • Tabelle: Transaktionen mit der höchsten Paket-Feerate (inkl. CPFP)"),
+        Line::from(" • Neu abgefragt bei neuem Block oder Mempool-Änderung"),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("🏊 Pools", 
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
+        ]),
+        Line::from(" • Zuordnung über Coinbase-Tag und Auszahlungsadresse"),
+        Line::from(" • Definitionen in pools.json (Format der öffentlichen Pool-Listen)"),
+        Line::from(" • Verteilung über die letzten POOL_SHARE_BLOCKS Blöcke (Standard: 144)"),
     ]
 }
 
@@ -368,12 +675,12 @@ fn create_security_help() -> Vec<Line<'static>> {
     vec![
         Line::from(vec![
             Span::styled("Sicherheit", 
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().title).add_modifier(Modifier::BOLD))
         ]),
         Line::from(""),
         Line::from(vec![
             Span::styled("🔒 Node Sicherheit", 
-                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
         ]),
         Line::from(" • Verbindungen: Verschlüsselt/Unverschlüsselt"),
         Line::from(" • Authentifizierung: RPC Zugriffskontrolle"),
@@ -381,7 +688,7 @@ fn create_security_help() -> Vec<Line<'static>> {
         Line::from(""),
         Line::from(vec![
             Span::styled("🛡️ Blockchain", 
-                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
         ]),
         Line::from(" • Verifizierung: Signatur-Checks"),
         Line::from(" • Konsens: Aktuelle Regeln"),
@@ -389,7 +696,7 @@ fn create_security_help() -> Vec<Line<'static>> {
         Line::from(""),
         Line::from(vec![
             Span::styled("⚠️ Warnungen", 
-                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
         ]),
         Line::from(" • Version: Sicherheitsupdates verfügbar"),
         Line::from(" • Netzwerk: Verbindungsprobleme"),
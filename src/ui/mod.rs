@@ -3,8 +3,10 @@ mod help;
 mod components;
 mod render;
 mod common;
+pub mod theme;
 
 use std::io;
+use std::sync::mpsc::{self, Receiver};
 use std::time::{Duration, Instant};
 use crossterm::{
     event::{self, Event, KeyCode, KeyEvent},
@@ -15,15 +17,39 @@ use ratatui::{
     backend::{CrosstermBackend},
     Terminal,
     widgets::{Block, Borders, Paragraph},
-    style::{Style, Color},
+    style::Style,
     layout::Alignment,
 };
-use crate::rpc::{BitcoinRPC, NodeStatus};
+use crate::rpc::{AddrManOverview, BitcoinRPC, BlockTemplate, MiningStats, NodeStatus, max_broadcast_feerate, read_raw_tx};
 pub use crate::ui::tabs::block_details::BlockSearchMode;
-use crate::ui::tabs::tx_details::TxMode;
+use crate::ui::tabs::tx_details::{BroadcastConfirm, TxMode};
 use crate::ui::tabs::address_details::AddressMode;
+use crate::ui::tabs::wallet::WalletMode;
+use crate::ui::tabs::watchlist::WatchlistMode;
+use crate::ui::tabs::psbt::PsbtMode;
+use crate::ui::tabs::chain_tips::ChainTipsMode;
+use crate::ui::tabs::peer_list::PeerListMode;
+use crate::ui::tabs::dashboard::SyncTracker;
+use crate::ui::tabs::traffic::TrafficMonitor;
+use crate::ui::theme::theme;
+use crate::watchlist::{WatchTarget, Watchlist};
+use crate::history::{HistoryStore, HistoryView};
+use crate::pools::PoolShare;
+use crate::reorg::ReorgMonitor;
+use crate::alerts::Alert;
+use crate::alerts::rules::{self, AlertEngine, NotificationKind};
+use crate::zmq::{TxRateCounter, ZmqConfig, ZmqEvent, ZmqListener};
+use crate::export::{self, ExportFormat};
 
-#[derive(PartialEq)]
+// Mindestabstand zwischen zwei getblocktemplate-Abfragen
+const TEMPLATE_MIN_INTERVAL: Duration = Duration::from_secs(5);
+// Der Address-Manager ändert sich langsam, getnodeaddresses nicht öfter abfragen
+const ADDRMAN_MIN_INTERVAL: Duration = Duration::from_secs(60);
+
+// (Anzahl Blöcke, Verteilung) aus dem Hintergrund-Thread
+type PoolSharesResult = anyhow::Result<(u64, Vec<PoolShare>)>;
+
+#[derive(PartialEq, Clone, Copy)]
 pub enum Tab {
     Dashboard,
     BlockDetails,
@@ -34,6 +60,45 @@ pub enum Tab {
     PeerList,
     Mining,
     Security,
+    Wallet,
+    Watchlist,
+    Psbt,
+    ChainTips,
+    Traffic,
+    AddrMan,
+}
+
+impl Tab {
+    /// Reihenfolge der Tabs in der Navigation
+    pub const ALL: [Tab; 15] = [
+        Tab::Dashboard,
+        Tab::BlockDetails,
+        Tab::TxDetails,
+        Tab::AddressDetails,
+        Tab::Mempool,
+        Tab::Network,
+        Tab::PeerList,
+        Tab::Mining,
+        Tab::Security,
+        Tab::Wallet,
+        Tab::Watchlist,
+        Tab::Psbt,
+        Tab::ChainTips,
+        Tab::Traffic,
+        Tab::AddrMan,
+    ];
+
+    pub fn index(&self) -> usize {
+        Tab::ALL.iter().position(|t| t == self).unwrap_or(0)
+    }
+
+    pub fn next(&self) -> Tab {
+        Tab::ALL[(self.index() + 1) % Tab::ALL.len()]
+    }
+
+    pub fn previous(&self) -> Tab {
+        Tab::ALL[(self.index() + Tab::ALL.len() - 1) % Tab::ALL.len()]
+    }
 }
 
 pub struct UI {
@@ -53,10 +118,41 @@ pub struct UI {
     block_input: String,
     block_search_mode: BlockSearchMode,
     tx_mode: Option<TxMode>,
+    // Some(..) solange eine Roh-Transaktion eingegeben wird
+    tx_input: Option<String>,
     address_mode: Option<AddressMode>,
+    wallet_mode: WalletMode,
+    watchlist: Watchlist,
+    watchlist_mode: WatchlistMode,
+    psbt_mode: PsbtMode,
+    chain_tips_mode: ChainTipsMode,
+    peer_list_mode: PeerListMode,
+    sync_tracker: SyncTracker,
+    reorg_monitor: ReorgMonitor,
+    // None ohne konfigurierte ZMQ-Endpunkte, dann nur Polling
+    zmq: Option<ZmqListener>,
+    tx_rate: TxRateCounter,
+    // Formatauswahl (JSON/CSV) für den Export des aktuellen Tabs
+    export_prompt: bool,
+    alert_engine: AlertEngine,
+    // None, wenn HISTORY_DB leer gesetzt ist
+    history: Option<HistoryStore>,
+    history_view: HistoryView,
     should_quit: bool,
-    blocks_until_adjustment: i64,
-    next_difficulty_estimate: f64,
+    mining_stats: Option<MiningStats>,
+    // Fehlermeldung statt Template, z.B. während des initialen Syncs
+    block_template: Option<Result<BlockTemplate, String>>,
+    // Neuer Block oder Mempool-Änderung seit der letzten Template-Abfrage
+    template_stale: bool,
+    template_fetched: Option<Instant>,
+    // (Anzahl Blöcke, Verteilung) und der Tip, für den sie berechnet wurde
+    pool_shares: Option<(u64, Vec<PoolShare>)>,
+    pool_shares_tip: Option<String>,
+    // Laufende Berechnung im Hintergrund; beim ersten Aufruf bis zu 288 RPCs
+    pool_shares_job: Option<Receiver<PoolSharesResult>>,
+    traffic: TrafficMonitor,
+    addrman: Option<AddrManOverview>,
+    addrman_fetched: Option<Instant>,
 }
 
 #[derive(Clone)]
@@ -82,6 +178,25 @@ impl UI {
         let stdout = io::stdout();
         
         let terminal = Terminal::new(CrosstermBackend::new(stdout))?;
+
+        // Fehlerhafte Regeldatei soll die TUI nicht blockieren
+        let mut status_messages = Vec::new();
+        let alert_engine = AlertEngine::load().unwrap_or_else(|e| {
+            status_messages.push(StatusMessage {
+                text: format!("Alert-Regeln nicht geladen: {}", e),
+                level: MessageLevel::Error,
+                timestamp: Instant::now(),
+            });
+            AlertEngine::new(Vec::new())
+        });
+        let history = HistoryStore::from_env().unwrap_or_else(|e| {
+            status_messages.push(StatusMessage {
+                text: format!("Verlauf nicht verfügbar: {}", e),
+                level: MessageLevel::Error,
+                timestamp: Instant::now(),
+            });
+            None
+        });
         
         Ok(Self {
             terminal,
@@ -94,15 +209,38 @@ impl UI {
             rpc_client: None,
             node_info: None,
             connection_state: ConnectionState::Connecting,
-            status_messages: Vec::new(),
+            status_messages,
             block_input_active: false,
             block_input: String::new(),
             block_search_mode: initial_block_mode,
-            tx_mode: initial_tx.map(|txid| TxMode::new(txid)),
+            tx_mode: initial_tx.map(TxMode::new),
+            tx_input: None,
             address_mode: initial_addr.map(|address| AddressMode { address }),
+            wallet_mode: WalletMode::default(),
+            watchlist: Watchlist::load(),
+            watchlist_mode: WatchlistMode::default(),
+            psbt_mode: PsbtMode::default(),
+            chain_tips_mode: ChainTipsMode::default(),
+            peer_list_mode: PeerListMode::default(),
+            sync_tracker: SyncTracker::default(),
+            reorg_monitor: ReorgMonitor::new(),
+            zmq: None,
+            tx_rate: TxRateCounter::default(),
+            export_prompt: false,
+            alert_engine,
+            history,
+            history_view: HistoryView::default(),
             should_quit: false,
-            blocks_until_adjustment: 0,
-            next_difficulty_estimate: 0.0,
+            mining_stats: None,
+            block_template: None,
+            template_stale: true,
+            template_fetched: None,
+            pool_shares: None,
+            pool_shares_tip: None,
+            pool_shares_job: None,
+            traffic: TrafficMonitor::default(),
+            addrman: None,
+            addrman_fetched: None,
         })
     }
 
@@ -125,11 +263,18 @@ impl UI {
             Ok(client) => {
                 match client.test_connection() {
                     Ok(info) => {
+                        self.sync_tracker.record(info.height, info.verification_progress);
+                        if let Ok(tips) = client.get_chain_tips() {
+                            self.chain_tips_mode.observe(&tips);
+                            self.chain_tips_mode.load_branch(&client);
+                        }
+                        let _ = self.reorg_monitor.check(&client, info.height, &info.block_hash);
                         self.rpc_client = Some(client);
                         self.node_info = Some(info);
                         self.connection_state = ConnectionState::Connected;
                         self.last_update = Instant::now();
                         self.show_status("Verbindung hergestellt".to_string(), MessageLevel::Info);
+                        self.start_zmq();
                     }
                     Err(e) => {
                         let error = format!("Verbindungstest fehlgeschlagen: {}", e);
@@ -152,6 +297,22 @@ impl UI {
 
         while !self.should_quit {
             self.cleanup_old_messages();
+            self.handle_zmq_events();
+            self.refresh_block_template();
+            self.refresh_pool_shares();
+            self.refresh_addrman();
+
+            // Polling als Fallback, falls ZMQ fehlt oder Nachrichten ausbleiben
+            if self.connection_state == ConnectionState::Connected
+                && self.last_update.elapsed() >= self.update_interval
+            {
+                let _ = self.update();
+            }
+            // Nur mit rawtx oder sequence gibt es eine Live-Rate, sonst bliebe sie bei 0
+            let live_tx_rate = self.zmq.as_ref()
+                .filter(|zmq| zmq.counts_transactions())
+                .map(|_| self.tx_rate.per_second());
+            let rule_alerts: Vec<Alert> = self.alert_engine.firing_banners().iter().map(|rule| rule.alert()).collect();
 
             // UI rendern
             self.terminal.draw(|f| {
@@ -159,14 +320,14 @@ impl UI {
                     ConnectionState::Connecting => {
                         let loading = Paragraph::new("Verbinde mit Bitcoin Node...")
                             .block(Block::default().borders(Borders::ALL))
-                            .style(Style::default().fg(Color::Yellow))
+                            .style(Style::default().fg(theme().warn))
                             .alignment(Alignment::Center);
                         f.render_widget(loading, f.size());
                     }
                     ConnectionState::Error(ref error) => {
                         let error_msg = Paragraph::new(format!("Verbindungsfehler:\n{}", error))
                             .block(Block::default().borders(Borders::ALL))
-                            .style(Style::default().fg(Color::Red))
+                            .style(Style::default().fg(theme().error))
                             .alignment(Alignment::Center);
                         f.render_widget(error_msg, f.size());
                     }
@@ -185,7 +346,7 @@ impl UI {
                                 node_info.verification_progress,
                                 node_info.mempool_size,
                                 &node_info.network,
-                                self.update_interval,
+                                self.last_update.elapsed(),
                                 self.is_updating,
                                 self.spinner_state,
                                 &node_info.mempool_info,
@@ -195,7 +356,25 @@ impl UI {
                                 &self.block_search_mode,
                                 self.block_input_active,
                                 &self.tx_mode,
+                                &self.tx_input,
                                 &self.address_mode,
+                                &self.wallet_mode,
+                                &self.watchlist,
+                                &self.watchlist_mode,
+                                &self.psbt_mode,
+                                &self.chain_tips_mode,
+                                &self.peer_list_mode,
+                                &self.sync_tracker,
+                                self.zmq.is_some(),
+                                live_tx_rate,
+                                self.export_prompt,
+                                &rule_alerts,
+                                &self.history_view,
+                                self.mining_stats.as_ref(),
+                                self.block_template.as_ref(),
+                                self.pool_shares.as_ref(),
+                                &self.traffic,
+                                self.addrman.as_ref(),
                             )
                         }
                     }
@@ -204,15 +383,113 @@ impl UI {
 
             // Event handling
             if crossterm::event::poll(Duration::from_millis(250))? {
-                match event::read()? {
-                    Event::Key(key) => self.handle_input(key),
-                    _ => {}
+                if let Event::Key(key) = event::read()? {
+                    self.handle_input(key);
                 }
             }
         }
         Ok(())
     }
 
+    // Endpunkte kommen aus der .env, die erst beim Verbinden geladen wird
+    fn start_zmq(&mut self) {
+        if self.zmq.is_none() {
+            if let Some(config) = ZmqConfig::from_env() {
+                self.zmq = Some(ZmqListener::start(config));
+                self.tx_rate.start();
+            }
+        }
+    }
+
+    fn handle_zmq_events(&mut self) {
+        let Some(listener) = &self.zmq else { return };
+        let mut new_block = false;
+
+        for event in listener.drain() {
+            if event.is_block_event() {
+                new_block = true;
+            } else if listener.is_new_mempool_tx(&event) {
+                self.tx_rate.record();
+                self.template_stale = true;
+            } else if let ZmqEvent::Error(error) = event {
+                self.status_messages.push(StatusMessage {
+                    text: error,
+                    level: MessageLevel::Error,
+                    timestamp: Instant::now(),
+                });
+            }
+        }
+
+        // Neuer Block: sofort aktualisieren statt auf das Intervall zu warten
+        if new_block && self.connection_state == ConnectionState::Connected {
+            let _ = self.update();
+        }
+    }
+
+    /// getblocktemplate ist teuer: nur im Mining-Tab und höchstens einmal pro Intervall
+    fn refresh_block_template(&mut self) {
+        if self.current_tab != Tab::Mining
+            || !self.template_stale
+            || self.template_fetched.is_some_and(|fetched| fetched.elapsed() < TEMPLATE_MIN_INTERVAL)
+        {
+            return;
+        }
+        let Some(client) = &self.rpc_client else { return };
+
+        self.block_template = Some(client.get_block_template().map_err(|e| {
+            log::warn!("Block-Template konnte nicht abgefragt werden: {}", e);
+            e.to_string()
+        }));
+        self.template_stale = false;
+        self.template_fetched = Some(Instant::now());
+    }
+
+    /// Address-Manager nur im Addrman-Tab und höchstens einmal pro Minute abfragen
+    fn refresh_addrman(&mut self) {
+        if self.current_tab != Tab::AddrMan
+            || self.addrman_fetched.is_some_and(|fetched| fetched.elapsed() < ADDRMAN_MIN_INTERVAL)
+        {
+            return;
+        }
+        let Some(client) = &self.rpc_client else { return };
+
+        match client.get_addrman_overview() {
+            Ok(overview) => self.addrman = Some(overview),
+            Err(e) => log::warn!("Address-Manager konnte nicht abgefragt werden: {}", e),
+        }
+        self.addrman_fetched = Some(Instant::now());
+    }
+
+    /// Pool-Verteilung nur im Mining-Tab und nur nach einem neuen Block neu berechnen
+    fn refresh_pool_shares(&mut self) {
+        // Ergebnis einer laufenden Berechnung übernehmen, auch wenn der Tab gewechselt wurde
+        if let Some(job) = &self.pool_shares_job {
+            match job.try_recv() {
+                Ok(Ok(shares)) => self.pool_shares = Some(shares),
+                Ok(Err(e)) => log::warn!("Pool-Verteilung konnte nicht berechnet werden: {}", e),
+                Err(mpsc::TryRecvError::Empty) => return,
+                Err(mpsc::TryRecvError::Disconnected) => {}
+            }
+            self.pool_shares_job = None;
+        }
+
+        if self.current_tab != Tab::Mining {
+            return;
+        }
+        let (Some(client), Some(info)) = (&self.rpc_client, &self.node_info) else { return };
+        if self.pool_shares_tip.as_deref() == Some(info.block_hash.as_str()) {
+            return;
+        }
+
+        let (sender, job) = mpsc::channel();
+        let rpc = client.clone();
+        std::thread::spawn(move || {
+            let _ = sender.send(rpc.get_pool_shares());
+        });
+        self.pool_shares_job = Some(job);
+        self.pool_shares_tip = Some(info.block_hash.clone());
+    }
+
     pub fn update(&mut self) -> anyhow::Result<()> {
         self.is_updating = true;
         self.spinner_state = (self.spinner_state + 1) % 4;
@@ -220,13 +497,93 @@ impl UI {
         let result = if let Some(client) = &self.rpc_client {
             match client.test_connection() {
                 Ok(info) => {
-                    let difficulty = info.difficulty;
+                    let height = info.height;
+                    self.sync_tracker.record(height, info.verification_progress);
+
+                    // Vorherigen Tip gegen die aktive Chain prüfen
+                    match self.reorg_monitor.check(client, height, &info.block_hash) {
+                        Ok(Some(event)) => self.status_messages.push(StatusMessage {
+                            text: event.message(),
+                            level: MessageLevel::Error,
+                            timestamp: Instant::now(),
+                        }),
+                        Ok(None) => {},
+                        Err(e) => log::warn!("Reorg-Prüfung fehlgeschlagen: {}", e),
+                    }
+                    // Mempool-Verlauf auch außerhalb des Mempool-Tabs fortschreiben
+                    let mempool = client.get_mempool_stats().ok();
+
+                    // Benutzerdefinierte Regeln prüfen und Aktionen auslösen
+                    let now = chrono::Utc::now().timestamp();
+                    let values = rules::sample_values(&info, mempool.as_ref(), now);
+                    for notification in self.alert_engine.evaluate(&values, now) {
+                        rules::dispatch(&notification);
+                        if notification.wants_banner() {
+                            let level = match notification.kind {
+                                NotificationKind::Fired => MessageLevel::Error,
+                                NotificationKind::Resolved => MessageLevel::Info,
+                            };
+                            self.status_messages.push(StatusMessage {
+                                text: notification.message,
+                                level,
+                                timestamp: Instant::now(),
+                            });
+                        }
+                    }
+
+                    // Snapshot speichern und Vergleichswerte für die Tabs neu berechnen
+                    if let Some(history) = self.history.as_mut() {
+                        match history.record(&info, mempool.as_ref(), now).and_then(|_| history.view(now)) {
+                            Ok(view) => self.history_view = view,
+                            Err(e) => log::warn!("Verlauf konnte nicht gespeichert werden: {}", e),
+                        }
+                    }
+                    // Byte-Zähler für die Durchsatzraten im Traffic-Tab
+                    match client.get_net_totals() {
+                        Ok(totals) => self.traffic.record(totals, &info.peers),
+                        Err(e) => log::warn!("Traffic-Daten konnten nicht abgefragt werden: {}", e),
+                    }
+
+                    // Template nur bei neuem Block oder geändertem Mempool neu abfragen
+                    if self.node_info.as_ref().is_none_or(|old| {
+                        old.block_hash != info.block_hash || old.mempool_size != info.mempool_size
+                    }) {
+                        self.template_stale = true;
+                    }
                     self.node_info = Some(info);
                     
-                    // Difficulty-Anpassung berechnen
-                    if let Ok((blocks_until, adjustment)) = client.get_difficulty_adjustment_estimate() {
-                        self.blocks_until_adjustment = blocks_until;
-                        self.next_difficulty_estimate = difficulty * (1.0 + adjustment / 100.0);
+                    // Hashrate, Difficulty-Anpassung und Halving für den Mining-Tab
+                    match client.get_mining_stats() {
+                        Ok(stats) => self.mining_stats = Some(stats),
+                        Err(e) => log::warn!("Mining-Daten konnten nicht abgefragt werden: {}", e),
+                    }
+
+                    // Neue valide Forks melden
+                    match client.get_chain_tips() {
+                        Ok(tips) => {
+                            for tip in self.chain_tips_mode.observe(&tips) {
+                                self.status_messages.push(StatusMessage {
+                                    text: format!(
+                                        "Neuer valider Fork bei Höhe {} (Zweiglänge {}): {}",
+                                        tip.height, tip.branch_len, tip.hash
+                                    ),
+                                    level: MessageLevel::Error,
+                                    timestamp: Instant::now(),
+                                });
+                            }
+                            self.chain_tips_mode.load_branch(client);
+                        }
+                        Err(e) => self.chain_tips_mode.tips_error = Some(e.to_string()),
+                    }
+
+                    // Beobachtete TXs und Adressen prüfen
+                    for event in self.watchlist.refresh(client, height) {
+                        let level = if event.is_warning() { MessageLevel::Error } else { MessageLevel::Info };
+                        self.status_messages.push(StatusMessage {
+                            text: event.message(),
+                            level,
+                            timestamp: Instant::now(),
+                        });
                     }
                     
                     self.last_update = Instant::now();
@@ -277,6 +634,29 @@ impl UI {
     }
 
     pub fn handle_input(&mut self, key: KeyEvent) {
+        // Texteingabe hat Vorrang vor den globalen Tasten
+        if self.current_tab == Tab::Watchlist && self.watchlist_mode.input.is_some() {
+            self.handle_watchlist_text_input(key.code);
+            return;
+        }
+        if self.current_tab == Tab::TxDetails
+            && self.tx_mode.as_ref().is_some_and(|m| m.confirm_broadcast.is_some()) {
+            self.handle_broadcast_confirm(key.code);
+            return;
+        }
+        if self.current_tab == Tab::TxDetails && self.tx_input.is_some() {
+            self.handle_tx_text_input(key.code);
+            return;
+        }
+        if self.current_tab == Tab::Psbt && self.psbt_mode.input.is_some() {
+            self.handle_psbt_text_input(key.code);
+            return;
+        }
+        if self.export_prompt {
+            self.handle_export_prompt(key.code);
+            return;
+        }
+
         match key.code {
             KeyCode::Char('q') | KeyCode::Char('Q') => {
                 self.should_quit = true;
@@ -296,6 +676,365 @@ impl UI {
             KeyCode::Char('7') => self.current_tab = Tab::PeerList,
             KeyCode::Char('8') => self.current_tab = Tab::Mining,
             KeyCode::Char('9') => self.current_tab = Tab::Security,
+            KeyCode::Char('0') => self.current_tab = Tab::Wallet,
+            KeyCode::Char('e') | KeyCode::Char('E') => self.export_prompt = true,
+            KeyCode::Tab => self.current_tab = self.current_tab.next(),
+            KeyCode::BackTab => self.current_tab = self.current_tab.previous(),
+            KeyCode::Left | KeyCode::Right | KeyCode::Up | KeyCode::Down
+                if self.current_tab == Tab::Wallet => self.handle_wallet_input(key.code),
+            KeyCode::Char('w') | KeyCode::Char('W')
+                if matches!(self.current_tab, Tab::TxDetails | Tab::AddressDetails) => self.watch_current(),
+            KeyCode::Char('l') | KeyCode::Char('L') if self.current_tab == Tab::TxDetails => {
+                self.tx_input = Some(String::new());
+            },
+            KeyCode::Char('t') | KeyCode::Char('T') if self.current_tab == Tab::TxDetails => self.test_mempool_accept(),
+            KeyCode::Char('s') | KeyCode::Char('S') if self.current_tab == Tab::TxDetails => self.prepare_broadcast(),
+            KeyCode::Char('a') | KeyCode::Char('A') | KeyCode::Char('d') | KeyCode::Char('D')
+            | KeyCode::Up | KeyCode::Down | KeyCode::Enter
+                if self.current_tab == Tab::Watchlist => self.handle_watchlist_input(key.code),
+            KeyCode::Up | KeyCode::Down | KeyCode::Left | KeyCode::Right | KeyCode::Enter
+                if self.current_tab == Tab::ChainTips => self.handle_chain_tips_input(key.code),
+            KeyCode::Up | KeyCode::Down if self.current_tab == Tab::PeerList => {
+                let peers = self.node_info.as_ref().map(|info| info.peers.as_slice()).unwrap_or_default();
+                if key.code == KeyCode::Up {
+                    self.peer_list_mode.select_previous(peers);
+                } else {
+                    self.peer_list_mode.select_next(peers);
+                }
+            },
+            KeyCode::Char('i') | KeyCode::Char('I') | KeyCode::Char('f') | KeyCode::Char('F')
+            | KeyCode::Char('x') | KeyCode::Char('X') | KeyCode::Up | KeyCode::Down | KeyCode::Esc
+                if self.current_tab == Tab::Psbt => self.handle_psbt_input(key.code),
+            _ => {}
+        }
+    }
+
+    // Aktuelle TX bzw. Adresse zur Watchlist hinzufügen
+    fn watch_current(&mut self) {
+        let target = match self.current_tab {
+            Tab::TxDetails => self.tx_mode.as_ref().map(|m| WatchTarget::Transaction(m.txid.clone())),
+            Tab::AddressDetails => self.address_mode.as_ref().map(|m| WatchTarget::Address(m.address.clone())),
+            _ => None,
+        };
+        if let Some(target) = target {
+            self.add_to_watchlist(target);
+        }
+    }
+
+    fn add_to_watchlist(&mut self, target: WatchTarget) {
+        let id = target.id().to_string();
+        match self.watchlist.add(target) {
+            Ok(true) => self.show_status(format!("Zur Watchlist hinzugefügt: {}", id), MessageLevel::Info),
+            Ok(false) => self.show_status(format!("Wird bereits beobachtet: {}", id), MessageLevel::Info),
+            Err(e) => self.show_status(format!("Watchlist konnte nicht gespeichert werden: {}", e), MessageLevel::Error),
+        }
+    }
+
+    fn handle_watchlist_input(&mut self, code: KeyCode) {
+        let entry_count = self.watchlist.entries().len();
+        match code {
+            KeyCode::Char('a') | KeyCode::Char('A') => self.watchlist_mode.input = Some(String::new()),
+            KeyCode::Char('d') | KeyCode::Char('D') => {
+                if let Err(e) = self.watchlist.remove(self.watchlist_mode.selected) {
+                    self.show_status(format!("Watchlist konnte nicht gespeichert werden: {}", e), MessageLevel::Error);
+                }
+                self.watchlist_mode.selected = self.watchlist_mode.selected
+                    .min(self.watchlist.entries().len().saturating_sub(1));
+            }
+            KeyCode::Up => self.watchlist_mode.select_previous(),
+            KeyCode::Down => self.watchlist_mode.select_next(entry_count),
+            KeyCode::Enter => {
+                // Ausgewählten Eintrag im passenden Detail-Tab öffnen
+                match self.watchlist.entries().get(self.watchlist_mode.selected).map(|e| e.target.clone()) {
+                    Some(WatchTarget::Transaction(txid)) => {
+                        self.tx_mode = Some(TxMode::new(txid));
+                        self.current_tab = Tab::TxDetails;
+                    }
+                    Some(WatchTarget::Address(address)) => {
+                        self.address_mode = Some(AddressMode { address });
+                        self.current_tab = Tab::AddressDetails;
+                    }
+                    None => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_watchlist_text_input(&mut self, code: KeyCode) {
+        let Some(input) = self.watchlist_mode.input.as_mut() else { return };
+        match code {
+            KeyCode::Char(c) => input.push(c),
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Esc => self.watchlist_mode.input = None,
+            KeyCode::Enter => {
+                let input = self.watchlist_mode.input.take().unwrap_or_default();
+                match WatchTarget::parse(&input) {
+                    Some(target) => self.add_to_watchlist(target),
+                    None => self.show_status("Ungültige TXID oder Adresse".to_string(), MessageLevel::Error),
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_tx_text_input(&mut self, code: KeyCode) {
+        let Some(input) = self.tx_input.as_mut() else { return };
+        match code {
+            KeyCode::Char(c) => input.push(c),
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Esc => self.tx_input = None,
+            KeyCode::Enter => {
+                let input = self.tx_input.take().unwrap_or_default();
+                let Some(client) = &self.rpc_client else { return };
+                let decoded = read_raw_tx(&input)
+                    .and_then(|hex| client.decode_raw_transaction(&hex).map(|tx| (hex, tx)));
+                match decoded {
+                    Ok((hex, tx)) => self.tx_mode = Some(TxMode::from_raw(hex, tx)),
+                    Err(e) => self.show_status(format!("Transaktion konnte nicht dekodiert werden: {}", e), MessageLevel::Error),
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Geladene Roh-Transaktion gegen die Mempool-Policy prüfen
+    fn test_mempool_accept(&mut self) {
+        let Some(client) = &self.rpc_client else { return };
+        let Some(tx_mode) = self.tx_mode.as_mut() else { return };
+        let Some(hex) = tx_mode.raw_hex.clone() else {
+            self.show_status("Nur für geladene Roh-Transaktionen (L)".to_string(), MessageLevel::Info);
+            return;
+        };
+
+        let result = client.test_mempool_accept(&hex).map_err(|e| e.to_string());
+        let message = match &result {
+            Ok(r) if r.allowed => ("Transaktion würde akzeptiert".to_string(), MessageLevel::Info),
+            Ok(r) => (
+                format!("Abgelehnt: {}", r.reject_reason.clone().unwrap_or_default()),
+                MessageLevel::Error,
+            ),
+            Err(e) => (format!("testmempoolaccept fehlgeschlagen: {}", e), MessageLevel::Error),
+        };
+        tx_mode.mempool_accept = Some(result);
+        self.show_status(message.0, message.1);
+    }
+
+    // Vor dem Bestätigungsdialog immer gegen Policy und Feerate-Limit prüfen
+    fn prepare_broadcast(&mut self) {
+        let Some(client) = &self.rpc_client else { return };
+        let Some(tx_mode) = self.tx_mode.as_mut() else { return };
+        let Some(hex) = tx_mode.raw_hex.clone() else {
+            self.show_status("Nur für geladene Roh-Transaktionen (L)".to_string(), MessageLevel::Info);
+            return;
+        };
+
+        let accept = match client.test_mempool_accept(&hex) {
+            Ok(accept) => accept,
+            Err(e) => {
+                self.show_status(format!("testmempoolaccept fehlgeschlagen: {}", e), MessageLevel::Error);
+                return;
+            }
+        };
+        tx_mode.mempool_accept = Some(Ok(accept.clone()));
+
+        let max_feerate = max_broadcast_feerate();
+        let confirm = if !accept.allowed {
+            Err(format!("Senden blockiert: {}", accept.reject_reason.unwrap_or_default()))
+        } else if accept.effective_feerate.is_some_and(|rate| rate > max_feerate) {
+            Err(format!(
+                "Senden blockiert: Feerate {:.2} sat/vB über Limit von {:.2} sat/vB",
+                accept.effective_feerate.unwrap_or_default(),
+                max_feerate
+            ))
+        } else {
+            tx_mode.decoded.as_ref()
+                .ok_or_else(|| "Transaktion wurde nicht dekodiert".to_string())
+                .map(|tx| BroadcastConfirm {
+                    outputs: tx.vout.iter().map(|out| (
+                        out.get("scriptPubKey")
+                            .and_then(|s| s.get("address"))
+                            .and_then(|v| v.as_str())
+                            .unwrap_or("Unbekannte Adresse")
+                            .to_string(),
+                        out.get("value").and_then(|v| v.as_f64()).unwrap_or(0.0),
+                    )).collect(),
+                    fee: accept.fee,
+                    feerate: accept.effective_feerate,
+                    max_feerate,
+                })
+        };
+
+        match confirm {
+            Ok(confirm) => tx_mode.confirm_broadcast = Some(confirm),
+            Err(message) => self.show_status(message, MessageLevel::Error),
+        }
+    }
+
+    fn handle_broadcast_confirm(&mut self, code: KeyCode) {
+        let Some(tx_mode) = self.tx_mode.as_mut() else { return };
+        match code {
+            KeyCode::Char('j') | KeyCode::Char('J') | KeyCode::Char('y') | KeyCode::Char('Y') => {
+                let Some(confirm) = tx_mode.confirm_broadcast.take() else { return };
+                let Some(hex) = tx_mode.raw_hex.clone() else { return };
+                let Some(client) = &self.rpc_client else { return };
+
+                match client.send_raw_transaction(&hex, confirm.max_feerate) {
+                    Ok(txid) => {
+                        self.tx_mode = Some(TxMode::new(txid.clone()));
+                        self.show_status(format!("Transaktion gesendet: {}", txid), MessageLevel::Info);
+                    }
+                    Err(e) => self.show_status(format!("Senden fehlgeschlagen: {}", e), MessageLevel::Error),
+                }
+            }
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                tx_mode.confirm_broadcast = None;
+                self.show_status("Senden abgebrochen".to_string(), MessageLevel::Info);
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_chain_tips_input(&mut self, code: KeyCode) {
+        let Some(client) = &self.rpc_client else { return };
+        let branch = match self.chain_tips_mode.selected_branch() {
+            Some(Ok(blocks)) => blocks.clone(),
+            _ => Vec::new(),
+        };
+
+        match code {
+            KeyCode::Up => self.chain_tips_mode.select_previous(),
+            KeyCode::Down => self.chain_tips_mode.select_next(self.chain_tips_mode.tips.len()),
+            KeyCode::Right => self.chain_tips_mode.deeper(branch.len()),
+            KeyCode::Left => self.chain_tips_mode.shallower(),
+            KeyCode::Enter => {
+                if let Some((_, hash)) = branch.get(self.chain_tips_mode.depth) {
+                    self.block_search_mode = BlockSearchMode::Custom(hash.clone());
+                    self.current_tab = Tab::BlockDetails;
+                }
+            }
+            _ => {}
+        }
+        // Nach einem Auswahlwechsel nur den neuen Zweig nachladen
+        self.chain_tips_mode.load_branch(client);
+    }
+
+    fn handle_psbt_input(&mut self, code: KeyCode) {
+        match code {
+            KeyCode::Char('i') | KeyCode::Char('I') => self.psbt_mode.input = Some(String::new()),
+            KeyCode::Char('f') | KeyCode::Char('F') => self.finalize_psbt(false),
+            KeyCode::Char('x') | KeyCode::Char('X') => self.finalize_psbt(true),
+            KeyCode::Up => self.psbt_mode.scroll_up(),
+            KeyCode::Down => self.psbt_mode.scroll_down(),
+            KeyCode::Esc => self.psbt_mode = PsbtMode::default(),
+            _ => {}
+        }
+    }
+
+    fn handle_export_prompt(&mut self, code: KeyCode) {
+        let format = match code {
+            KeyCode::Char('j') | KeyCode::Char('J') => ExportFormat::Json,
+            KeyCode::Char('c') | KeyCode::Char('C') => ExportFormat::Csv,
+            KeyCode::Esc => {
+                self.export_prompt = false;
+                return;
+            }
+            _ => return,
+        };
+        self.export_prompt = false;
+
+        match self.export_current_tab(format) {
+            Ok(path) => self.show_status(format!("Exportiert nach {}", path.display()), MessageLevel::Info),
+            Err(e) => self.show_status(format!("Export fehlgeschlagen: {}", e), MessageLevel::Error),
+        }
+    }
+
+    // Daten frisch abrufen, damit der Export dem angezeigten Stand entspricht
+    fn export_current_tab(&self, format: ExportFormat) -> anyhow::Result<std::path::PathBuf> {
+        let client = self.rpc_client.as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Keine Verbindung zum Bitcoin Node"))?;
+        let node_info = self.node_info.as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Noch keine Node-Daten geladen"))?;
+        let dir = export::export_dir();
+
+        match self.current_tab {
+            Tab::Dashboard => export::export(node_info, "status", format, &dir),
+            Tab::BlockDetails => {
+                let block = match &self.block_search_mode {
+                    BlockSearchMode::Latest => client.get_block_details(&node_info.height.to_string())?,
+                    BlockSearchMode::Custom(input) => client.get_block_details(input)?,
+                };
+                export::export(&block, "block", format, &dir)
+            }
+            Tab::TxDetails => {
+                let mode = self.tx_mode.as_ref()
+                    .ok_or_else(|| anyhow::anyhow!("Keine Transaktion ausgewählt"))?;
+                let tx = match &mode.decoded {
+                    Some(tx) => tx.clone(),
+                    None => client.get_raw_transaction(&mode.txid)?,
+                };
+                export::export(&tx, "tx", format, &dir)
+            }
+            Tab::AddressDetails => {
+                let mode = self.address_mode.as_ref()
+                    .ok_or_else(|| anyhow::anyhow!("Keine Adresse ausgewählt"))?;
+                export::export(&client.get_address_details(&mode.address)?, "address", format, &dir)
+            }
+            Tab::Network | Tab::PeerList => export::export(&client.get_peer_info()?, "peers", format, &dir),
+            Tab::Mempool => export::export(&client.get_mempool_stats()?, "mempool", format, &dir),
+            Tab::Traffic => export::export(&client.get_net_totals()?, "traffic", format, &dir),
+            Tab::AddrMan => export::export(&client.get_node_addresses(0, None)?, "addrman", format, &dir),
+            _ => Err(anyhow::anyhow!("Export ist für diesen Tab nicht verfügbar")),
+        }
+    }
+
+    fn handle_psbt_text_input(&mut self, code: KeyCode) {
+        let Some(input) = self.psbt_mode.input.as_mut() else { return };
+        match code {
+            KeyCode::Char(c) => input.push(c),
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Esc => self.psbt_mode.input = None,
+            KeyCode::Enter => {
+                let input = self.psbt_mode.input.take().unwrap_or_default();
+                let Some(client) = &self.rpc_client else { return };
+                if let Err(e) = self.psbt_mode.load(client, &input) {
+                    self.show_status(format!("PSBT konnte nicht geladen werden: {}", e), MessageLevel::Error);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn finalize_psbt(&mut self, extract: bool) {
+        let Some(client) = &self.rpc_client else { return };
+        match self.psbt_mode.finalize(client, extract) {
+            Ok(true) => self.show_status("PSBT vollständig finalisiert".to_string(), MessageLevel::Info),
+            Ok(false) => self.show_status("PSBT noch nicht vollständig signiert".to_string(), MessageLevel::Error),
+            Err(e) => self.show_status(format!("Finalisierung fehlgeschlagen: {}", e), MessageLevel::Error),
+        }
+    }
+
+    fn handle_wallet_input(&mut self, code: KeyCode) {
+        let Some(client) = &self.rpc_client else { return };
+        let wallets = client.list_wallets().unwrap_or_default();
+
+        match code {
+            KeyCode::Right => self.wallet_mode.next_wallet(wallets.len()),
+            KeyCode::Left => self.wallet_mode.previous_wallet(wallets.len()),
+            KeyCode::Up => self.wallet_mode.scroll_up(),
+            KeyCode::Down => {
+                let tx_count = wallets.get(self.wallet_mode.selected)
+                    .and_then(|wallet| client.get_wallet_overview(wallet).ok())
+                    .map(|overview| overview.transactions.len())
+                    .unwrap_or(0);
+                self.wallet_mode.scroll_down(tx_count);
+            }
             _ => {}
         }
     }
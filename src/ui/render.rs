@@ -1,25 +1,49 @@
 use super::common::*;
 use super::{components, help};
 use std::time::Duration;
-use crate::rpc::{BitcoinRPC, MempoolInfo, NodeStatus};
+use crate::rpc::{AddrManOverview, BitcoinRPC, BlockTemplate, MempoolInfo, MiningStats, NodeStatus, mempool_history};
 use crate::ui::{StatusMessage, MessageLevel};
 use crate::ui::tabs::block_details::BlockSearchMode;
 use ratatui::prelude::Alignment;
-use ratatui::widgets::{Paragraph, Table};
+use ratatui::widgets::{Paragraph, Sparkline, Table, TableState};
 use super::tabs::{
     render_node_info, render_block_details, render_mempool,
     render_network, render_peer_list, render_mining,
-    render_security, render_tx_details, render_address_details
+    render_security, render_tx_details, render_address_details,
+    render_wallet, render_watchlist, render_psbt, render_chain_tips,
+    render_block_template, render_traffic, render_addrman,
 };
 use crate::ui::tabs::security::{SecurityStatus, RPCStatus};
-use crate::ui::tabs::tx_details::TxMode;
+use crate::ui::tabs::tx_details::{TxMode, render_broadcast_dialog};
 use crate::ui::tabs::address_details::AddressMode;
+use crate::ui::tabs::wallet::WalletMode;
+use crate::ui::tabs::watchlist::WatchlistMode;
+use crate::ui::tabs::psbt::PsbtMode;
+use crate::ui::tabs::chain_tips::ChainTipsMode;
+use crate::ui::tabs::peer_list::{PeerListMode, PEER_HEADER_HEIGHT};
+use crate::ui::tabs::dashboard::SyncTracker;
+use crate::ui::tabs::traffic::TrafficMonitor;
+use crate::ui::tabs::mining::TEMPLATE_HEADER_HEIGHT;
+use crate::ui::tabs::addrman::ADDRMAN_HEADER_HEIGHT;
+use crate::watchlist::Watchlist;
+use crate::alerts::{Alert, collect_alerts};
+use crate::history::HistoryView;
+use crate::pools::PoolShare;
 
+#[allow(clippy::large_enum_variant)]
 enum ContentWidget<'a> {
     Text(Paragraph<'a>),
-    Table(Table<'a>),
+    // Kopfbereich mit fester Höhe und Tabelle darunter
+    Split(Paragraph<'a>, u16, Table<'a>, TableState),
+    // Text mit Fortschrittsbalken darunter
+    Gauge(Paragraph<'a>, ratatui::widgets::Gauge<'a>),
+    // Text links, gestapelte Sparklines rechts
+    Charts(Paragraph<'a>, Vec<Chart>),
+    // Text links, rechts Kopfbereich mit fester Höhe und Tabelle darunter
+    Columns(Paragraph<'a>, Paragraph<'a>, u16, Table<'a>),
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn draw_ui(
     f: &mut Frame,
     tab: &Tab,
@@ -32,7 +56,7 @@ pub fn draw_ui(
     verification_progress: f64,
     mempool_size: u64,
     network: &str,
-    update_interval: Duration,
+    since_update: Duration,
     is_updating: bool,
     spinner_state: usize,
     _mempool_info: &MempoolInfo,
@@ -42,7 +66,25 @@ pub fn draw_ui(
     block_search_mode: &BlockSearchMode,
     block_input_active: bool,
     tx_mode: &Option<TxMode>,
+    tx_input: &Option<String>,
     address_mode: &Option<AddressMode>,
+    wallet_mode: &WalletMode,
+    watchlist: &Watchlist,
+    watchlist_mode: &WatchlistMode,
+    psbt_mode: &PsbtMode,
+    chain_tips_mode: &ChainTipsMode,
+    peer_list_mode: &PeerListMode,
+    sync_tracker: &SyncTracker,
+    zmq_active: bool,
+    live_tx_rate: Option<f64>,
+    export_prompt: bool,
+    rule_alerts: &[Alert],
+    history: &HistoryView,
+    mining_stats: Option<&MiningStats>,
+    block_template: Option<&Result<BlockTemplate, String>>,
+    pool_shares: Option<&(u64, Vec<PoolShare>)>,
+    traffic: &TrafficMonitor,
+    addrman: Option<&AddrManOverview>,
 ) {
     if !show_help {
         let chunks = Layout::default()
@@ -60,20 +102,30 @@ pub fn draw_ui(
         let header = components::create_header(version);
         let tabs = components::create_tabs(tab);
         let content = match tab {
-            Tab::Dashboard => ContentWidget::Text(render_node_info(
-                &network,
-                connections,
-                height,
-                height,
-                node_info.difficulty,
-                node_info.chain_work.clone(),
-                verification_progress,
-                node_info.initial_block_download,
-                node_info.size_on_disk,
-                node_info.pruned,
-                mempool_size,
-                "",
-            )),
+            Tab::Dashboard => {
+                // Ausgelöste Regeln stehen zusammen mit den festen Prüfungen im Dashboard
+                let mut alerts = collect_alerts(node_info, Utc::now().timestamp());
+                alerts.extend(rule_alerts.iter().cloned());
+                alerts.sort_by_key(|alert| alert.severity);
+                let (overview, gauge) = render_node_info(
+                    network,
+                    connections,
+                    height,
+                    node_info.headers,
+                    node_info.difficulty,
+                    node_info.chain_work.clone(),
+                    verification_progress,
+                    node_info.initial_block_download,
+                    node_info.size_on_disk,
+                    node_info.pruned,
+                    mempool_size,
+                    live_tx_rate,
+                    &alerts,
+                    sync_tracker,
+                    history,
+                );
+                ContentWidget::Gauge(overview, gauge)
+            },
             Tab::BlockDetails => {
                 match rpc_client {
                     Some(client) => {
@@ -89,48 +141,67 @@ pub fn draw_ui(
                                 block_input_active,
                             )),
                             Err(_) => ContentWidget::Text(Paragraph::new("Block konnte nicht gefunden werden")
-                                .style(Style::default().fg(Color::Red)))
+                                .style(Style::default().fg(theme().error)))
                         }
                     },
                     None => ContentWidget::Text(Paragraph::new("Keine Verbindung zum Bitcoin Node")
-                        .style(Style::default().fg(Color::Red)))
+                        .style(Style::default().fg(theme().error)))
                 }
             },
             Tab::Mempool => {
                 match rpc_client {
                     Some(client) => {
                         match client.get_mempool_stats() {
-                            Ok(stats) => ContentWidget::Text(render_mempool(&stats, is_updating)),
+                            Ok(stats) => {
+                                let (summary, charts) = render_mempool(&stats, &mempool_history(), is_updating);
+                                ContentWidget::Charts(summary, charts)
+                            },
                             Err(_) => ContentWidget::Text(Paragraph::new("Mempool-Daten konnten nicht geladen werden")
-                                .style(Style::default().fg(Color::Red)))
+                                .style(Style::default().fg(theme().error)))
                         }
                     },
                     None => ContentWidget::Text(Paragraph::new("Keine Verbindung zum Bitcoin Node")
-                        .style(Style::default().fg(Color::Red)))
+                        .style(Style::default().fg(theme().error)))
                 }
             },
-            Tab::Network => ContentWidget::Text(render_network(
-                connections,
-                network.to_string(),
-                verification_progress,
-                &node_info.peers,
-            )),
-            Tab::PeerList => ContentWidget::Table(render_peer_list(&node_info.peers)),
-            Tab::Mining => ContentWidget::Text(render_mining(
-                node_info.difficulty,
-                (2016 - (node_info.height % 2016)) as i64,
-                node_info.difficulty * 2.0_f64.powf(32.0) / 600.0,
-                node_info.difficulty * (1.0 + (node_info.height % 2016) as f64 / 2016.0),
-            )),
+            Tab::Network => {
+                let (summary, charts) = render_network(
+                    connections,
+                    network.to_string(),
+                    verification_progress,
+                    &node_info.peers,
+                    node_info.version,
+                    history,
+                );
+                ContentWidget::Charts(summary, charts)
+            },
+            Tab::PeerList => {
+                let (details, peers, state) = render_peer_list(&node_info.peers, peer_list_mode, node_info.version);
+                ContentWidget::Split(details, PEER_HEADER_HEIGHT, peers, state)
+            },
+            Tab::Mining => {
+                let overview = match mining_stats {
+                    Some(stats) => render_mining(stats, pool_shares),
+                    None => Paragraph::new("Mining-Daten werden geladen...")
+                        .style(Style::default().fg(theme().warn)),
+                };
+                let (summary, transactions) = render_block_template(block_template);
+                ContentWidget::Columns(overview, summary, TEMPLATE_HEADER_HEIGHT, transactions)
+            },
             Tab::Security => {
+                let peers_in = |networks: &[&str]| node_info.peers.iter()
+                    .filter(|p| networks.contains(&p.network.as_str()))
+                    .count() as u64;
                 let security_status = SecurityStatus {
                     version: node_info.version,
                     latest_version: 250000,
                     peers_total: node_info.peers.len() as u64,
-                    peers_onion: node_info.peers.iter().filter(|p| p.addr.contains(".onion")).count() as u64,
-                    peers_clearnet: node_info.peers.iter().filter(|p| !p.addr.contains(".onion")).count() as u64,
+                    peers_onion: peers_in(&["onion"]),
+                    peers_i2p: peers_in(&["i2p"]),
+                    peers_cjdns: peers_in(&["cjdns"]),
+                    peers_clearnet: peers_in(&["ipv4", "ipv6"]),
                     firewall_active: true,
-                    tor_active: node_info.peers.iter().any(|p| p.addr.contains(".onion")),
+                    tor_active: node_info.peers.iter().any(|p| p.network == "onion"),
                     rpc_restricted: true,
                     wallet_encrypted: true,
                     disk_encryption: true,
@@ -144,25 +215,111 @@ pub fn draw_ui(
                 };
                 ContentWidget::Text(render_security(&security_status))
             },
-            Tab::TxDetails => ContentWidget::Text(render_tx_details(tx_mode.as_ref(), rpc_client)),
+            Tab::TxDetails => ContentWidget::Text(render_tx_details(tx_mode.as_ref(), tx_input.as_deref(), rpc_client)),
             Tab::AddressDetails => ContentWidget::Text(render_address_details(address_mode.as_ref(), rpc_client)),
+            Tab::Wallet => {
+                let (header, transactions) = render_wallet(wallet_mode, rpc_client);
+                ContentWidget::Split(header, 8, transactions, TableState::default())
+            },
+            Tab::Watchlist => {
+                let (header, entries) = render_watchlist(watchlist, watchlist_mode);
+                ContentWidget::Split(header, 5, entries, TableState::default())
+            },
+            Tab::Psbt => ContentWidget::Text(render_psbt(psbt_mode)),
+            Tab::Traffic => {
+                let (summary, charts) = render_traffic(traffic);
+                ContentWidget::Charts(summary, charts)
+            },
+            Tab::AddrMan => {
+                let (samples, summary, networks) = render_addrman(addrman, &node_info.peers);
+                ContentWidget::Columns(samples, summary, ADDRMAN_HEADER_HEIGHT, networks)
+            },
+            Tab::ChainTips => {
+                let (header, tips) = render_chain_tips(chain_tips_mode, rpc_client);
+                ContentWidget::Split(header, 5, tips, TableState::default())
+            },
         };
-        let footer = components::create_footer(update_interval, is_updating, spinner_state);
+        let footer = components::create_footer(since_update, is_updating, spinner_state, zmq_active);
 
         f.render_widget(header, chunks[0]);
         f.render_widget(tabs, chunks[1]);
         match content {
             ContentWidget::Text(widget) => f.render_widget(widget, chunks[2]),
-            ContentWidget::Table(widget) => f.render_widget(widget, chunks[2]),
+            ContentWidget::Split(header, header_height, table, mut state) => {
+                let areas = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([Constraint::Length(header_height), Constraint::Min(0)])
+                    .split(chunks[2]);
+                f.render_widget(header, areas[0]);
+                f.render_stateful_widget(table, areas[1], &mut state);
+            },
+            ContentWidget::Columns(text, header, header_height, table) => {
+                let columns = Layout::default()
+                    .direction(Direction::Horizontal)
+                    .constraints([Constraint::Percentage(45), Constraint::Percentage(55)])
+                    .split(chunks[2]);
+                f.render_widget(text, columns[0]);
+
+                let areas = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([Constraint::Length(header_height), Constraint::Min(0)])
+                    .split(columns[1]);
+                f.render_widget(header, areas[0]);
+                f.render_widget(table, areas[1]);
+            },
+            ContentWidget::Gauge(text, gauge) => {
+                let areas = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([Constraint::Min(0), Constraint::Length(3)])
+                    .split(chunks[2]);
+                f.render_widget(text, areas[0]);
+                f.render_widget(gauge, areas[1]);
+            },
+            ContentWidget::Charts(summary, charts) => {
+                let areas = Layout::default()
+                    .direction(Direction::Horizontal)
+                    .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+                    .split(chunks[2]);
+                f.render_widget(summary, areas[0]);
+
+                let rows = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints(vec![Constraint::Ratio(1, charts.len().max(1) as u32); charts.len()])
+                    .split(areas[1]);
+                for (chart, area) in charts.iter().zip(rows.iter()) {
+                    // Ganze Reihe auf die Breite verdichten, damit der Zeitraum im Titel stimmt
+                    let data = downsample(&chart.data, area.width.saturating_sub(2) as usize);
+                    let sparkline = Sparkline::default()
+                        .block(Block::default().borders(Borders::ALL).title(chart.title.clone()))
+                        .data(&data)
+                        .style(Style::default().fg(chart.color));
+                    f.render_widget(sparkline, *area);
+                }
+            },
         }
         f.render_widget(footer, chunks[3]);
 
+        // Bestätigungsdialog vor dem Senden einer Roh-Transaktion
+        if *tab == Tab::TxDetails {
+            if let Some(confirm) = tx_mode.as_ref().and_then(|m| m.confirm_broadcast.as_ref()) {
+                let area = centered_rect(60, 50, chunks[2]);
+                f.render_widget(Clear, area);
+                f.render_widget(render_broadcast_dialog(confirm), area);
+            }
+        }
+
+        if export_prompt {
+            let area = centered_rect(40, 30, chunks[2]);
+            f.render_widget(Clear, area);
+            f.render_widget(components::create_export_dialog(), area);
+        }
+
         // Status-Nachrichten anzeigen
         if !status_messages.is_empty() {
             let message = &status_messages[0];
             let style = match message.level {
-                MessageLevel::Info => Style::default().fg(Color::Blue),
-                MessageLevel::Error => Style::default().fg(Color::Red),
+                MessageLevel::Info => Style::default().fg(theme().accent),
+                MessageLevel::Error => Style::default().fg(theme().error),
             };
             
             let status = Paragraph::new(message.text.clone())
@@ -178,7 +335,7 @@ pub fn draw_ui(
         // Dann den gesamten Bildschirm schwarz färben
         f.render_widget(
             Block::default()
-                .style(Style::default().bg(Color::Black))
+                .style(Style::default().bg(theme().background))
                 .borders(Borders::NONE),
             f.size()
         );
@@ -189,7 +346,7 @@ pub fn draw_ui(
         // Nochmal explizit den Hilfe-Bereich schwarz färben
         f.render_widget(
             Block::default()
-                .style(Style::default().bg(Color::Black))
+                .style(Style::default().bg(theme().background))
                 .borders(Borders::NONE),
             area
         );
@@ -1,5 +1,6 @@
 use crate::ui::common::*;
-use crate::rpc::BitcoinRPCInterface;
+use crate::rpc::{BitcoinRPCInterface, Portfolio, PortfolioState, is_descriptor_input};
+use crate::watchlist::short_id;
 
 #[derive(Clone)]
 pub struct AddressMode {
@@ -7,13 +8,20 @@ pub struct AddressMode {
 }
 
 pub fn render<T: BitcoinRPCInterface>(mode: Option<&AddressMode>, rpc_client: &Option<T>) -> Paragraph<'static> {
+    // Descriptoren und xpubs werden als Portfolio über alle abgeleiteten Adressen angezeigt
+    if let (Some(addr_mode), Some(client)) = (mode, rpc_client) {
+        if is_descriptor_input(&addr_mode.address) {
+            return render_portfolio(client.get_portfolio(&addr_mode.address));
+        }
+    }
+
     match (mode, rpc_client) {
         (Some(addr_mode), Some(client)) => {
             match client.get_address_details(&addr_mode.address) {
                 Ok(details) => {
                     let lines = vec![
                         Line::from(vec![
-                            Span::styled("📊 Adress-Details", Style::default().fg(Color::Yellow))
+                            Span::styled("📊 Adress-Details", Style::default().fg(theme().title))
                         ]),
                         Line::from(""),
                         Line::from(format!("Adresse: {}", addr_mode.address)),
@@ -22,64 +30,64 @@ pub fn render<T: BitcoinRPCInterface>(mode: Option<&AddressMode>, rpc_client: &O
                             Span::raw("Transaktionen: "),
                             Span::styled(
                                 format!("{}", details.tx_count),
-                                Style::default().fg(Color::Green)
+                                Style::default().fg(theme().ok)
                             )
                         ]),
                         Line::from(vec![
                             Span::raw("Empfangen: "),
                             Span::styled(
                                 format!("{:.8} BTC", details.received),
-                                Style::default().fg(Color::Green)
+                                Style::default().fg(theme().ok)
                             )
                         ]),
                         Line::from(vec![
                             Span::raw("Gesendet: "),
                             Span::styled(
                                 format!("{:.8} BTC", details.sent),
-                                Style::default().fg(Color::Red)
+                                Style::default().fg(theme().error)
                             )
                         ]),
                         Line::from(vec![
                             Span::raw("Aktuelles Guthaben: "),
                             Span::styled(
                                 format!("{:.8} BTC", details.balance),
-                                Style::default().fg(if details.balance > 0.0 { Color::Green } else { Color::Red })
+                                Style::default().fg(if details.balance > 0.0 { theme().ok } else { theme().error })
                             )
                         ]),
                         Line::from(""),
                         Line::from(vec![
-                            Span::styled("📊 UTXO Statistiken", Style::default().fg(Color::Cyan))
+                            Span::styled("📊 UTXO Statistiken", Style::default().fg(theme().label))
                         ]),
                         Line::from(vec![
                             Span::raw("Empfangene Outputs: "),
                             Span::styled(
                                 format!("{}", details.funded_txo_count),
-                                Style::default().fg(Color::Green)
+                                Style::default().fg(theme().ok)
                             )
                         ]),
                         Line::from(vec![
                             Span::raw("Ausgegebene Outputs: "),
                             Span::styled(
                                 format!("{}", details.spent_txo_count),
-                                Style::default().fg(Color::Yellow)
+                                Style::default().fg(theme().warn)
                             )
                         ]),
                         Line::from(vec![
                             Span::raw("Unausgegebene Outputs: "),
                             Span::styled(
                                 format!("{}", details.unspent_txo_count),
-                                Style::default().fg(Color::Green)
+                                Style::default().fg(theme().ok)
                             )
                         ]),
                         Line::from(""),
                         Line::from(vec![
-                            Span::styled("🔍 Technische Details", Style::default().fg(Color::Cyan))
+                            Span::styled("🔍 Technische Details", Style::default().fg(theme().label))
                         ]),
                         Line::from(vec![
                             Span::raw("Adresstyp: "),
                             Span::styled(
                                 details.address_type.clone(),
-                                Style::default().fg(Color::Yellow)
+                                Style::default().fg(theme().title)
                             )
                         ]),
                         Line::from(vec![
@@ -87,12 +95,12 @@ pub fn render<T: BitcoinRPCInterface>(mode: Option<&AddressMode>, rpc_client: &O
                             if details.has_mempool_tx {
                                 Span::styled(
                                     "Unbestätigte Transaktionen vorhanden",
-                                    Style::default().fg(Color::Yellow)
+                                    Style::default().fg(theme().warn)
                                 )
                             } else {
                                 Span::styled(
                                     "Keine unbestätigten Transaktionen",
-                                    Style::default().fg(Color::Green)
+                                    Style::default().fg(theme().ok)
                                 )
                             }
                         ]),
@@ -100,7 +108,7 @@ pub fn render<T: BitcoinRPCInterface>(mode: Option<&AddressMode>, rpc_client: &O
                     Paragraph::new(lines)
                 },
                 Err(e) => Paragraph::new(format!("Fehler beim Laden der Adressdetails: {}", e))
-                    .style(Style::default().fg(Color::Red))
+                    .style(Style::default().fg(theme().error))
             }
         },
         _ => Paragraph::new("Keine Adresse ausgewählt")
@@ -110,16 +118,133 @@ pub fn render<T: BitcoinRPCInterface>(mode: Option<&AddressMode>, rpc_client: &O
         .title(" Address Details "))
 }
 
+fn render_portfolio(state: anyhow::Result<PortfolioState>) -> Paragraph<'static> {
+    let paragraph = match state {
+        Ok(PortfolioState::Ready(portfolio)) => Paragraph::new(portfolio_lines(&portfolio)),
+        Ok(PortfolioState::Loading) => Paragraph::new(vec![
+            Line::from(vec![
+                Span::styled("📂 Descriptor Portfolio", Style::default().fg(theme().title))
+            ]),
+            Line::from(""),
+            Line::from(Span::styled(
+                "Scan läuft... (scantxoutset kann einige Minuten dauern)",
+                Style::default().fg(theme().warn)
+            )),
+        ]),
+        Ok(PortfolioState::Failed(e)) => Paragraph::new(format!("Fehler beim Laden des Portfolios: {}", e))
+            .style(Style::default().fg(theme().error)),
+        Err(e) => Paragraph::new(format!("Fehler beim Laden des Portfolios: {}", e))
+            .style(Style::default().fg(theme().error)),
+    };
+
+    paragraph.block(Block::default()
+        .borders(Borders::ALL)
+        .title(" Address Details - Portfolio "))
+}
+
+fn portfolio_lines(portfolio: &Portfolio) -> Vec<Line<'static>> {
+    const MAX_UTXOS: usize = 15;
+
+    let used: Vec<_> = portfolio.addresses.iter().filter(|a| a.utxo_count > 0).collect();
+    // Ohne Verlauf lässt sich nur "ohne Guthaben" prüfen, nicht "nie benutzt"
+    let next_receive = portfolio.addresses.iter()
+        .find(|a| !a.is_change && !a.used && a.utxo_count == 0);
+    let next_receive_label = if portfolio.history_known {
+        "Nächste unbenutzte Empfangsadresse: "
+    } else {
+        "Nächste Empfangsadresse ohne Guthaben (Verlauf unbekannt): "
+    };
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("📂 Descriptor Portfolio", Style::default().fg(theme().title))
+        ]),
+        Line::from(""),
+        Line::from(format!("Descriptor: {}", portfolio.descriptor)),
+        Line::from(vec![
+            Span::raw("Gap-Limit: "),
+            Span::styled(format!("{}", portfolio.gap_limit), Style::default().fg(theme().value)),
+            Span::raw(" | Abgeleitet: "),
+            Span::styled(format!("{} pro Pfad", portfolio.derived), Style::default().fg(theme().value)),
+            Span::raw(" | Quelle: "),
+            Span::styled(portfolio.source.clone(), Style::default().fg(theme().value)),
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::raw("Gesamtguthaben: "),
+            Span::styled(
+                format!("{:.8} BTC", portfolio.total),
+                Style::default().fg(if portfolio.total > 0.0 { theme().ok } else { theme().error })
+            ),
+            Span::raw(format!(" in {} UTXOs", portfolio.utxos.len())),
+        ]),
+        Line::from(vec![
+            Span::raw("Adressen mit Guthaben: "),
+            Span::styled(
+                format!("{} von {}", used.len(), portfolio.addresses.len()),
+                Style::default().fg(theme().ok)
+            ),
+        ]),
+        Line::from(vec![
+            Span::raw(next_receive_label),
+            Span::styled(
+                next_receive
+                    .map(|a| format!("#{} {}", a.index, a.address))
+                    .unwrap_or_else(|| "Gap-Limit erreicht".to_string()),
+                Style::default().fg(theme().warn)
+            ),
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("📬 Adressen mit Guthaben", Style::default().fg(theme().label))
+        ]),
+    ];
+
+    if used.is_empty() {
+        lines.push(Line::from(" • Keine"));
+    }
+    for address in &used {
+        lines.push(Line::from(vec![
+            Span::raw(format!(" • {} #{:<3} ", if address.is_change { "Wechsel " } else { "Empfang " }, address.index)),
+            Span::raw(format!("{} ", address.address)),
+            Span::styled(format!("{:.8} BTC", address.balance), Style::default().fg(theme().ok)),
+        ]));
+    }
+
+    let mut utxos = portfolio.utxos.clone();
+    utxos.sort_by(|a, b| b.amount.partial_cmp(&a.amount).unwrap_or(std::cmp::Ordering::Equal));
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("🪙 UTXOs (größte zuerst)", Style::default().fg(theme().label))
+    ]));
+    for utxo in utxos.iter().take(MAX_UTXOS) {
+        lines.push(Line::from(vec![
+            Span::styled(format!(" • {:.8} BTC ", utxo.amount), Style::default().fg(theme().ok)),
+            Span::raw(format!("{}:{} ", short_id(&utxo.txid), utxo.vout)),
+            Span::styled(
+                utxo.height.map(|h| format!("Block {}", h)).unwrap_or_else(|| "unbestätigt".to_string()),
+                Style::default().fg(theme().muted)
+            ),
+        ]));
+    }
+    if utxos.len() > MAX_UTXOS {
+        lines.push(Line::from(format!("   ... und {} weitere", utxos.len() - MAX_UTXOS)));
+    }
+
+    lines
+}
+
 /// Gibt die Hilfe-Dokumentation für die Adressdetails zurück
 #[allow(dead_code)]
 pub fn render_help() -> Paragraph<'static> {
     Paragraph::new(vec![
         Line::from(vec![
-            Span::styled("📚 Adressdetails Hilfe", Style::default().fg(Color::Yellow))
+            Span::styled("📚 Adressdetails Hilfe", Style::default().fg(theme().title))
         ]),
         Line::from(""),
         Line::from(vec![
-            Span::styled("💰 Transaktionsübersicht", Style::default().fg(Color::Cyan))
+            Span::styled("💰 Transaktionsübersicht", Style::default().fg(theme().label))
         ]),
         Line::from(" • Transaktionen: Gesamtzahl aller Ein- und Ausgänge"),
         Line::from(" • Empfangen: Summe aller empfangenen Satoshis (1 BTC = 100.000.000 Satoshis)"),
@@ -127,7 +252,7 @@ pub fn render_help() -> Paragraph<'static> {
         Line::from(" • Guthaben: Aktuell verfügbare Satoshis (Empfangen - Gesendet)"),
         Line::from(""),
         Line::from(vec![
-            Span::styled("📊 UTXO Details", Style::default().fg(Color::Cyan))
+            Span::styled("📊 UTXO Details", Style::default().fg(theme().label))
         ]),
         Line::from(" • Empfangene Outputs (funded_txo_count):"),
         Line::from("   - Anzahl aller jemals empfangenen Zahlungen"),
@@ -142,14 +267,14 @@ pub fn render_help() -> Paragraph<'static> {
         Line::from("   - Im Beispiel: 1 verfügbarer Output"),
         Line::from(""),
         Line::from(vec![
-            Span::styled("🔄 Mempool Status", Style::default().fg(Color::Cyan))
+            Span::styled("🔄 Mempool Status", Style::default().fg(theme().label))
         ]),
         Line::from(" • Zeigt unbestätigte Transaktionen im Mempool"),
         Line::from(" • funded_txo_sum: Neue eingehende Satoshis"),
         Line::from(" • spent_txo_sum: Neue ausgehende Satoshis"),
         Line::from(""),
         Line::from(vec![
-            Span::styled("🎨 Farbkodierung", Style::default().fg(Color::Cyan))
+            Span::styled("🎨 Farbkodierung", Style::default().fg(theme().label))
         ]),
         Line::from(" • Grün: Verfügbare/Positive Werte (Empfangen, Guthaben)"),
         Line::from(" • Rot: Ausgegebene/Negative Werte (Gesendet)"),
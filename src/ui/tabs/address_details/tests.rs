@@ -0,0 +1,86 @@
+use super::*;
+use crate::rpc::{AddressDetails, PortfolioState};
+use mockall::mock;
+use ratatui::{backend::TestBackend, Terminal};
+
+mock! {
+    pub Rpc {}
+
+    impl Clone for Rpc {
+        fn clone(&self) -> Self;
+    }
+
+    impl BitcoinRPCInterface for Rpc {
+        fn get_address_details(&self, address: &str) -> anyhow::Result<AddressDetails>;
+        fn get_portfolio(&self, input: &str) -> anyhow::Result<PortfolioState>;
+    }
+}
+
+fn sample_details() -> AddressDetails {
+    AddressDetails {
+        tx_count: 2,
+        received: 1.451245,
+        sent: 1.451245,
+        balance: 0.0,
+        funded_txo_count: 1,
+        spent_txo_count: 1,
+        unspent_txo_count: 0,
+        has_mempool_tx: false,
+        address_type: "Taproot (P2TR)".to_string(),
+    }
+}
+
+fn render_to_string(paragraph: Paragraph<'static>) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+    terminal.draw(|f| f.render_widget(paragraph, f.size())).unwrap();
+    terminal.backend().buffer().content().iter()
+        .map(|cell| cell.symbol.as_str())
+        .collect()
+}
+
+#[test]
+fn render_shows_address_details() {
+    let mut rpc = MockRpc::new();
+    rpc.expect_get_address_details()
+        .withf(|addr| addr == "bc1ptest")
+        .returning(|_| Ok(sample_details()));
+
+    let mode = AddressMode { address: "bc1ptest".to_string() };
+    let output = render_to_string(render(Some(&mode), &Some(rpc)));
+
+    assert!(output.contains("Adresse: bc1ptest"));
+    assert!(output.contains("Taproot (P2TR)"));
+    assert!(output.contains("Keine unbestätigten Transaktionen"));
+}
+
+#[test]
+fn render_shows_error_message() {
+    let mut rpc = MockRpc::new();
+    rpc.expect_get_address_details()
+        .returning(|_| Err(anyhow::anyhow!("API Fehler: 404")));
+
+    let mode = AddressMode { address: "bc1ptest".to_string() };
+    let output = render_to_string(render(Some(&mode), &Some(rpc)));
+
+    assert!(output.contains("Fehler beim Laden der Adressdetails"));
+}
+
+#[test]
+fn render_without_address() {
+    let output = render_to_string(render::<MockRpc>(None, &None));
+    assert!(output.contains("Keine Adresse ausgewählt"));
+}
+
+#[test]
+fn render_descriptor_while_scanning() {
+    let mut rpc = MockRpc::new();
+    rpc.expect_get_portfolio()
+        .returning(|_| Ok(PortfolioState::Loading));
+    rpc.expect_get_address_details().never();
+
+    let mode = AddressMode { address: "wpkh(xpubABC/0/*)".to_string() };
+    let output = render_to_string(render(Some(&mode), &Some(rpc)));
+
+    assert!(output.contains("Portfolio"));
+    assert!(output.contains("Scan läuft"));
+}
@@ -0,0 +1,144 @@
+use crate::ui::common::*;
+use crate::rpc::{ADDRMAN_NETWORKS, AddrManOverview, PeerInfo};
+use num_format::{Locale, ToFormattedString};
+use ratatui::widgets::{Table, Row};
+use chrono::{DateTime, Utc};
+
+// Rahmen plus Summenzeilen über der Netzwerk-Tabelle
+pub const ADDRMAN_HEADER_HEIGHT: u16 = 7;
+
+/// Stichprobe bekannter Adressen je Netzwerk (links)
+fn render_samples(overview: &AddrManOverview) -> Paragraph<'static> {
+    let mut content = vec![
+        Line::from(vec![
+            Span::styled("🔎 Stichprobe (getnodeaddresses)",
+                Style::default().fg(theme().title).add_modifier(Modifier::BOLD))
+        ]),
+        Line::from(Span::styled(
+            "Zufällige Auswahl, neu bei jeder Aktualisierung",
+            Style::default().fg(theme().muted)
+        )),
+    ];
+
+    for network in ADDRMAN_NETWORKS {
+        let addresses: Vec<_> = overview.samples.iter().filter(|a| a.network == network).collect();
+        if addresses.is_empty() {
+            continue;
+        }
+        content.push(Line::from(""));
+        content.push(Line::from(Span::styled(network.to_string(), Style::default().fg(theme().label))));
+        content.extend(addresses.into_iter().map(|address| {
+            let seen = DateTime::<Utc>::from_timestamp(address.time, 0)
+                .map(|time| time.format("%d.%m. %H:%M").to_string())
+                .unwrap_or_default();
+            Line::from(vec![
+                Span::styled(format!("{}:{}", address.address, address.port), Style::default().fg(theme().value)),
+                Span::styled(format!(" {}", seen), Style::default().fg(theme().muted)),
+            ])
+        }));
+    }
+
+    if overview.samples.is_empty() {
+        content.push(Line::from(""));
+        content.push(Line::from(Span::styled(
+            "Keine Adressen bekannt",
+            Style::default().fg(theme().muted)
+        )));
+    }
+
+    Paragraph::new(content)
+        .block(Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme().value))
+            .title(" Bekannte Adressen "))
+}
+
+pub fn render(overview: Option<&AddrManOverview>, peers: &[PeerInfo]) -> (Paragraph<'static>, Paragraph<'static>, Table<'static>) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme().value))
+        .title(" Address-Manager ");
+
+    let Some(overview) = overview else {
+        let loading = Paragraph::new("Address-Manager wird geladen...")
+            .style(Style::default().fg(theme().warn))
+            .block(block);
+        return (loading, Paragraph::new(""), Table::new(Vec::<Row>::new()));
+    };
+
+    let summary = match overview.count("all_networks") {
+        Some(all) => vec![
+            Line::from(vec![
+                Span::styled("Bekannt: ", Style::default().fg(theme().label)),
+                Span::styled(all.total.to_formatted_string(&Locale::de), Style::default().fg(theme().value)),
+            ]),
+            Line::from(vec![
+                Span::styled("Getestet: ", Style::default().fg(theme().label)),
+                Span::styled(
+                    format!("{} ({:.1}%)", all.tried.to_formatted_string(&Locale::de), all.tried_percent()),
+                    Style::default().fg(theme().value)
+                ),
+            ]),
+            Line::from(vec![
+                Span::styled("Neu: ", Style::default().fg(theme().label)),
+                Span::styled(all.new.to_formatted_string(&Locale::de), Style::default().fg(theme().value)),
+            ]),
+        ],
+        None => vec![Line::from(Span::styled(
+            "getaddrmaninfo erfordert Bitcoin Core 26 oder neuer",
+            Style::default().fg(theme().warn)
+        ))],
+    };
+    let mut content = summary;
+    content.extend([
+        Line::from(""),
+        Line::from(Span::styled(
+            "E: Alle bekannten Adressen exportieren",
+            Style::default().fg(theme().muted)
+        )),
+    ]);
+    let header = Paragraph::new(content).block(block);
+
+    let table_header = Row::new(vec!["Netzwerk", "Erreichbar", "Proxy", "Verbunden", "Neu", "Getestet", "Getestet %"])
+        .style(Style::default().fg(theme().title).add_modifier(Modifier::BOLD));
+
+    let rows: Vec<Row> = ADDRMAN_NETWORKS.iter().map(|network| {
+        let reachability = overview.reachability(network);
+        let count = overview.count(network);
+        let connected = peers.iter().filter(|p| p.network == *network).count();
+        let (status, color) = match reachability {
+            Some(r) if r.reachable => ("ja", if connected > 0 { theme().ok } else { theme().warn }),
+            Some(r) if r.limited => ("onlynet", theme().muted),
+            _ => ("nein", theme().muted),
+        };
+        let number = |value: Option<u64>| value.map(|v| v.to_formatted_string(&Locale::de)).unwrap_or_else(|| "-".to_string());
+
+        Row::new(vec![
+            network.to_string(),
+            status.to_string(),
+            reachability.map(|r| r.proxy.clone()).filter(|p| !p.is_empty()).unwrap_or_else(|| "-".to_string()),
+            connected.to_string(),
+            number(count.map(|c| c.new)),
+            number(count.map(|c| c.tried)),
+            count.map(|c| format!("{:.1}", c.tried_percent())).unwrap_or_else(|| "-".to_string()),
+        ]).style(Style::default().fg(color))
+    }).collect();
+
+    let table = Table::new(rows)
+        .header(table_header)
+        .block(Block::default()
+            .borders(Borders::ALL)
+            .title(" Erreichbarkeit je Netzwerk "))
+        .widths(&[
+            Constraint::Percentage(12), // Netzwerk
+            Constraint::Percentage(12), // Erreichbar
+            Constraint::Percentage(24), // Proxy
+            Constraint::Percentage(12), // Verbunden
+            Constraint::Percentage(14), // Neu
+            Constraint::Percentage(13), // Getestet
+            Constraint::Percentage(13), // Getestet %
+        ])
+        .column_spacing(1);
+
+    (render_samples(overview), header, table)
+}
@@ -18,13 +18,13 @@ pub fn render(
     let content = vec![
         Line::from(vec![
             Span::styled("📦 Block Information", 
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().title).add_modifier(Modifier::BOLD))
         ]),
         Line::from(""),
         // Suchleiste
         Line::from(vec![
-            Span::styled("🔍 ", Style::default().fg(Color::Yellow)),
-            Span::styled("Block suchen: ", Style::default().fg(Color::Cyan)),
+            Span::styled("🔍 ", Style::default().fg(theme().title)),
+            Span::styled("Block suchen: ", Style::default().fg(theme().label)),
             match search_mode {
                 BlockSearchMode::Latest => Span::styled(
                     if is_input_active {
@@ -32,7 +32,7 @@ pub fn render(
                     } else {
                         "[Aktueller Block]"
                     },
-                    Style::default().fg(if is_input_active { Color::Yellow } else { Color::Green })
+                    Style::default().fg(if is_input_active { theme().warn } else { theme().ok })
                 ),
                 BlockSearchMode::Custom(input) => Span::styled(
                     if is_input_active {
@@ -40,86 +40,93 @@ pub fn render(
                     } else {
                         format!("[{}]", input)
                     },
-                    Style::default().fg(Color::Yellow)
+                    Style::default().fg(theme().title)
                 ),
             },
         ]),
         Line::from(""),
         Line::from(vec![
-            Span::styled("Höhe: ", Style::default().fg(Color::Cyan)),
+            Span::styled("Höhe: ", Style::default().fg(theme().label)),
             Span::styled(
                 block.height.to_formatted_string(&Locale::de),
-                Style::default().fg(Color::White)
+                Style::default().fg(theme().value)
             ),
         ]),
         Line::from(vec![
-            Span::styled("Hash: ", Style::default().fg(Color::Cyan)),
-            Span::styled(block.hash.clone(), Style::default().fg(Color::White)),
+            Span::styled("Hash: ", Style::default().fg(theme().label)),
+            Span::styled(block.hash.clone(), Style::default().fg(theme().value)),
         ]),
         Line::from(vec![
-            Span::styled("Zeit: ", Style::default().fg(Color::Cyan)),
+            Span::styled("Zeit: ", Style::default().fg(theme().label)),
             Span::styled(
                 dt.format("%Y-%m-%d %H:%M:%S UTC").to_string(),
-                Style::default().fg(Color::White)
+                Style::default().fg(theme().value)
             ),
         ]),
+        Line::from(vec![
+            Span::styled("Pool: ", Style::default().fg(theme().label)),
+            match &block.pool {
+                Some(pool) => Span::styled(pool.clone(), Style::default().fg(theme().value)),
+                None => Span::styled("Unbekannt", Style::default().fg(theme().muted)),
+            },
+        ]),
         Line::from(""),
         Line::from(vec![
             Span::styled("🔍 Block Details", 
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().title).add_modifier(Modifier::BOLD))
         ]),
         Line::from(vec![
-            Span::styled("Transaktionen: ", Style::default().fg(Color::Cyan)),
+            Span::styled("Transaktionen: ", Style::default().fg(theme().label)),
             Span::styled(
                 block.tx_count.to_formatted_string(&Locale::de),
-                Style::default().fg(Color::White)
+                Style::default().fg(theme().value)
             ),
         ]),
         Line::from(vec![
-            Span::styled("Größe: ", Style::default().fg(Color::Cyan)),
+            Span::styled("Größe: ", Style::default().fg(theme().label)),
             Span::styled(
                 format!("{} Bytes", block.size.to_formatted_string(&Locale::de)),
-                Style::default().fg(Color::White)
+                Style::default().fg(theme().value)
             ),
         ]),
         Line::from(vec![
-            Span::styled("Gewicht: ", Style::default().fg(Color::Cyan)),
+            Span::styled("Gewicht: ", Style::default().fg(theme().label)),
             Span::styled(
                 format!("{} WU", block.weight.to_formatted_string(&Locale::de)),
-                Style::default().fg(Color::White)
+                Style::default().fg(theme().value)
             ),
         ]),
         Line::from(""),
         Line::from(vec![
             Span::styled("🔧 Technische Details", 
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().title).add_modifier(Modifier::BOLD))
         ]),
         Line::from(vec![
-            Span::styled("Version: ", Style::default().fg(Color::Cyan)),
+            Span::styled("Version: ", Style::default().fg(theme().label)),
             Span::styled(
                 block.version.to_string(),
-                Style::default().fg(Color::White)
+                Style::default().fg(theme().value)
             ),
         ]),
         Line::from(vec![
-            Span::styled("Merkle Root: ", Style::default().fg(Color::Cyan)),
+            Span::styled("Merkle Root: ", Style::default().fg(theme().label)),
             Span::styled(
                 block.merkle_root.clone(),
-                Style::default().fg(Color::White)
+                Style::default().fg(theme().value)
             ),
         ]),
         Line::from(vec![
-            Span::styled("Bits: ", Style::default().fg(Color::Cyan)),
+            Span::styled("Bits: ", Style::default().fg(theme().label)),
             Span::styled(
                 block.bits.clone(),
-                Style::default().fg(Color::White)
+                Style::default().fg(theme().value)
             ),
         ]),
         Line::from(vec![
-            Span::styled("Nonce: ", Style::default().fg(Color::Cyan)),
+            Span::styled("Nonce: ", Style::default().fg(theme().label)),
             Span::styled(
                 block.nonce.to_formatted_string(&Locale::de),
-                Style::default().fg(Color::White)
+                Style::default().fg(theme().value)
             ),
         ]),
     ];
@@ -127,6 +134,6 @@ pub fn render(
     Paragraph::new(content)
         .block(Block::default()
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::White))
+            .border_style(Style::default().fg(theme().value))
             .title(" Block Details "))
 } 
\ No newline at end of file
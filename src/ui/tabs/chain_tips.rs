@@ -0,0 +1,197 @@
+use crate::ui::common::*;
+use crate::rpc::{BitcoinRPC, ChainTip};
+use num_format::{Locale, ToFormattedString};
+use ratatui::widgets::{Table, Row};
+use std::collections::{HashMap, HashSet};
+
+// Blöcke eines Zweigs vom Tip abwärts als (Höhe, Hash) oder die Fehlermeldung
+type Branch = Result<Vec<(u64, String)>, String>;
+
+#[derive(Clone, Default)]
+pub struct ChainTipsMode {
+    pub selected: usize,
+    // Position innerhalb des Fork-Zweigs (0 = Tip)
+    pub depth: usize,
+    // Bereits gesehene valid-fork Tips; None bis zur ersten Abfrage
+    known_forks: Option<HashSet<String>>,
+    // Seit dem Start neu aufgetauchte valid-fork Tips
+    pub new_forks: HashSet<String>,
+    // Tips der letzten Abfrage und der Fehler, falls sie fehlschlug
+    pub tips: Vec<ChainTip>,
+    pub tips_error: Option<String>,
+    // Bereits abgelaufene Zweige je Tip-Hash, verworfen sobald sich die Tips ändern
+    branches: HashMap<String, Branch>,
+}
+
+impl ChainTipsMode {
+    pub fn select_next(&mut self, tip_count: usize) {
+        if self.selected + 1 < tip_count {
+            self.selected += 1;
+            self.depth = 0;
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        if self.selected > 0 {
+            self.selected -= 1;
+            self.depth = 0;
+        }
+    }
+
+    pub fn deeper(&mut self, branch_len: usize) {
+        if self.depth + 1 < branch_len {
+            self.depth += 1;
+        }
+    }
+
+    pub fn shallower(&mut self) {
+        self.depth = self.depth.saturating_sub(1);
+    }
+
+    /// Merkt sich die Tips samt valid-fork Tips und liefert die neu hinzugekommenen
+    pub fn observe(&mut self, tips: &[ChainTip]) -> Vec<ChainTip> {
+        self.tips_error = None;
+        if self.tips != tips {
+            self.tips = tips.to_vec();
+            self.branches.clear();
+        }
+
+        let forks = tips.iter().filter(|tip| tip.status == "valid-fork");
+
+        // Beim ersten Abruf vorhandene Forks nur übernehmen, nicht melden
+        let Some(known) = self.known_forks.as_mut() else {
+            self.known_forks = Some(forks.map(|tip| tip.hash.clone()).collect());
+            return Vec::new();
+        };
+
+        let new: Vec<ChainTip> = forks.filter(|tip| known.insert(tip.hash.clone())).cloned().collect();
+        self.new_forks.extend(new.iter().map(|tip| tip.hash.clone()));
+        new
+    }
+
+    /// Zweig des ausgewählten Tips ablaufen, falls er noch nicht im Cache liegt
+    pub fn load_branch(&mut self, client: &BitcoinRPC) {
+        let Some(tip) = self.tips.get(self.selected) else { return };
+        if !self.branches.contains_key(&tip.hash) {
+            let branch = client.get_fork_blocks(tip).map_err(|e| e.to_string());
+            self.branches.insert(tip.hash.clone(), branch);
+        }
+    }
+
+    /// Zwischengespeicherter Zweig des ausgewählten Tips; None vor load_branch
+    pub fn selected_branch(&self) -> Option<&Branch> {
+        self.branches.get(&self.tips.get(self.selected)?.hash)
+    }
+}
+
+pub fn render(mode: &ChainTipsMode, rpc_client: &Option<BitcoinRPC>) -> (Paragraph<'static>, Table<'static>) {
+    let mut content = vec![
+        Line::from(vec![
+            Span::styled("🌿 Chain Tips & Forks",
+                Style::default().fg(theme().title).add_modifier(Modifier::BOLD))
+        ]),
+        Line::from(Span::styled(
+            "↑/↓: Tip auswählen | ←/→: Im Fork-Zweig wandern | Enter: In Block Details öffnen",
+            Style::default().fg(theme().muted)
+        )),
+    ];
+
+    let error = match rpc_client {
+        Some(_) => mode.tips_error.clone(),
+        None => Some("Keine Verbindung zum Bitcoin Node".to_string()),
+    };
+    if let Some(e) = error {
+        content.push(Line::from(Span::styled(
+            format!("Fehler beim Laden der Chain Tips: {}", e),
+            Style::default().fg(theme().error)
+        )));
+    }
+    let tips = &mode.tips;
+
+    // Ausgewählten Block im Zweig des markierten Tips anzeigen
+    if let (Some(tip), Some(branch)) = (tips.get(mode.selected), mode.selected_branch()) {
+        let line = match branch {
+            Ok(blocks) if !tip.is_active() => {
+                let (height, hash) = &blocks[mode.depth.min(blocks.len() - 1)];
+                Line::from(vec![
+                    Span::styled(
+                        format!("Fork-Block {}/{}: ", mode.depth.min(blocks.len() - 1) + 1, blocks.len()),
+                        Style::default().fg(theme().label)
+                    ),
+                    Span::styled(
+                        format!("#{} {}", height.to_formatted_string(&Locale::de), hash),
+                        Style::default().fg(theme().value)
+                    ),
+                ])
+            }
+            Ok(_) => Line::from(vec![
+                Span::styled("Aktive Chain: ", Style::default().fg(theme().label)),
+                Span::styled(
+                    format!("#{} {}", tip.height.to_formatted_string(&Locale::de), tip.hash),
+                    Style::default().fg(theme().ok)
+                ),
+            ]),
+            Err(e) => Line::from(Span::styled(
+                format!("Fork-Blöcke nicht verfügbar: {}", e),
+                Style::default().fg(theme().error)
+            )),
+        };
+        content.push(line);
+    }
+
+    let header = Paragraph::new(content)
+        .block(Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme().value))
+            .title(" Chain Tips "));
+
+    let table_header = Row::new(vec!["Höhe", "Hash", "Zweiglänge", "Status"])
+        .style(Style::default().fg(theme().title).add_modifier(Modifier::BOLD));
+
+    let rows: Vec<Row> = tips.iter().enumerate().map(|(index, tip)| {
+        let is_new = mode.new_forks.contains(&tip.hash);
+        let mut style = Style::default().fg(status_color(&tip.status));
+        if is_new {
+            style = style.fg(theme().accent).add_modifier(Modifier::BOLD);
+        }
+        if index == mode.selected {
+            style = style.add_modifier(Modifier::REVERSED);
+        }
+
+        Row::new(vec![
+            tip.height.to_formatted_string(&Locale::de),
+            tip.hash.clone(),
+            tip.branch_len.to_string(),
+            if is_new { format!("{} (NEU)", tip.status) } else { tip.status.clone() },
+        ]).style(style)
+    }).collect();
+
+    let forks = tips.iter().filter(|tip| tip.status == "valid-fork").count();
+    let table = Table::new(rows)
+        .header(table_header)
+        .block(Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" Tips ({}) | Valide Forks: {} ", tips.len(), forks)))
+        .widths(&[
+            Constraint::Length(10), // Höhe
+            Constraint::Min(64),    // Hash
+            Constraint::Length(10), // Zweiglänge
+            Constraint::Length(20), // Status
+        ])
+        .column_spacing(1);
+
+    (header, table)
+}
+
+fn status_color(status: &str) -> Color {
+    match status {
+        "active" => theme().ok,
+        "valid-fork" => theme().warn,
+        "valid-headers" | "headers-only" => theme().label,
+        "invalid" => theme().error,
+        _ => theme().value,
+    }
+}
+
+#[cfg(test)]
+mod tests;
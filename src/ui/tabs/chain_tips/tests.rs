@@ -0,0 +1,43 @@
+use super::*;
+
+fn tip(hash: &str, status: &str) -> ChainTip {
+    ChainTip {
+        height: 100,
+        hash: hash.to_string(),
+        branch_len: 1,
+        status: status.to_string(),
+    }
+}
+
+#[test]
+fn existing_forks_are_not_reported_on_first_observe() {
+    let mut mode = ChainTipsMode::default();
+    assert!(mode.observe(&[tip("a", "active"), tip("b", "valid-fork")]).is_empty());
+    assert!(mode.new_forks.is_empty());
+}
+
+#[test]
+fn new_valid_fork_is_reported_once() {
+    let mut mode = ChainTipsMode::default();
+    mode.observe(&[tip("a", "active")]);
+
+    let tips = [tip("a", "active"), tip("b", "valid-fork"), tip("c", "headers-only")];
+    assert_eq!(mode.observe(&tips), vec![tip("b", "valid-fork")]);
+    assert!(mode.observe(&tips).is_empty());
+    assert!(mode.new_forks.contains("b"));
+}
+
+#[test]
+fn branch_cache_cleared_when_tips_change() {
+    let mut mode = ChainTipsMode::default();
+    let tips = [tip("a", "active"), tip("b", "valid-fork")];
+    mode.observe(&tips);
+    mode.branches.insert("a".to_string(), Ok(vec![(100, "a".to_string())]));
+
+    // Unveränderte Tips behalten den Cache
+    mode.observe(&tips);
+    assert!(mode.selected_branch().is_some());
+
+    mode.observe(&[tip("a", "active"), tip("c", "valid-fork")]);
+    assert!(mode.selected_branch().is_none());
+}
@@ -1,7 +1,69 @@
 use crate::ui::common::*;
+use crate::alerts::{Alert, Severity};
+use crate::history::{Delta, HistoryField, HistoryView};
 use num_format::{Locale, ToFormattedString};
 use ratatui::prelude::Alignment;
+use ratatui::widgets::Gauge;
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
 
+// Anzahl der Aktualisierungen, über die die Sync-Rate gemittelt wird
+const SYNC_WINDOW: usize = 10;
+
+#[derive(Debug, Clone, Copy)]
+struct SyncSample {
+    height: u64,
+    progress: f64,
+    at: Instant,
+}
+
+/// Misst die Synchronisationsgeschwindigkeit über mehrere Aktualisierungen
+#[derive(Debug, Clone, Default)]
+pub struct SyncTracker {
+    samples: VecDeque<SyncSample>,
+}
+
+impl SyncTracker {
+    pub fn record(&mut self, height: u64, progress: f64) {
+        self.record_at(height, progress, Instant::now());
+    }
+
+    fn record_at(&mut self, height: u64, progress: f64, at: Instant) {
+        if self.samples.len() >= SYNC_WINDOW {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(SyncSample { height, progress, at });
+    }
+
+    fn span(&self) -> Option<(SyncSample, SyncSample, f64)> {
+        let first = *self.samples.front()?;
+        let last = *self.samples.back()?;
+        let seconds = last.at.duration_since(first.at).as_secs_f64();
+        (seconds > 0.0).then_some((first, last, seconds))
+    }
+
+    pub fn blocks_per_sec(&self) -> Option<f64> {
+        self.span().map(|(first, last, seconds)| last.height.saturating_sub(first.height) as f64 / seconds)
+    }
+
+    /// Fortschritt in Prozentpunkten pro Stunde
+    pub fn progress_per_hour(&self) -> Option<f64> {
+        self.span().map(|(first, last, seconds)| (last.progress - first.progress) * 100.0 / seconds * 3600.0)
+    }
+
+    /// Geschätzte Restdauer bis zur vollständigen Verifikation
+    pub fn eta(&self) -> Option<Duration> {
+        let (_, last, _) = self.span()?;
+        let rate = self.progress_per_hour()?;
+        if rate <= 0.0 {
+            return None;
+        }
+        let hours = (1.0 - last.progress).max(0.0) * 100.0 / rate;
+        Some(Duration::from_secs_f64(hours * 3600.0))
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn render(
     network: &str,
     connections: u64,
@@ -14,20 +76,23 @@ pub fn render(
     size_on_disk: u64,
     pruned: bool,
     mempool_size: u64,
-    warnings: &str,
-) -> Paragraph<'static> {
+    live_tx_rate: Option<f64>,
+    alerts: &[Alert],
+    sync: &SyncTracker,
+    history: &HistoryView,
+) -> (Paragraph<'static>, Gauge<'static>) {
     // Konstanten für bessere Lesbarkeit
     const CRITICAL_CONNECTIONS: u64 = 4;
     const WARNING_CONNECTIONS: u64 = 8;
     const SYNC_COMPLETE_THRESHOLD: f64 = 0.99;
     
     // Farbdefinitionen für konsistenteres Aussehen
-    let title_style = Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD);
-    let label_style = Style::default().fg(Color::Cyan);
-    let value_style = Style::default().fg(Color::White);
-    let success_style = Style::default().fg(Color::Green);
-    let warning_style = Style::default().fg(Color::Yellow);
-    let error_style = Style::default().fg(Color::Red);
+    let title_style = Style::default().fg(theme().title).add_modifier(Modifier::BOLD);
+    let label_style = Style::default().fg(theme().label);
+    let value_style = Style::default().fg(theme().value);
+    let success_style = Style::default().fg(theme().ok);
+    let warning_style = Style::default().fg(theme().warn);
+    let error_style = Style::default().fg(theme().error);
     
     // Verbindungsstatus-Anzeige verbessern
     let connection_style = if connections > WARNING_CONNECTIONS {
@@ -68,11 +133,11 @@ pub fn render(
     let mut content = vec![
         // Netzwerk-Header mit verbesserten Icons und Layout
         Line::from(vec![
-            Span::styled("⚡ ", Style::default().fg(Color::Yellow)),
+            Span::styled("⚡ ", Style::default().fg(theme().title)),
             Span::styled(format!("Netzwerk: {} ", network), 
-                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD)),
             Span::raw(" | "),
-            Span::styled("🔌 ", Style::default().fg(Color::Yellow)),
+            Span::styled("🔌 ", Style::default().fg(theme().title)),
             Span::styled(
                 format!("{} Peers", connections.to_formatted_string(&Locale::de)),
                 connection_style
@@ -80,6 +145,18 @@ pub fn render(
             connection_warning
         ]),
         Line::from(""),
+    ];
+
+    // Alerts-Sektion (nur wenn nötig), kritische zuerst
+    if !alerts.is_empty() {
+        content.push(Line::from(vec![
+            Span::styled("🚨 Alerts", error_style.add_modifier(Modifier::BOLD)),
+        ]));
+        content.extend(alerts.iter().map(alert_line));
+        content.push(Line::from(""));
+    }
+
+    content.extend(vec![
         
         // Blockchain-Sektion
         Line::from(vec![
@@ -127,6 +204,7 @@ pub fn render(
                 ibd_style,
             ),
         ]),
+        sync_rate_line(sync, initial_block_download || verification_progress < SYNC_COMPLETE_THRESHOLD),
         Line::from(vec![
             Span::styled("Speicherplatz: ", label_style),
             Span::styled(format_size(size_on_disk), value_style),
@@ -148,28 +226,142 @@ pub fn render(
             Span::styled("Transaktionen: ", label_style),
             Span::styled(mempool_size.to_formatted_string(&Locale::de), value_style),
         ]),
-    ];
+    ]);
 
-    // Warnungs-Sektion (nur wenn nötig)
-    if !warnings.is_empty() {
-        content.extend(vec![
-            Line::from(""),
-            Line::from(vec![
-                Span::styled("⚠️ Warnungen: ", 
-                    error_style.add_modifier(Modifier::BOLD)),
-                Span::styled(warnings.to_string(), error_style),
-            ]),
-        ]);
+    // Nur mit ZMQ-Abonnement verfügbar
+    if let Some(rate) = live_tx_rate {
+        content.push(Line::from(vec![
+            Span::styled("Neue TXs (live): ", label_style),
+            Span::styled(format!("{:.1} tx/s", rate), value_style),
+            Span::styled(" (ZMQ)", Style::default().fg(theme().muted)),
+        ]));
+    }
+
+    // Vergleich mit dem lokalen Verlauf
+    if !history.deltas.is_empty() || history.blocks_24h > 0 {
+        content.push(Line::from(""));
+        content.push(Line::from(vec![
+            Span::styled("📈 Vergleich zu vor 24 h", title_style),
+        ]));
+        content.extend(history.deltas.iter().map(delta_line));
+        if let Some(interval) = history.avg_block_interval {
+            content.push(Line::from(vec![
+                Span::styled("Blockabstand: ", label_style),
+                Span::styled(format!("Ø {:.1} min", interval / 60.0), value_style),
+                Span::styled(format!(" ({} Blöcke gesehen)", history.blocks_24h), Style::default().fg(theme().muted)),
+            ]));
+        }
     }
 
     // Paragraph erstellen mit verbessertem Stil
-    Paragraph::new(content)
+    let paragraph = Paragraph::new(content)
         .block(Block::default()
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::White))
+            .border_style(Style::default().fg(theme().value))
             .title(Span::styled(" Dashboard Übersicht ", 
-                   Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))))
-        .alignment(Alignment::Left)
+                   Style::default().fg(theme().label).add_modifier(Modifier::BOLD))))
+        .alignment(Alignment::Left);
+
+    let gauge = Gauge::default()
+        .block(Block::default().borders(Borders::ALL).title(" Verifikation "))
+        .gauge_style(sync_style)
+        .ratio(verification_progress.clamp(0.0, 1.0))
+        .label(format!("{:.2}%", verification_progress * 100.0));
+
+    (paragraph, gauge)
+}
+
+fn delta_line(delta: &Delta) -> Line<'static> {
+    let (current, change) = match delta.field {
+        HistoryField::MempoolBytes => (
+            format!("{:.1} MB", delta.current / 1_000_000.0),
+            format!("{:+.1} MB", delta.change() / 1_000_000.0),
+        ),
+        HistoryField::SizeOnDisk => (
+            format!("{:.1} GB", delta.current / 1e9),
+            format!("{:+.2} GB", delta.change() / 1e9),
+        ),
+        _ => (
+            (delta.current as u64).to_formatted_string(&Locale::de),
+            format!("{:+}", delta.change() as i64),
+        ),
+    };
+    let percent = delta.percent()
+        .filter(|_| delta.field != HistoryField::Height)
+        .map(|p| format!(" / {:+.1}%", p))
+        .unwrap_or_default();
+    let color = if delta.change() > 0.0 {
+        theme().ok
+    } else if delta.change() < 0.0 {
+        theme().error
+    } else {
+        theme().muted
+    };
+
+    Line::from(vec![
+        Span::styled(format!("{}: ", delta.field.label()), Style::default().fg(theme().label)),
+        Span::styled(current, Style::default().fg(theme().value)),
+        Span::styled(format!(" ({}{})", change, percent), Style::default().fg(color)),
+    ])
+}
+
+fn alert_line(alert: &Alert) -> Line<'static> {
+    let style = match alert.severity {
+        Severity::Critical => Style::default().fg(theme().error),
+        Severity::Warning => Style::default().fg(theme().warn),
+        Severity::Info => Style::default().fg(theme().accent),
+    };
+    Line::from(vec![
+        Span::styled(format!(" ● {:<9}", alert.severity.label()), style.add_modifier(Modifier::BOLD)),
+        Span::styled(format!("{}: ", alert.source), Style::default().fg(theme().label)),
+        Span::styled(alert.message.clone(), style),
+    ])
+}
+
+fn sync_rate_line(sync: &SyncTracker, syncing: bool) -> Line<'static> {
+    let label_style = Style::default().fg(theme().label);
+    let value_style = Style::default().fg(theme().value);
+
+    if !syncing {
+        return Line::from(vec![
+            Span::styled("Sync: ", label_style),
+            Span::styled("Vollständig synchronisiert", Style::default().fg(theme().ok)),
+        ]);
+    }
+
+    let Some(blocks_per_sec) = sync.blocks_per_sec() else {
+        return Line::from(vec![
+            Span::styled("Sync-Rate: ", label_style),
+            Span::styled("Wird nach der nächsten Aktualisierung berechnet", Style::default().fg(theme().muted)),
+        ]);
+    };
+
+    let eta = match sync.eta() {
+        Some(eta) => {
+            let done = Utc::now() + chrono::Duration::seconds(eta.as_secs() as i64);
+            format!("{} (ca. {})", format_duration(eta), done.format("%d.%m. %H:%M UTC"))
+        }
+        None => "Unbekannt".to_string(),
+    };
+
+    Line::from(vec![
+        Span::styled("Rate: ", label_style),
+        Span::styled(format!("{:.1} Blöcke/s", blocks_per_sec), value_style),
+        Span::raw(" | "),
+        Span::styled(format!("{:.2}%/h", sync.progress_per_hour().unwrap_or(0.0)), value_style),
+        Span::raw(" | "),
+        Span::styled("ETA: ", label_style),
+        Span::styled(eta, Style::default().fg(theme().warn)),
+    ])
+}
+
+fn format_duration(duration: Duration) -> String {
+    let minutes = duration.as_secs() / 60;
+    match (minutes / (24 * 60), minutes / 60 % 24, minutes % 60) {
+        (0, 0, m) => format!("{}m", m),
+        (0, h, m) => format!("{}h {}m", h, m),
+        (d, h, _) => format!("{}d {}h", d, h),
+    }
 }
 
 fn format_size(bytes: u64) -> String {
@@ -191,4 +383,7 @@ fn format_size(bytes: u64) -> String {
     } else {
         format!("{} Bytes", bytes)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,41 @@
+use super::*;
+
+#[test]
+fn single_sample_has_no_rate() {
+    let mut sync = SyncTracker::default();
+    sync.record(100, 0.5);
+
+    assert!(sync.blocks_per_sec().is_none());
+    assert!(sync.eta().is_none());
+}
+
+#[test]
+fn rate_and_eta_from_samples() {
+    let start = Instant::now();
+    let mut sync = SyncTracker::default();
+    sync.record_at(1_000, 0.40, start);
+    sync.record_at(2_800, 0.50, start + Duration::from_secs(3600));
+
+    assert_eq!(sync.blocks_per_sec(), Some(0.5));
+    assert!((sync.progress_per_hour().unwrap() - 10.0).abs() < 1e-9);
+    // Noch 50 Prozentpunkte bei 10 pro Stunde
+    assert_eq!(sync.eta().unwrap().as_secs(), 5 * 3600);
+}
+
+#[test]
+fn stalled_sync_has_no_eta() {
+    let start = Instant::now();
+    let mut sync = SyncTracker::default();
+    sync.record_at(1_000, 0.40, start);
+    sync.record_at(1_000, 0.40, start + Duration::from_secs(60));
+
+    assert_eq!(sync.blocks_per_sec(), Some(0.0));
+    assert!(sync.eta().is_none());
+}
+
+#[test]
+fn durations_are_compact() {
+    assert_eq!(format_duration(Duration::from_secs(59 * 60)), "59m");
+    assert_eq!(format_duration(Duration::from_secs(3 * 3600 + 20 * 60)), "3h 20m");
+    assert_eq!(format_duration(Duration::from_secs(50 * 3600)), "2d 2h");
+}
@@ -1,109 +1,179 @@
-use crate::rpc::MempoolStats;
+use crate::rpc::{MempoolSample, MempoolStats, Trend};
+use crate::ui::common::{Chart, format_span, theme};
 use num_format::{Locale, ToFormattedString};
 use ratatui::{
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
     prelude::Alignment,
 };
 
-pub fn render(stats: &MempoolStats, is_loading: bool) -> Paragraph<'static> {
+pub fn render(stats: &MempoolStats, history: &[MempoolSample], is_loading: bool) -> (Paragraph<'static>, Vec<Chart>) {
     let title = if is_loading {
         " Mempool 🔄 "  // Lade-Symbol
     } else {
         " Mempool "
     };
 
+    let tx_counts: Vec<f64> = history.iter().map(|s| s.tx_count as f64).collect();
+    let vsizes: Vec<f64> = history.iter().map(|s| s.vsize as f64).collect();
+    let total_fees: Vec<f64> = history.iter().map(|s| s.total_fee).collect();
+    let min_fees: Vec<f64> = history.iter().map(|s| s.min_fee).collect();
+
     let content = vec![
         // Titel
         Line::from(vec![
             Span::styled("💭 Mempool Status", 
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().title).add_modifier(Modifier::BOLD))
         ]),
         Line::from(""),
         
         // Allgemeine Info
         Line::from(vec![
-            Span::styled("Transaktionen: ", Style::default().fg(Color::Cyan)),
+            Span::styled("Transaktionen: ", Style::default().fg(theme().label)),
             Span::styled(
                 stats.tx_count.to_formatted_string(&Locale::de),
-                Style::default().fg(Color::White)
+                Style::default().fg(theme().value)
             ),
+            trend_span(&tx_counts),
         ]),
         Line::from(vec![
-            Span::styled("Größe: ", Style::default().fg(Color::Cyan)),
+            Span::styled("Größe: ", Style::default().fg(theme().label)),
             Span::styled(
                 format!("{:.2} MB", (stats.size as f64 / 1_000_000.0)),
-                Style::default().fg(Color::White)
+                Style::default().fg(theme().value)
+            ),
+            trend_span(&vsizes),
+        ]),
+        Line::from(vec![
+            Span::styled("Gebühren gesamt: ", Style::default().fg(theme().label)),
+            Span::styled(
+                format!("{:.4} BTC", stats.total_fee),
+                Style::default().fg(theme().value)
+            ),
+            trend_span(&total_fees),
+        ]),
+        Line::from(vec![
+            Span::styled("Mindest-Feerate: ", Style::default().fg(theme().label)),
+            Span::styled(
+                format!("{:.2} sat/vB", stats.min_fee),
+                Style::default().fg(theme().value)
             ),
+            trend_span(&min_fees),
         ]),
         Line::from(""),
         
         // Gebühren-Header vereinfachen
         Line::from(vec![
             Span::styled("📊 Transaction Fees", 
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().title).add_modifier(Modifier::BOLD))
         ]),
         Line::from(""),
         
         // No Priority
         Line::from(vec![
-            Span::styled("No Priority    ", Style::default().fg(Color::DarkGray)),
+            Span::styled("No Priority    ", Style::default().fg(theme().muted)),
             Span::styled(
                 format!("{:>3.0} sat/vB", stats.no_priority.rate),
-                Style::default().fg(Color::White)
+                Style::default().fg(theme().value)
             ),
             Span::styled(
                 format!("  ${:.2}", stats.no_priority.usd_price),
-                Style::default().fg(Color::DarkGray)
+                Style::default().fg(theme().muted)
             ),
         ]),
         
         // Low Priority
         Line::from(vec![
-            Span::styled("Low Priority   ", Style::default().fg(Color::Red)),
+            Span::styled("Low Priority   ", Style::default().fg(theme().error)),
             Span::styled(
                 format!("{:>3.0} sat/vB", stats.low_priority.rate),
-                Style::default().fg(Color::White)
+                Style::default().fg(theme().value)
             ),
             Span::styled(
                 format!("  ${:.2}", stats.low_priority.usd_price),
-                Style::default().fg(Color::DarkGray)
+                Style::default().fg(theme().muted)
             ),
         ]),
         
         // Medium Priority
         Line::from(vec![
-            Span::styled("Medium Priority", Style::default().fg(Color::Yellow)),
+            Span::styled("Medium Priority", Style::default().fg(theme().warn)),
             Span::styled(
                 format!("{:>3.0} sat/vB", stats.medium_priority.rate),
-                Style::default().fg(Color::White)
+                Style::default().fg(theme().value)
             ),
             Span::styled(
                 format!("  ${:.2}", stats.medium_priority.usd_price),
-                Style::default().fg(Color::DarkGray)
+                Style::default().fg(theme().muted)
             ),
         ]),
         // High Priority
         Line::from(vec![
-            Span::styled("High Priority  ", Style::default().fg(Color::Green)),
+            Span::styled("High Priority  ", Style::default().fg(theme().ok)),
             Span::styled(
                 format!("{:>3.0} sat/vB", stats.high_priority.rate),
-                Style::default().fg(Color::White)
+                Style::default().fg(theme().value)
             ),
             Span::styled(
                 format!("  ${:.2}", stats.high_priority.usd_price),
-                Style::default().fg(Color::DarkGray)
+                Style::default().fg(theme().muted)
             ),
         ]),
         Line::from(""),
     ];
 
-    Paragraph::new(content)
+    let paragraph = Paragraph::new(content)
         .block(Block::default()
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::White))
-            .title(title))
+            .border_style(Style::default().fg(theme().value))
+            .title(title));
+
+    let span = history_span(history);
+    let charts = vec![
+        Chart {
+            title: format!(" Transaktionen {} ({}) ", Trend::from_series(&tx_counts).symbol(), span),
+            data: history.iter().map(|s| s.tx_count).collect(),
+            color: theme().accent,
+        },
+        Chart {
+            title: format!(" vsize {} ({}) ", Trend::from_series(&vsizes).symbol(), span),
+            data: history.iter().map(|s| s.vsize).collect(),
+            color: theme().accent,
+        },
+        Chart {
+            title: format!(" Gebühren gesamt {} ({}) ", Trend::from_series(&total_fees).symbol(), span),
+            // In Satoshi, damit die Sparkline ganzzahlige Werte bekommt
+            data: history.iter().map(|s| (s.total_fee * 100_000_000.0) as u64).collect(),
+            color: theme().warn,
+        },
+        Chart {
+            title: format!(" Mindest-Feerate {} ({}) ", Trend::from_series(&min_fees).symbol(), span),
+            data: history.iter().map(|s| (s.min_fee * 100.0) as u64).collect(),
+            color: theme().ok,
+        },
+    ];
+
+    (paragraph, charts)
+}
+
+fn trend_span(values: &[f64]) -> Span<'static> {
+    let trend = Trend::from_series(values);
+    let color = match trend {
+        Trend::Rising => theme().error,
+        Trend::Falling => theme().ok,
+        Trend::Stable => theme().muted,
+    };
+    Span::styled(format!(" {}", trend.symbol()), Style::default().fg(color))
+}
+
+// Zeitraum, den der Verlauf abdeckt
+fn history_span(history: &[MempoolSample]) -> String {
+    let seconds = match (history.first(), history.last()) {
+        (Some(first), Some(last)) => last.timestamp - first.timestamp,
+        _ => 0,
+    };
+    format_span(seconds)
 }
 
 #[allow(dead_code)]
@@ -111,17 +181,17 @@ pub fn render_help() -> Paragraph<'static> {
     let text = vec![
         Line::from(vec![
             Span::styled("📊 Mempool-Übersicht Hilfe", 
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().title).add_modifier(Modifier::BOLD))
         ]),
         Line::from(""),
         Line::from(vec![
-            Span::styled("Allgemeine Statistiken:", Style::default().fg(Color::Cyan))
+            Span::styled("Allgemeine Statistiken:", Style::default().fg(theme().label))
         ]),
         Line::from("• Transaktionen: Gesamtzahl der unbestätigten Transaktionen im Mempool"),
         Line::from("• Größe: Aktuelle Größe des Mempools in Megabyte (MB)"),
         Line::from(""),
         Line::from(vec![
-            Span::styled("Gebührenkategorien:", Style::default().fg(Color::Cyan))
+            Span::styled("Gebührenkategorien:", Style::default().fg(theme().label))
         ]),
         Line::from("1. No Priority (1 sat/vB):"),
         Line::from("   - Sehr niedrige Priorität"),
@@ -144,13 +214,13 @@ pub fn render_help() -> Paragraph<'static> {
         Line::from("   - Express-Option (~$1.70)"),
         Line::from(""),
         Line::from(vec![
-            Span::styled("Anzeige-Details:", Style::default().fg(Color::Cyan))
+            Span::styled("Anzeige-Details:", Style::default().fg(theme().label))
         ]),
         Line::from("• sat/vB: Gebühr in Satoshi pro virtuellem Byte"),
         Line::from("• USD: Geschätzter Preis für eine durchschnittliche Transaktion (250 Bytes)"),
         Line::from(""),
         Line::from(vec![
-            Span::styled("Datenquelle:", Style::default().fg(Color::Cyan))
+            Span::styled("Datenquelle:", Style::default().fg(theme().label))
         ]),
         Line::from("• Live-Daten von mempool.space"),
         Line::from("• Preise basieren auf aktuellem Bitcoin-Kurs"),
@@ -159,7 +229,7 @@ pub fn render_help() -> Paragraph<'static> {
     Paragraph::new(text)
         .block(Block::default()
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::White))
+            .border_style(Style::default().fg(theme().value))
             .title(" Mempool Hilfe "))
         .alignment(Alignment::Left)
 } 
\ No newline at end of file
@@ -1,86 +1,280 @@
 use crate::ui::common::*;
+use crate::pools::PoolShare;
+use crate::rpc::{BlockTemplate, MiningStats};
+use chrono::{Local, TimeZone};
 use num_format::{Locale, ToFormattedString};
+use ratatui::widgets::{Row, Table, Wrap};
 
-pub fn render(
-    difficulty: f64,
-    blocks_until_adjustment: i64,
-    estimated_hashrate: f64,
-    next_difficulty_estimate: f64,
-) -> Paragraph<'static> {
-    let content = vec![
+// Kopfbereich der Template-Vorschau über der Transaktionstabelle
+pub const TEMPLATE_HEADER_HEIGHT: u16 = 8;
+// Weitere Pools werden unter "Andere" zusammengefasst
+const MAX_LISTED_POOLS: usize = 6;
+
+pub fn render(stats: &MiningStats, pool_shares: Option<&(u64, Vec<PoolShare>)>) -> Paragraph<'static> {
+    let adjustment = &stats.adjustment;
+    let halving = &stats.halving;
+    let next_difficulty = adjustment.next_difficulty(stats.difficulty);
+
+    let mut content = vec![
         Line::from(vec![
-            Span::styled("⛏️  Mining Status", 
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+            Span::styled("⛏️  Mining Status",
+                Style::default().fg(theme().title).add_modifier(Modifier::BOLD))
         ]),
         Line::from(""),
         Line::from(vec![
-            Span::styled("📊 Aktuelle Difficulty", 
-                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+            Span::styled("📊 Aktuelle Difficulty",
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
         ]),
         Line::from(vec![
-            Span::styled("Wert: ", Style::default().fg(Color::Cyan)),
+            Span::styled("Wert: ", Style::default().fg(theme().label)),
             Span::styled(
-                format!("{:.2e}", difficulty),
-                Style::default().fg(Color::White)
+                format!("{:.2e}", stats.difficulty),
+                Style::default().fg(theme().value)
             ),
         ]),
         Line::from(vec![
-            Span::styled("Dezimal: ", Style::default().fg(Color::Cyan)),
+            Span::styled("Dezimal: ", Style::default().fg(theme().label)),
             Span::styled(
-                (difficulty.round() as u64).to_formatted_string(&Locale::de),
-                Style::default().fg(Color::White)
+                (stats.difficulty.round() as u64).to_formatted_string(&Locale::de),
+                Style::default().fg(theme().value)
             ),
         ]),
         Line::from(""),
         Line::from(vec![
-            Span::styled("🔄 Nächste Anpassung", 
-                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+            Span::styled("🔄 Nächste Anpassung",
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
         ]),
         Line::from(vec![
-            Span::styled("Blöcke: ", Style::default().fg(Color::Cyan)),
+            Span::styled("Blöcke: ", Style::default().fg(theme().label)),
             Span::styled(
-                blocks_until_adjustment.to_formatted_string(&Locale::de),
-                Style::default().fg(Color::White)
+                adjustment.blocks_until.to_formatted_string(&Locale::de),
+                Style::default().fg(theme().value)
             ),
             Span::raw(" ("),
             Span::styled(
-                format!("~{:.1} Tage", blocks_until_adjustment as f64 * 10.0 / (60.0 * 24.0)),
-                Style::default().fg(Color::DarkGray)
+                format!("~{}", format_date(adjustment.estimated_time)),
+                Style::default().fg(theme().muted)
             ),
             Span::raw(")"),
         ]),
         Line::from(vec![
-            Span::styled("Geschätzte Änderung: ", Style::default().fg(Color::Cyan)),
+            Span::styled("Ø Blockzeit (Epoche): ", Style::default().fg(theme().label)),
             Span::styled(
-                format!("{:+.2}%", (next_difficulty_estimate / difficulty - 1.0) * 100.0),
-                Style::default().fg(if next_difficulty_estimate > difficulty {
-                    Color::Red
+                format!("{:.1} min", adjustment.avg_block_time / 60.0),
+                Style::default().fg(theme().value)
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("Geschätzte Änderung: ", Style::default().fg(theme().label)),
+            Span::styled(
+                format!("{:+.2}%", adjustment.percent),
+                Style::default().fg(if adjustment.percent > 0.0 {
+                    theme().error
                 } else {
-                    Color::Green
+                    theme().ok
                 })
             ),
+            Span::styled(
+                format!(" → {:.2e}", next_difficulty),
+                Style::default().fg(theme().muted)
+            ),
         ]),
         Line::from(""),
         Line::from(vec![
-            Span::styled("⚡ Netzwerk-Hashrate", 
-                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+            Span::styled("⚡ Netzwerk-Hashrate",
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
         ]),
+    ];
+
+    content.extend(stats.hashrates.iter().map(|(window, hashrate)| Line::from(vec![
+        Span::styled(format!("{}: ", window), Style::default().fg(theme().label)),
+        Span::styled(
+            format_hashrate(*hashrate),
+            Style::default().fg(theme().value)
+        ),
+    ])));
+
+    content.extend([
+        Line::from(""),
         Line::from(vec![
-            Span::styled("Aktuell: ", Style::default().fg(Color::Cyan)),
+            Span::styled("💰 Halving",
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
+        ]),
+        Line::from(vec![
+            Span::styled("Nächstes Halving: ", Style::default().fg(theme().label)),
             Span::styled(
-                format_hashrate(estimated_hashrate),
-                Style::default().fg(Color::White)
+                format!("Block {}", halving.height.to_formatted_string(&Locale::de)),
+                Style::default().fg(theme().value)
             ),
         ]),
-    ];
+        Line::from(vec![
+            Span::styled("Verbleibend: ", Style::default().fg(theme().label)),
+            Span::styled(
+                format!("{} Blöcke", halving.blocks_until.to_formatted_string(&Locale::de)),
+                Style::default().fg(theme().value)
+            ),
+            Span::raw(" ("),
+            Span::styled(
+                format!("~{}", format_date(halving.estimated_time)),
+                Style::default().fg(theme().muted)
+            ),
+            Span::raw(")"),
+        ]),
+        Line::from(vec![
+            Span::styled("Subsidy: ", Style::default().fg(theme().label)),
+            Span::styled(
+                format_btc(halving.current_subsidy),
+                Style::default().fg(theme().value)
+            ),
+            Span::styled(
+                format!(" → {}", format_btc(halving.next_subsidy)),
+                Style::default().fg(theme().muted)
+            ),
+        ]),
+    ]);
+
+    if let Some((blocks, shares)) = pool_shares {
+        content.push(Line::from(""));
+        content.push(Line::from(vec![
+            Span::styled(format!("🏊 Pools (letzte {} Blöcke)", blocks),
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
+        ]));
+        content.extend(shares.iter().take(MAX_LISTED_POOLS).map(pool_line));
+
+        let others: Vec<&PoolShare> = shares.iter().skip(MAX_LISTED_POOLS).collect();
+        if !others.is_empty() {
+            content.push(pool_line(&PoolShare {
+                name: format!("Andere ({})", others.len()),
+                blocks: others.iter().map(|share| share.blocks).sum(),
+                percent: others.iter().map(|share| share.percent).sum(),
+            }));
+        }
+    }
 
     Paragraph::new(content)
         .block(Block::default()
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::White))
+            .border_style(Style::default().fg(theme().value))
             .title(" Mining "))
 }
 
+/// Vorschau des nächsten Blocks: Zusammenfassung und Transaktionen mit der höchsten Feerate
+pub fn render_template(template: Option<&Result<BlockTemplate, String>>) -> (Paragraph<'static>, Table<'static>) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme().value))
+        .title(" Nächster Block (getblocktemplate) ");
+
+    let template = match template {
+        Some(Ok(template)) => template,
+        Some(Err(e)) => {
+            let summary = Paragraph::new(format!("Block-Template nicht verfügbar: {}", e))
+                .style(Style::default().fg(theme().error))
+                .wrap(Wrap { trim: true })
+                .block(block);
+            return (summary, Table::new(Vec::<Row>::new()));
+        }
+        None => {
+            let summary = Paragraph::new("Block-Template wird geladen...")
+                .style(Style::default().fg(theme().warn))
+                .block(block);
+            return (summary, Table::new(Vec::<Row>::new()));
+        }
+    };
+
+    let feerates = match (template.min_feerate, template.max_feerate) {
+        (Some(min), Some(max)) => format!("{:.1} - {:.1} sat/vB", min, max),
+        _ => "-".to_string(),
+    };
+    let content = vec![
+        Line::from(vec![
+            Span::styled("Höhe: ", Style::default().fg(theme().label)),
+            Span::styled(
+                template.height.to_formatted_string(&Locale::de),
+                Style::default().fg(theme().value)
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("Transaktionen: ", Style::default().fg(theme().label)),
+            Span::styled(
+                template.tx_count.to_formatted_string(&Locale::de),
+                Style::default().fg(theme().value)
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("Gebühren: ", Style::default().fg(theme().label)),
+            Span::styled(format_btc(template.total_fees), Style::default().fg(theme().value)),
+        ]),
+        Line::from(vec![
+            Span::styled("Coinbase: ", Style::default().fg(theme().label)),
+            Span::styled(format_btc(template.coinbase_value), Style::default().fg(theme().value)),
+        ]),
+        Line::from(vec![
+            Span::styled("Weight: ", Style::default().fg(theme().label)),
+            Span::styled(
+                format!(
+                    "{} / {} WU",
+                    template.weight.to_formatted_string(&Locale::de),
+                    template.weight_limit.to_formatted_string(&Locale::de)
+                ),
+                Style::default().fg(theme().value)
+            ),
+            Span::styled(
+                format!(" ({:.1}%)", template.weight_percent()),
+                Style::default().fg(theme().muted)
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("Feerate (Paket): ", Style::default().fg(theme().label)),
+            Span::styled(feerates, Style::default().fg(theme().value)),
+        ]),
+    ];
+    let summary = Paragraph::new(content).block(block);
+
+    let header = Row::new(vec!["TX-ID", "sat/vB", "Gebühr (sat)", "vBytes"])
+        .style(Style::default().fg(theme().title).add_modifier(Modifier::BOLD));
+    let rows: Vec<Row> = template.top_transactions.iter().map(|tx| Row::new(vec![
+        tx.txid.clone(),
+        format!("{:.1}", tx.package_feerate),
+        tx.fee.to_formatted_string(&Locale::de),
+        tx.weight.div_ceil(4).to_formatted_string(&Locale::de),
+    ])).collect();
+
+    let table = Table::new(rows)
+        .header(header)
+        .block(Block::default()
+            .borders(Borders::ALL)
+            .title(" Höchste Feerates "))
+        .widths(&[
+            Constraint::Percentage(52), // TX-ID
+            Constraint::Percentage(14), // Feerate
+            Constraint::Percentage(20), // Gebühr
+            Constraint::Percentage(14), // vBytes
+        ])
+        .column_spacing(1);
+
+    (summary, table)
+}
+
+fn pool_line(share: &PoolShare) -> Line<'static> {
+    Line::from(vec![
+        Span::styled(format!("{}: ", share.name), Style::default().fg(theme().label)),
+        Span::styled(format!("{:.1}%", share.percent), Style::default().fg(theme().value)),
+        Span::styled(format!(" ({} Blöcke)", share.blocks), Style::default().fg(theme().muted)),
+    ])
+}
+
+fn format_date(timestamp: i64) -> String {
+    Local.timestamp_opt(timestamp, 0)
+        .single()
+        .map(|time| time.format("%d.%m.%Y %H:%M").to_string())
+        .unwrap_or_else(|| "-".to_string())
+}
+
+fn format_btc(sats: u64) -> String {
+    format!("{} BTC", sats as f64 / 100_000_000.0)
+}
+
 fn format_hashrate(h: f64) -> String {
     if h >= 1e18 {
         format!("{:.2} EH/s", h / 1e18)
@@ -97,4 +291,4 @@ fn format_hashrate(h: f64) -> String {
     } else {
         format!("{:.2} H/s", h)
     }
-} 
\ No newline at end of file
+}
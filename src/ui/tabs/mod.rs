@@ -7,6 +7,12 @@ pub mod network;
 pub mod peer_list;
 pub mod mining;
 pub mod security;
+pub mod wallet;
+pub mod watchlist;
+pub mod psbt;
+pub mod chain_tips;
+pub mod traffic;
+pub mod addrman;
 
 pub use dashboard::render as render_node_info;
 pub use block_details::render as render_block_details;
@@ -16,4 +22,11 @@ pub use mempool::render as render_mempool;
 pub use network::render as render_network;
 pub use peer_list::render as render_peer_list;
 pub use mining::render as render_mining;
-pub use security::render as render_security; 
\ No newline at end of file
+pub use mining::render_template as render_block_template;
+pub use security::render as render_security;
+pub use wallet::render as render_wallet;
+pub use watchlist::render as render_watchlist;
+pub use psbt::render as render_psbt;
+pub use chain_tips::render as render_chain_tips;
+pub use traffic::render as render_traffic;
+pub use addrman::render as render_addrman; 
\ No newline at end of file
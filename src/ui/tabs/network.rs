@@ -1,9 +1,21 @@
 use crate::ui::common::*;
-use crate::rpc::PeerInfo;
+use crate::rpc::{PeerInfo, client_distribution, peer_breakdown, user_agent_flags};
+use crate::history::HistoryView;
 use num_format::{Locale, ToFormattedString};
 use log::{info, warn, error};
 
-pub fn render(connections: u64, network: String, verification_progress: f64, peers: &[PeerInfo]) -> Paragraph<'static> {
+// Häufigste Clients in der Verteilung und Breite des längsten Balkens
+const CLIENT_ROWS: usize = 8;
+const CLIENT_BAR_WIDTH: usize = 20;
+
+pub fn render(
+    connections: u64,
+    network: String,
+    verification_progress: f64,
+    peers: &[PeerInfo],
+    node_version: u64,
+    history: &HistoryView,
+) -> (Paragraph<'static>, Vec<Chart>) {
     // Peer-Statistiken berechnen
     let inbound_count = peers.iter().filter(|p| p.inbound).count();
     let outbound_count = peers.len() - inbound_count;
@@ -12,16 +24,11 @@ pub fn render(connections: u64, network: String, verification_progress: f64, pee
     let mut min_latency: f64 = f64::MAX;
     let mut max_latency: f64 = 0.0;
     let mut avg_latency: f64 = 0.0;
-    let mut onion_count = 0;
 
     for peer in peers {
         min_latency = min_latency.min(peer.latency);
         max_latency = max_latency.max(peer.latency);
         avg_latency += peer.latency;
-        
-        if peer.addr.contains(".onion") {
-            onion_count += 1;
-        }
     }
 
     avg_latency = if !peers.is_empty() {
@@ -30,99 +37,160 @@ pub fn render(connections: u64, network: String, verification_progress: f64, pee
         0.0
     };
 
-    let content = vec![
+    let mut content = vec![
         Line::from(vec![
             Span::styled("⚡ Netzwerk Status", 
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().title).add_modifier(Modifier::BOLD))
         ]),
         Line::from(""),
         Line::from(vec![
-            Span::styled("Netzwerk: ", Style::default().fg(Color::Cyan)),
+            Span::styled("Netzwerk: ", Style::default().fg(theme().label)),
             Span::styled(
                 network,
-                Style::default().fg(Color::White)
+                Style::default().fg(theme().value)
             ),
         ]),
         Line::from(vec![
-            Span::styled("Synchronisation: ", Style::default().fg(Color::Cyan)),
+            Span::styled("Synchronisation: ", Style::default().fg(theme().label)),
             Span::styled(
                 format!("{:.2}%", verification_progress * 100.0),
                 Style::default().fg(if verification_progress >= 0.99 {
-                    Color::Green
+                    theme().ok
                 } else {
-                    Color::Yellow
+                    theme().warn
                 })
             ),
         ]),
         Line::from(""),
         Line::from(vec![
             Span::styled("🔌 Verbindungen", 
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().title).add_modifier(Modifier::BOLD))
         ]),
         Line::from(vec![
-            Span::styled("Gesamt: ", Style::default().fg(Color::Cyan)),
+            Span::styled("Gesamt: ", Style::default().fg(theme().label)),
             Span::styled(
                 connections.to_formatted_string(&Locale::de),
-                Style::default().fg(if connections >= 8 { Color::Green } else { Color::Yellow })
+                Style::default().fg(if connections >= 8 { theme().ok } else { theme().warn })
             ),
         ]),
         Line::from(vec![
-            Span::styled("Eingehend: ", Style::default().fg(Color::Cyan)),
+            Span::styled("Eingehend: ", Style::default().fg(theme().label)),
             Span::styled(
                 inbound_count.to_formatted_string(&Locale::de),
-                Style::default().fg(Color::White)
+                Style::default().fg(theme().value)
             ),
         ]),
         Line::from(vec![
-            Span::styled("Ausgehend: ", Style::default().fg(Color::Cyan)),
+            Span::styled("Ausgehend: ", Style::default().fg(theme().label)),
             Span::styled(
                 outbound_count.to_formatted_string(&Locale::de),
-                Style::default().fg(Color::White)
+                Style::default().fg(theme().value)
             ),
         ]),
         Line::from(vec![
-            Span::styled("Tor (Onion): ", Style::default().fg(Color::Cyan)),
+            Span::styled("Verschlüsselt (v2): ", Style::default().fg(theme().label)),
             Span::styled(
-                format!("{} ({:.1}%)", 
-                    onion_count,
-                    (onion_count as f64 / peers.len() as f64) * 100.0
-                ),
-                Style::default().fg(Color::White)
+                peers.iter().filter(|p| p.transport_protocol_type == "v2").count().to_formatted_string(&Locale::de),
+                Style::default().fg(theme().value)
             ),
         ]),
         Line::from(""),
         Line::from(vec![
             Span::styled("📊 Latenz (ms)", 
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().title).add_modifier(Modifier::BOLD))
         ]),
         Line::from(vec![
-            Span::styled("Minimum: ", Style::default().fg(Color::Cyan)),
+            Span::styled("Minimum: ", Style::default().fg(theme().label)),
             Span::styled(
                 format!("{:.2}", min_latency),
-                Style::default().fg(if min_latency < 100.0 { Color::Green } else { Color::Yellow })
+                Style::default().fg(if min_latency < 100.0 { theme().ok } else { theme().warn })
             ),
         ]),
         Line::from(vec![
-            Span::styled("Maximum: ", Style::default().fg(Color::Cyan)),
+            Span::styled("Maximum: ", Style::default().fg(theme().label)),
             Span::styled(
                 format!("{:.2}", max_latency),
-                Style::default().fg(if max_latency > 500.0 { Color::Red } else { Color::Yellow })
+                Style::default().fg(if max_latency > 500.0 { theme().error } else { theme().warn })
             ),
         ]),
         Line::from(vec![
-            Span::styled("Durchschnitt: ", Style::default().fg(Color::Cyan)),
+            Span::styled("Durchschnitt: ", Style::default().fg(theme().label)),
             Span::styled(
                 format!("{:.2}", avg_latency),
-                Style::default().fg(if avg_latency < 200.0 { Color::Green } else { Color::Yellow })
+                Style::default().fg(if avg_latency < 200.0 { theme().ok } else { theme().warn })
             ),
         ]),
     ];
 
-    Paragraph::new(content)
+    // Aufschlüsselung nach Netzwerk und Verbindungstyp
+    for (title, breakdown) in [
+        ("🌍 Netzwerke", peer_breakdown(peers, |p| &p.network)),
+        ("🔗 Verbindungstypen", peer_breakdown(peers, |p| &p.connection_type)),
+    ] {
+        content.push(Line::from(""));
+        content.push(Line::from(vec![
+            Span::styled(title, Style::default().fg(theme().title).add_modifier(Modifier::BOLD))
+        ]));
+        content.extend(breakdown.into_iter().map(|(name, count)| Line::from(vec![
+            Span::styled(format!("{}: ", name), Style::default().fg(theme().label)),
+            Span::styled(
+                format!("{} ({:.1}%)", count, count as f64 / peers.len() as f64 * 100.0),
+                Style::default().fg(theme().value)
+            ),
+        ])));
+    }
+
+    // Client-Verteilung als Textbalken, längster Balken = häufigster Client
+    let clients = client_distribution(peers);
+    let max_count = clients.first().map_or(1, |(_, count)| *count);
+    content.push(Line::from(""));
+    content.push(Line::from(vec![
+        Span::styled("🧩 Client-Versionen", Style::default().fg(theme().title).add_modifier(Modifier::BOLD))
+    ]));
+    content.extend(clients.into_iter().take(CLIENT_ROWS).map(|(label, count)| Line::from(vec![
+        Span::styled(format!("{:<16}", label), Style::default().fg(theme().label)),
+        Span::styled(
+            "█".repeat((count * CLIENT_BAR_WIDTH).div_ceil(max_count)),
+            Style::default().fg(theme().ok)
+        ),
+        Span::styled(format!(" {}", count), Style::default().fg(theme().value)),
+    ])));
+
+    let flagged = peers.iter().filter(|p| !user_agent_flags(p, node_version).is_empty()).count();
+    content.push(Line::from(vec![
+        Span::styled("Auffällige User-Agents: ", Style::default().fg(theme().label)),
+        Span::styled(
+            flagged.to_formatted_string(&Locale::de),
+            Style::default().fg(if flagged > 0 { theme().error } else { theme().ok })
+        ),
+        Span::styled(
+            if flagged > 0 { " (Details in der Peer-Liste)" } else { "" },
+            Style::default().fg(theme().muted)
+        ),
+    ]));
+
+    let paragraph = Paragraph::new(content)
         .block(Block::default()
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::White))
-            .title(" Netzwerk "))
+            .border_style(Style::default().fg(theme().value))
+            .title(" Netzwerk "));
+
+    // Verlauf aus dem lokalen Snapshot-Speicher; nach dem Start noch kürzer als 24 h
+    let span = format_span(history.series_span);
+    let charts = vec![
+        Chart {
+            title: format!(" Peers ({}) ", span),
+            data: history.connections.clone(),
+            color: theme().accent,
+        },
+        Chart {
+            title: format!(" Eingehend ({}) ", span),
+            data: history.peers_inbound.clone(),
+            color: theme().ok,
+        },
+    ];
+
+    (paragraph, charts)
 }
 
 #[allow(dead_code)]
@@ -1,9 +1,92 @@
 use crate::ui::common::*;
-use crate::rpc::PeerInfo;
-use ratatui::widgets::{Table, Row};
+use crate::rpc::{PeerInfo, parse_user_agent, user_agent_flags};
+use ratatui::widgets::{Table, TableState, Row};
 use chrono::{DateTime, Utc};
 
-pub fn render(peers: &[PeerInfo]) -> Table<'static> {
+// Rahmen plus fünf Zeilen für den ausgewählten Peer
+pub const PEER_HEADER_HEIGHT: u16 = 7;
+
+#[derive(Clone, Default)]
+pub struct PeerListMode {
+    // Adresse statt Index, weil die Liste bei jeder Aktualisierung neu sortiert wird
+    pub selected: Option<String>,
+}
+
+impl PeerListMode {
+    /// Position des ausgewählten Peers; ohne Auswahl oder nach Trennung der erste
+    pub fn selected_index(&self, sorted_peers: &[PeerInfo]) -> usize {
+        self.selected.as_ref()
+            .and_then(|addr| sorted_peers.iter().position(|peer| &peer.addr == addr))
+            .unwrap_or(0)
+    }
+
+    pub fn select_next(&mut self, peers: &[PeerInfo]) {
+        let sorted_peers = sort_by_latency(peers);
+        let index = (self.selected_index(&sorted_peers) + 1).min(sorted_peers.len().saturating_sub(1));
+        self.selected = sorted_peers.get(index).map(|peer| peer.addr.clone());
+    }
+
+    pub fn select_previous(&mut self, peers: &[PeerInfo]) {
+        let sorted_peers = sort_by_latency(peers);
+        let index = self.selected_index(&sorted_peers).saturating_sub(1);
+        self.selected = sorted_peers.get(index).map(|peer| peer.addr.clone());
+    }
+}
+
+fn sort_by_latency(peers: &[PeerInfo]) -> Vec<PeerInfo> {
+    let mut sorted_peers = peers.to_vec();
+    sorted_peers.sort_by(|a, b| a.latency.partial_cmp(&b.latency).unwrap_or(std::cmp::Ordering::Equal));
+    sorted_peers
+}
+
+fn selected_peer_details(peer: Option<&PeerInfo>, flags: &[String]) -> Paragraph<'static> {
+    let mut content = vec![
+        Line::from(vec![
+            Span::styled("👥 Ausgewählter Peer",
+                Style::default().fg(theme().title).add_modifier(Modifier::BOLD))
+        ]),
+        Line::from(Span::styled(
+            "↑/↓: Peer auswählen | ⚠ markiert auffällige User-Agents",
+            Style::default().fg(theme().muted)
+        )),
+    ];
+
+    if let Some(peer) = peer {
+        let client = parse_user_agent(&peer.subver)
+            .map(|client| format!("{} {}", client.implementation, client.version))
+            .unwrap_or_else(|| "Unbekannt".to_string());
+        content.extend([
+            Line::from(vec![
+                Span::styled("Adresse: ", Style::default().fg(theme().label)),
+                Span::styled(
+                    format!("{} ({}, Protokoll {})", peer.addr, peer.network, peer.version),
+                    Style::default().fg(theme().value)
+                ),
+            ]),
+            Line::from(vec![
+                Span::styled("Client: ", Style::default().fg(theme().label)),
+                Span::styled(client, Style::default().fg(theme().value)),
+                Span::styled(format!(" {:?}", peer.subver), Style::default().fg(theme().muted)),
+            ]),
+            Line::from(vec![
+                Span::styled("User-Agent: ", Style::default().fg(theme().label)),
+                if flags.is_empty() {
+                    Span::styled("Unauffällig", Style::default().fg(theme().ok))
+                } else {
+                    Span::styled(flags.join("; "), Style::default().fg(theme().error))
+                },
+            ]),
+        ]);
+    }
+
+    Paragraph::new(content)
+        .block(Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme().value))
+            .title(" Peer Details "))
+}
+
+pub fn render(peers: &[PeerInfo], mode: &PeerListMode, node_version: u64) -> (Paragraph<'static>, Table<'static>, TableState) {
     // Spaltenüberschriften
     let header = Row::new(vec![
         "Adresse",
@@ -13,13 +96,22 @@ pub fn render(peers: &[PeerInfo]) -> Table<'static> {
         "Senden/Empfangen",
         "Typ",
     ])
-    .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD));
+    .style(Style::default().fg(theme().title).add_modifier(Modifier::BOLD));
 
     // Peer-Daten sortiert nach Latenz
-    let mut sorted_peers = peers.to_vec();
-    sorted_peers.sort_by(|a, b| a.latency.partial_cmp(&b.latency).unwrap_or(std::cmp::Ordering::Equal));
+    let sorted_peers = sort_by_latency(peers);
+
+    let flags: Vec<Vec<String>> = sorted_peers.iter()
+        .map(|peer| user_agent_flags(peer, node_version))
+        .collect();
+    let flagged = flags.iter().filter(|reasons| !reasons.is_empty()).count();
+    let selected = mode.selected_index(&sorted_peers);
+    let details = selected_peer_details(
+        sorted_peers.get(selected),
+        flags.get(selected).map(Vec::as_slice).unwrap_or_default()
+    );
 
-    let rows: Vec<Row> = sorted_peers.iter().map(|peer| {
+    let rows: Vec<Row> = sorted_peers.iter().zip(&flags).map(|(peer, reasons)| {
         // Zeit seit Verbindungsaufbau berechnen
         let connected_since = DateTime::<Utc>::from_timestamp(peer.connected_time as i64, 0)
             .unwrap_or_default();
@@ -38,13 +130,26 @@ pub fn render(peers: &[PeerInfo]) -> Table<'static> {
             peer.bytes_recv as f64 / 1_000_000.0
         );
 
-        Row::new(vec![
-            // Adresse (mit Tor-Markierung)
-            if peer.addr.contains(".onion") {
-                format!("🧅 {}", peer.addr)
+        // Auffällige User-Agents rot, unabhängig von der Latenz
+        let style = Style::default().fg(
+            if !reasons.is_empty() {
+                theme().error
+            } else if peer.latency < 100.0 {
+                theme().ok
+            } else if peer.latency < 500.0 {
+                theme().warn
             } else {
-                peer.addr.clone()
-            },
+                theme().error
+            }
+        );
+
+        Row::new(vec![
+            // Adresse (mit Tor- und Warnmarkierung)
+            format!("{}{}{}",
+                if reasons.is_empty() { "" } else { "⚠ " },
+                if peer.network == "onion" { "🧅 " } else { "" },
+                peer.addr
+            ),
             // Version
             peer.subver.clone(),
             // Latenz mit Farbkodierung
@@ -54,24 +159,16 @@ pub fn render(peers: &[PeerInfo]) -> Table<'static> {
             // Datenübertragung
             transfer,
             // Verbindungstyp
-            if peer.inbound { "Eingehend" } else { "Ausgehend" }.to_string(),
+            peer.connection_type.clone(),
         ])
-        .style(Style::default().fg(
-            if peer.latency < 100.0 {
-                Color::Green
-            } else if peer.latency < 500.0 {
-                Color::Yellow
-            } else {
-                Color::Red
-            }
-        ))
+        .style(style)
     }).collect();
 
-    Table::new(rows)
+    let table = Table::new(rows)
         .header(header)
         .block(Block::default()
             .borders(Borders::ALL)
-            .title(" Peer Liste "))
+            .title(format!(" Peer Liste ({}) | Auffällig: {} ", sorted_peers.len(), flagged)))
         .widths(&[
             Constraint::Percentage(30), // Adresse
             Constraint::Percentage(20), // Version
@@ -81,5 +178,12 @@ pub fn render(peers: &[PeerInfo]) -> Table<'static> {
             Constraint::Percentage(15), // Typ
         ])
         .column_spacing(1)
-        .highlight_style(Style::default().add_modifier(Modifier::BOLD))
-} 
\ No newline at end of file
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+
+    // Mit TableState scrollt die Tabelle zur Auswahl
+    let state = TableState::default().with_selected((!sorted_peers.is_empty()).then_some(selected));
+    (details, table, state)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,36 @@
+use super::*;
+
+fn peer(addr: &str, latency: f64) -> PeerInfo {
+    PeerInfo {
+        addr: addr.to_string(),
+        version: 70016,
+        subver: "/Satoshi:27.0.0/".to_string(),
+        latency,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn selection_follows_peer_after_resort() {
+    let mut mode = PeerListMode::default();
+    let peers = vec![peer("a:8333", 10.0), peer("b:8333", 20.0), peer("c:8333", 30.0)];
+    mode.select_next(&peers);
+    assert_eq!(mode.selected.as_deref(), Some("b:8333"));
+
+    // b ist jetzt der schnellste Peer und steht oben
+    let peers = vec![peer("a:8333", 10.0), peer("b:8333", 5.0), peer("c:8333", 30.0)];
+    assert_eq!(mode.selected_index(&sort_by_latency(&peers)), 0);
+    mode.select_next(&peers);
+    assert_eq!(mode.selected.as_deref(), Some("a:8333"));
+}
+
+#[test]
+fn disconnected_peer_falls_back_to_first() {
+    let mode = PeerListMode { selected: Some("gone:8333".to_string()) };
+    let peers = vec![peer("a:8333", 10.0), peer("b:8333", 20.0)];
+    assert_eq!(mode.selected_index(&peers), 0);
+
+    let mut mode = mode;
+    mode.select_previous(&[]);
+    assert_eq!(mode.selected, None);
+}
@@ -0,0 +1,212 @@
+use crate::ui::common::*;
+use crate::rpc::{BitcoinRPC, PsbtFinalization, PsbtInspection, read_psbt};
+use crate::watchlist::short_id;
+use ratatui::widgets::Wrap;
+
+#[derive(Clone, Default)]
+pub struct PsbtMode {
+    // Some(..) solange Base64 oder ein Dateipfad eingegeben wird
+    pub input: Option<String>,
+    pub psbt: Option<String>,
+    pub inspection: Option<Result<PsbtInspection, String>>,
+    pub finalization: Option<PsbtFinalization>,
+    pub scroll: u16,
+}
+
+impl PsbtMode {
+    /// Lädt eine PSBT aus Base64-Text oder Datei und analysiert sie
+    pub fn load(&mut self, client: &BitcoinRPC, input: &str) -> anyhow::Result<()> {
+        let psbt = read_psbt(input)?;
+        self.inspection = Some(client.inspect_psbt(&psbt).map_err(|e| e.to_string()));
+        self.psbt = Some(psbt);
+        self.finalization = None;
+        self.scroll = 0;
+        Ok(())
+    }
+
+    /// Finalisiert die geladene PSBT; bei `extract` wird zusätzlich der TX-Hex erzeugt
+    pub fn finalize(&mut self, client: &BitcoinRPC, extract: bool) -> anyhow::Result<bool> {
+        let psbt = self.psbt.clone()
+            .ok_or_else(|| anyhow::anyhow!("Keine PSBT geladen"))?;
+        let result = client.finalize_psbt(&psbt, extract)?;
+        let complete = result.complete;
+
+        // Die finalisierte PSBT ersetzt die geladene, damit die Analyse aktuell bleibt
+        if let Some(finalized) = &result.psbt {
+            self.inspection = Some(client.inspect_psbt(finalized).map_err(|e| e.to_string()));
+            self.psbt = Some(finalized.clone());
+        }
+        self.finalization = Some(result);
+        Ok(complete)
+    }
+
+    pub fn scroll_down(&mut self) {
+        self.scroll = self.scroll.saturating_add(1);
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.scroll = self.scroll.saturating_sub(1);
+    }
+}
+
+pub fn render(mode: &PsbtMode) -> Paragraph<'static> {
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("🧾 PSBT Inspector",
+                Style::default().fg(theme().title).add_modifier(Modifier::BOLD))
+        ]),
+    ];
+
+    match &mode.input {
+        Some(input) => lines.push(Line::from(vec![
+            Span::styled("Base64 oder Dateipfad: ", Style::default().fg(theme().label)),
+            Span::styled(format!(">{}█", input), Style::default().fg(theme().title)),
+        ])),
+        None => lines.push(Line::from(Span::styled(
+            "I: PSBT einfügen/laden | F: Finalisieren | X: TX-Hex extrahieren | ↑/↓: scrollen",
+            Style::default().fg(theme().muted)
+        ))),
+    }
+    lines.push(Line::from(""));
+
+    match &mode.inspection {
+        None => lines.push(Line::from("Keine PSBT geladen")),
+        Some(Err(e)) => lines.push(Line::from(Span::styled(
+            format!("Fehler beim Analysieren der PSBT: {}", e),
+            Style::default().fg(theme().error)
+        ))),
+        Some(Ok(inspection)) => lines.extend(inspection_lines(inspection)),
+    }
+
+    if let Some(result) = &mode.finalization {
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled("✅ Finalisierung", Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
+        ]));
+        lines.push(Line::from(vec![
+            Span::styled("Vollständig: ", Style::default().fg(theme().label)),
+            if result.complete {
+                Span::styled("Ja", Style::default().fg(theme().ok))
+            } else {
+                Span::styled("Nein - es fehlen noch Signaturen", Style::default().fg(theme().error))
+            },
+        ]));
+        if let Some(hex) = &result.hex {
+            lines.push(Line::from(Span::styled("TX-Hex:", Style::default().fg(theme().label))));
+            lines.push(Line::from(hex.clone()));
+        }
+    }
+
+    Paragraph::new(lines)
+        .wrap(Wrap { trim: false })
+        .scroll((mode.scroll, 0))
+        .block(Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme().value))
+            .title(" PSBT "))
+}
+
+fn inspection_lines(inspection: &PsbtInspection) -> Vec<Line<'static>> {
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("TXID: ", Style::default().fg(theme().label)),
+            Span::styled(inspection.txid.clone(), Style::default().fg(theme().value)),
+        ]),
+        Line::from(vec![
+            Span::styled("Nächste Rolle: ", Style::default().fg(theme().label)),
+            Span::styled(
+                describe_role(&inspection.next_role),
+                Style::default().fg(theme().title).add_modifier(Modifier::BOLD)
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("Gebühr: ", Style::default().fg(theme().label)),
+            Span::styled(
+                inspection.fee.map(|f| format!("{:.8} BTC", f)).unwrap_or_else(|| "Unbekannt".to_string()),
+                Style::default().fg(theme().value)
+            ),
+            Span::raw(" | "),
+            Span::styled("Feerate: ", Style::default().fg(theme().label)),
+            Span::styled(
+                inspection.feerate.map(|r| format!("{:.1} sat/vB", r)).unwrap_or_else(|| "-".to_string()),
+                Style::default().fg(theme().value)
+            ),
+            Span::raw(" | "),
+            Span::styled("Größe: ", Style::default().fg(theme().label)),
+            Span::styled(
+                inspection.vsize.map(|v| format!("~{} vB", v)).unwrap_or_else(|| "-".to_string()),
+                Style::default().fg(theme().value)
+            ),
+        ]),
+    ];
+
+    if let Some(error) = &inspection.error {
+        lines.push(Line::from(Span::styled(format!("⚠️ {}", error), Style::default().fg(theme().error))));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled(format!("Inputs ({}):", inspection.inputs.len()), Style::default().fg(theme().label))
+    ]));
+    for (index, input) in inspection.inputs.iter().enumerate() {
+        lines.push(Line::from(vec![
+            Span::raw(format!(" #{} ", index)),
+            Span::styled(
+                input.amount.map(|a| format!("{:.8} BTC", a)).unwrap_or_else(|| "Betrag unbekannt".to_string()),
+                Style::default().fg(theme().value)
+            ),
+            Span::raw(" von "),
+            Span::raw(input.address.clone().unwrap_or_else(|| "Unbekannte Adresse".to_string())),
+        ]));
+        lines.push(Line::from(format!("   Prevout: {}", input.prevout)));
+
+        let status = if input.is_final {
+            Span::styled("Final signiert".to_string(), Style::default().fg(theme().ok))
+        } else {
+            let mut missing = Vec::new();
+            if !input.missing_signatures.is_empty() {
+                missing.push(format!(
+                    "{} Signatur(en) [{}]",
+                    input.missing_signatures.len(),
+                    input.missing_signatures.iter().map(|k| short_id(k)).collect::<Vec<_>>().join(", ")
+                ));
+            }
+            if !input.missing_pubkeys.is_empty() {
+                missing.push(format!("{} Pubkey(s)", input.missing_pubkeys.len()));
+            }
+            missing.extend(input.missing_scripts.iter().cloned());
+
+            if missing.is_empty() {
+                Span::styled(
+                    format!("{} Signatur(en) vorhanden, weiter mit {}", input.partial_signatures, input.next_role),
+                    Style::default().fg(theme().warn)
+                )
+            } else {
+                Span::styled(format!("Fehlt: {}", missing.join(", ")), Style::default().fg(theme().error))
+            }
+        };
+        lines.push(Line::from(vec![Span::raw("   Status: "), status]));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled(format!("Outputs ({}):", inspection.outputs.len()), Style::default().fg(theme().label))
+    ]));
+    for (index, output) in inspection.outputs.iter().enumerate() {
+        lines.push(Line::from(format!(" #{} {:.8} BTC an {}", index, output.amount, output.address)));
+    }
+
+    lines
+}
+
+fn describe_role(role: &str) -> String {
+    match role {
+        "creator" => "Creator (PSBT unvollständig)".to_string(),
+        "updater" => "Updater (UTXO-/Schlüsseldaten ergänzen)".to_string(),
+        "signer" => "Signer (Signaturen fehlen)".to_string(),
+        "finalizer" => "Finalizer (bereit zum Finalisieren)".to_string(),
+        "extractor" => "Extractor (TX kann extrahiert werden)".to_string(),
+        "" => "Unbekannt".to_string(),
+        other => other.to_string(),
+    }
+}
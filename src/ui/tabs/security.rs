@@ -12,6 +12,8 @@ pub struct SecurityStatus {
     pub latest_version: u64,
     pub peers_total: u64,
     pub peers_onion: u64,
+    pub peers_i2p: u64,
+    pub peers_cjdns: u64,
     pub peers_clearnet: u64,
     pub firewall_active: bool,
     pub tor_active: bool,
@@ -27,132 +29,134 @@ pub fn render(status: &SecurityStatus) -> Paragraph<'static> {
     let content = vec![
         Line::from(vec![
             Span::styled("🔒 Sicherheitsstatus", 
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().title).add_modifier(Modifier::BOLD))
         ]),
         Line::from(""),
         Line::from(vec![
             Span::styled("📦 Software", 
-                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
         ]),
         Line::from(vec![
-            Span::styled("Version: ", Style::default().fg(Color::Cyan)),
+            Span::styled("Version: ", Style::default().fg(theme().label)),
             Span::styled(
                 format!("v{}", status.version),
                 Style::default().fg(if status.version >= status.latest_version {
-                    Color::Green
+                    theme().ok
                 } else {
-                    Color::Red
+                    theme().error
                 })
             ),
         ]),
         Line::from(""),
         Line::from(vec![
             Span::styled("🔌 Netzwerk", 
-                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
         ]),
         Line::from(vec![
-            Span::styled("Verbindungen: ", Style::default().fg(Color::Cyan)),
+            Span::styled("Verbindungen: ", Style::default().fg(theme().label)),
             Span::styled(
-                format!("{} ({} Tor / {} Direkt)", 
+                format!("{} ({} Tor / {} I2P / {} CJDNS / {} Direkt)", 
                     status.peers_total.to_formatted_string(&Locale::de),
                     status.peers_onion.to_formatted_string(&Locale::de),
+                    status.peers_i2p.to_formatted_string(&Locale::de),
+                    status.peers_cjdns.to_formatted_string(&Locale::de),
                     status.peers_clearnet.to_formatted_string(&Locale::de)
                 ),
-                Style::default().fg(if status.peers_onion > status.peers_clearnet {
-                    Color::Green
+                Style::default().fg(if status.peers_onion + status.peers_i2p + status.peers_cjdns > status.peers_clearnet {
+                    theme().ok
                 } else {
-                    Color::Yellow
+                    theme().warn
                 })
             ),
         ]),
         Line::from(vec![
-            Span::styled("Firewall: ", Style::default().fg(Color::Cyan)),
+            Span::styled("Firewall: ", Style::default().fg(theme().label)),
             Span::styled(
                 if status.firewall_active { "Aktiv" } else { "Inaktiv" },
                 Style::default().fg(if status.firewall_active {
-                    Color::Green
+                    theme().ok
                 } else {
-                    Color::Red
+                    theme().error
                 })
             ),
         ]),
         Line::from(vec![
-            Span::styled("Tor: ", Style::default().fg(Color::Cyan)),
+            Span::styled("Tor: ", Style::default().fg(theme().label)),
             Span::styled(
                 if status.tor_active { "Aktiv" } else { "Inaktiv" },
                 Style::default().fg(if status.tor_active {
-                    Color::Green
+                    theme().ok
                 } else {
-                    Color::Yellow
+                    theme().warn
                 })
             ),
         ]),
         Line::from(""),
         Line::from(vec![
             Span::styled("🔐 Zugriffskontrolle", 
-                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
         ]),
         Line::from(vec![
-            Span::styled("RPC Zugriff: ", Style::default().fg(Color::Cyan)),
+            Span::styled("RPC Zugriff: ", Style::default().fg(theme().label)),
             Span::styled(
                 if status.rpc_restricted { "Eingeschränkt" } else { "Offen" },
                 Style::default().fg(if status.rpc_restricted {
-                    Color::Green
+                    theme().ok
                 } else {
-                    Color::Red
+                    theme().error
                 })
             ),
         ]),
         Line::from(vec![
-            Span::styled("Wallet: ", Style::default().fg(Color::Cyan)),
+            Span::styled("Wallet: ", Style::default().fg(theme().label)),
             Span::styled(
                 if status.wallet_encrypted { "Verschlüsselt" } else { "Unverschlüsselt" },
                 Style::default().fg(if status.wallet_encrypted {
-                    Color::Green
+                    theme().ok
                 } else {
-                    Color::Red
+                    theme().error
                 })
             ),
         ]),
         Line::from(vec![
-            Span::styled("Festplatte: ", Style::default().fg(Color::Cyan)),
+            Span::styled("Festplatte: ", Style::default().fg(theme().label)),
             Span::styled(
                 if status.disk_encryption { "Verschlüsselt" } else { "Unverschlüsselt" },
                 Style::default().fg(if status.disk_encryption {
-                    Color::Green
+                    theme().ok
                 } else {
-                    Color::Yellow
+                    theme().warn
                 })
             ),
         ]),
         Line::from(""),
         Line::from(vec![
             Span::styled("⏰ System", 
-                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+                Style::default().fg(theme().label).add_modifier(Modifier::BOLD))
         ]),
         Line::from(vec![
-            Span::styled("Uptime: ", Style::default().fg(Color::Cyan)),
+            Span::styled("Uptime: ", Style::default().fg(theme().label)),
             Span::styled(
                 format_uptime(status.uptime),
-                Style::default().fg(Color::White)
+                Style::default().fg(theme().value)
             ),
         ]),
         Line::from(vec![
-            Span::styled("Letztes Backup: ", Style::default().fg(Color::Cyan)),
+            Span::styled("Letztes Backup: ", Style::default().fg(theme().label)),
             Span::styled(
                 match status.last_backup {
                     Some(timestamp) => format_backup_time(timestamp),
                     None => "Kein Backup gefunden".to_string()
                 },
                 Style::default().fg(match status.last_backup {
-                    Some(ts) if (chrono::Utc::now().timestamp() - ts) < 7 * 24 * 3600 => Color::Green,
-                    Some(_) => Color::Yellow,
-                    None => Color::Red
+                    Some(ts) if (chrono::Utc::now().timestamp() - ts) < 7 * 24 * 3600 => theme().ok,
+                    Some(_) => theme().warn,
+                    None => theme().error
                 })
             ),
         ]),
         Line::from(vec![
-            Span::styled("RPC Sicherheit: ", Style::default().fg(Color::Cyan)),
+            Span::styled("RPC Sicherheit: ", Style::default().fg(theme().label)),
             Span::styled(
                 format!("SSL: {}, IP: {}, Auth: {}", 
                     if status.rpc_status.ssl_enabled { "✓" } else { "✗" },
@@ -162,9 +166,9 @@ pub fn render(status: &SecurityStatus) -> Paragraph<'static> {
                 Style::default().fg(if status.rpc_status.ssl_enabled && 
                                      status.rpc_status.ip_restricted && 
                                      status.rpc_status.auth_required {
-                    Color::Green
+                    theme().ok
                 } else {
-                    Color::Red
+                    theme().error
                 })
             ),
         ]),
@@ -173,7 +177,7 @@ pub fn render(status: &SecurityStatus) -> Paragraph<'static> {
     Paragraph::new(content)
         .block(Block::default()
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::White))
+            .border_style(Style::default().fg(theme().value))
             .title(" Sicherheit "))
 }
 
@@ -0,0 +1,238 @@
+use crate::ui::common::*;
+use crate::rpc::{NetTotals, PeerInfo};
+use std::collections::{HashMap, VecDeque};
+
+// Bei 10s Intervall etwa eine Stunde Verlauf
+const TRAFFIC_WINDOW: usize = 360;
+// Peers mit dem höchsten Durchsatz in der Übersicht
+const TOP_PEERS: usize = 10;
+
+/// Durchsatz in Bytes pro Sekunde
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Throughput {
+    pub recv: f64,
+    pub sent: f64,
+}
+
+impl Throughput {
+    /// Rate aus zwei Zählerständen; ein Zurücksetzen der Zähler (Neustart, Reconnect) ergibt 0
+    fn between(previous: (u64, u64), current: (u64, u64), seconds: f64) -> Self {
+        Self {
+            recv: current.0.saturating_sub(previous.0) as f64 / seconds,
+            sent: current.1.saturating_sub(previous.1) as f64 / seconds,
+        }
+    }
+}
+
+/// Leitet aus den kumulierten Zählern von getnettotals/getpeerinfo Raten ab
+#[derive(Default)]
+pub struct TrafficMonitor {
+    latest: Option<NetTotals>,
+    rates: VecDeque<Throughput>,
+    // Zählerstände je Peer-Adresse aus der letzten Messung
+    peer_bytes: HashMap<String, (u64, u64)>,
+    peer_rates: HashMap<String, Throughput>,
+}
+
+impl TrafficMonitor {
+    pub fn record(&mut self, totals: NetTotals, peers: &[PeerInfo]) {
+        let seconds = self.latest.as_ref()
+            .map(|latest| (totals.time_millis - latest.time_millis) as f64 / 1000.0)
+            .filter(|seconds| *seconds > 0.0);
+
+        if let (Some(latest), Some(seconds)) = (&self.latest, seconds) {
+            if self.rates.len() >= TRAFFIC_WINDOW {
+                self.rates.pop_front();
+            }
+            self.rates.push_back(Throughput::between(
+                (latest.total_bytes_recv, latest.total_bytes_sent),
+                (totals.total_bytes_recv, totals.total_bytes_sent),
+                seconds,
+            ));
+
+            // Nur Peers, die bei beiden Messungen verbunden waren
+            self.peer_rates = peers.iter()
+                .filter_map(|peer| {
+                    let previous = *self.peer_bytes.get(&peer.addr)?;
+                    let rate = Throughput::between(previous, (peer.bytes_recv, peer.bytes_sent), seconds);
+                    Some((peer.addr.clone(), rate))
+                })
+                .collect();
+        }
+
+        // Gleicher Zeitstempel (doppelte Abfrage): alte Messung als Basis behalten
+        if seconds.is_some() || self.latest.is_none() {
+            self.peer_bytes = peers.iter()
+                .map(|peer| (peer.addr.clone(), (peer.bytes_recv, peer.bytes_sent)))
+                .collect();
+            self.latest = Some(totals);
+        }
+    }
+
+    pub fn latest(&self) -> Option<&NetTotals> {
+        self.latest.as_ref()
+    }
+
+    pub fn current(&self) -> Option<Throughput> {
+        self.rates.back().copied()
+    }
+
+    /// Peers nach Gesamtdurchsatz, höchster zuerst
+    pub fn top_peers(&self, limit: usize) -> Vec<(String, Throughput)> {
+        let mut peers: Vec<(String, Throughput)> = self.peer_rates.iter()
+            .map(|(addr, rate)| (addr.clone(), *rate))
+            .collect();
+        peers.sort_by(|a, b| (b.1.recv + b.1.sent).total_cmp(&(a.1.recv + a.1.sent)).then(a.0.cmp(&b.0)));
+        peers.truncate(limit);
+        peers
+    }
+}
+
+pub fn render(monitor: &TrafficMonitor) -> (Paragraph<'static>, Vec<Chart>) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme().value))
+        .title(" Traffic ");
+
+    let Some(totals) = monitor.latest() else {
+        let paragraph = Paragraph::new("Traffic-Daten werden geladen...")
+            .style(Style::default().fg(theme().warn))
+            .block(block);
+        return (paragraph, Vec::new());
+    };
+
+    let current = monitor.current();
+    let rate = |value: Option<f64>| value.map(format_rate).unwrap_or_else(|| "-".to_string());
+    let mut content = vec![
+        Line::from(vec![
+            Span::styled("📶 Durchsatz",
+                Style::default().fg(theme().title).add_modifier(Modifier::BOLD))
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Download: ", Style::default().fg(theme().label)),
+            Span::styled(rate(current.map(|c| c.recv)), Style::default().fg(theme().ok)),
+        ]),
+        Line::from(vec![
+            Span::styled("Upload: ", Style::default().fg(theme().label)),
+            Span::styled(rate(current.map(|c| c.sent)), Style::default().fg(theme().accent)),
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("📦 Seit Node-Start",
+                Style::default().fg(theme().title).add_modifier(Modifier::BOLD))
+        ]),
+        Line::from(vec![
+            Span::styled("Empfangen: ", Style::default().fg(theme().label)),
+            Span::styled(format_bytes(totals.total_bytes_recv), Style::default().fg(theme().value)),
+        ]),
+        Line::from(vec![
+            Span::styled("Gesendet: ", Style::default().fg(theme().label)),
+            Span::styled(format_bytes(totals.total_bytes_sent), Style::default().fg(theme().value)),
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("🎯 Upload-Limit",
+                Style::default().fg(theme().title).add_modifier(Modifier::BOLD))
+        ]),
+    ];
+
+    let target = &totals.upload_target;
+    if target.is_limited() {
+        content.extend([
+            Line::from(vec![
+                Span::styled("Limit: ", Style::default().fg(theme().label)),
+                Span::styled(
+                    format!("{} pro {} h", format_bytes(target.target), target.timeframe / 3600),
+                    Style::default().fg(theme().value)
+                ),
+            ]),
+            Line::from(vec![
+                Span::styled("Verbleibend: ", Style::default().fg(theme().label)),
+                Span::styled(
+                    format_bytes(target.bytes_left_in_cycle),
+                    Style::default().fg(if target.target_reached { theme().error } else { theme().value })
+                ),
+                Span::styled(
+                    format!(" (Zyklus endet in {} min)", target.time_left_in_cycle / 60),
+                    Style::default().fg(theme().muted)
+                ),
+            ]),
+            Line::from(vec![
+                Span::styled("Status: ", Style::default().fg(theme().label)),
+                if target.target_reached {
+                    Span::styled("Limit erreicht", Style::default().fg(theme().error))
+                } else {
+                    Span::styled("Im Rahmen", Style::default().fg(theme().ok))
+                },
+                Span::styled(
+                    if target.serve_historical_blocks { "" } else { " (keine historischen Blöcke)" },
+                    Style::default().fg(theme().warn)
+                ),
+            ]),
+        ]);
+    } else {
+        content.push(Line::from(vec![
+            Span::styled("Kein Limit (-maxuploadtarget)", Style::default().fg(theme().muted)),
+        ]));
+    }
+
+    content.push(Line::from(""));
+    content.push(Line::from(vec![
+        Span::styled("👥 Aktivste Peers (↓ / ↑)",
+            Style::default().fg(theme().title).add_modifier(Modifier::BOLD))
+    ]));
+    let top_peers = monitor.top_peers(TOP_PEERS);
+    if top_peers.is_empty() {
+        content.push(Line::from(Span::styled(
+            "Raten ab der zweiten Messung",
+            Style::default().fg(theme().muted)
+        )));
+    }
+    content.extend(top_peers.into_iter().map(|(addr, rate)| Line::from(vec![
+        Span::styled(format!("{}: ", addr), Style::default().fg(theme().label)),
+        Span::styled(
+            format!("{} / {}", format_rate(rate.recv), format_rate(rate.sent)),
+            Style::default().fg(theme().value)
+        ),
+    ])));
+
+    let charts = vec![
+        Chart {
+            title: format!(" Download ({}) ", rate(current.map(|c| c.recv))),
+            data: monitor.rates.iter().map(|r| r.recv as u64).collect(),
+            color: theme().ok,
+        },
+        Chart {
+            title: format!(" Upload ({}) ", rate(current.map(|c| c.sent))),
+            data: monitor.rates.iter().map(|r| r.sent as u64).collect(),
+            color: theme().accent,
+        },
+    ];
+
+    (Paragraph::new(content).block(block), charts)
+}
+
+pub fn format_rate(bytes_per_sec: f64) -> String {
+    if bytes_per_sec >= 1e6 {
+        format!("{:.2} MB/s", bytes_per_sec / 1e6)
+    } else if bytes_per_sec >= 1e3 {
+        format!("{:.1} kB/s", bytes_per_sec / 1e3)
+    } else {
+        format!("{:.0} B/s", bytes_per_sec)
+    }
+}
+
+fn format_bytes(bytes: u64) -> String {
+    let bytes = bytes as f64;
+    if bytes >= 1e9 {
+        format!("{:.2} GB", bytes / 1e9)
+    } else if bytes >= 1e6 {
+        format!("{:.1} MB", bytes / 1e6)
+    } else {
+        format!("{:.0} kB", bytes / 1e3)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,74 @@
+use super::*;
+use crate::rpc::NetTotals;
+use serde_json::json;
+
+fn totals(recv: u64, sent: u64, time_millis: i64) -> NetTotals {
+    NetTotals::from_value(&json!({
+        "totalbytesrecv": recv,
+        "totalbytessent": sent,
+        "timemillis": time_millis,
+        "uploadtarget": { "target": 0 }
+    }))
+}
+
+fn peer(addr: &str, bytes_recv: u64, bytes_sent: u64) -> PeerInfo {
+    PeerInfo {
+        addr: addr.to_string(),
+        version: 70016,
+        subver: "/Satoshi:27.0.0/".to_string(),
+        latency: 0.05,
+        bytes_sent,
+        bytes_recv,
+        inbound: false,
+        connected_time: 0,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn first_sample_has_no_rate() {
+    let mut monitor = TrafficMonitor::default();
+    monitor.record(totals(1_000, 2_000, 0), &[peer("a:8333", 10, 10)]);
+
+    assert!(monitor.latest().is_some());
+    assert!(monitor.current().is_none());
+    assert!(monitor.top_peers(10).is_empty());
+}
+
+#[test]
+fn rates_from_byte_deltas() {
+    let mut monitor = TrafficMonitor::default();
+    monitor.record(totals(1_000, 2_000, 0), &[peer("a:8333", 0, 0), peer("b:8333", 0, 0)]);
+    monitor.record(
+        totals(21_000, 7_000, 10_000),
+        &[peer("a:8333", 1_000, 500), peer("b:8333", 10_000, 0), peer("c:8333", 50_000, 0)],
+    );
+
+    assert_eq!(monitor.current(), Some(Throughput { recv: 2_000.0, sent: 500.0 }));
+
+    // Neuer Peer c hat noch keinen Vergleichswert
+    let top = monitor.top_peers(10);
+    assert_eq!(top.len(), 2);
+    assert_eq!(top[0], ("b:8333".to_string(), Throughput { recv: 1_000.0, sent: 0.0 }));
+    assert_eq!(top[1].1, Throughput { recv: 100.0, sent: 50.0 });
+}
+
+#[test]
+fn counter_reset_and_duplicate_samples() {
+    let mut monitor = TrafficMonitor::default();
+    monitor.record(totals(50_000, 50_000, 0), &[]);
+    // Gleicher Zeitstempel wird ignoriert
+    monitor.record(totals(60_000, 60_000, 0), &[]);
+    assert!(monitor.current().is_none());
+
+    // Node-Neustart setzt die Zähler zurück
+    monitor.record(totals(1_000, 1_000, 5_000), &[]);
+    assert_eq!(monitor.current(), Some(Throughput { recv: 0.0, sent: 0.0 }));
+}
+
+#[test]
+fn rate_formatting() {
+    assert_eq!(format_rate(512.0), "512 B/s");
+    assert_eq!(format_rate(12_345.0), "12.3 kB/s");
+    assert_eq!(format_rate(2_500_000.0), "2.50 MB/s");
+}
@@ -1,39 +1,101 @@
 use super::super::common::*;
-use crate::rpc::BitcoinRPC;
+use crate::rpc::{BitcoinRPC, MempoolAcceptResult, Transaction};
 use chrono::{DateTime, Utc};
+use ratatui::widgets::Wrap;
 
 #[derive(Clone)]
 pub struct TxMode {
     pub txid: String,
+    // Gesetzt, wenn die TX als Hex eingefügt wurde statt per TXID
+    pub raw_hex: Option<String>,
+    // Einmal beim Laden dekodiert, nicht bei jedem Frame
+    pub decoded: Option<Transaction>,
+    pub mempool_accept: Option<Result<MempoolAcceptResult, String>>,
+    // Gesetzt, solange der Bestätigungsdialog zum Senden offen ist
+    pub confirm_broadcast: Option<BroadcastConfirm>,
+}
+
+#[derive(Clone)]
+pub struct BroadcastConfirm {
+    // (Adresse, Betrag in BTC)
+    pub outputs: Vec<(String, f64)>,
+    pub fee: Option<f64>,
+    pub feerate: Option<f64>,
+    pub max_feerate: f64,
 }
 
 impl TxMode {
     pub fn new(txid: String) -> Self {
         Self {
             txid,
+            raw_hex: None,
+            decoded: None,
+            mempool_accept: None,
+            confirm_broadcast: None,
+        }
+    }
+
+    pub fn from_raw(hex: String, tx: Transaction) -> Self {
+        Self {
+            txid: tx.txid.clone(),
+            raw_hex: Some(hex),
+            decoded: Some(tx),
+            mempool_accept: None,
+            confirm_broadcast: None,
         }
     }
 }
 
-pub fn render(mode: Option<&TxMode>, rpc_client: &Option<BitcoinRPC>) -> Paragraph<'static> {
+pub fn render(mode: Option<&TxMode>, input: Option<&str>, rpc_client: &Option<BitcoinRPC>) -> Paragraph<'static> {
+    // Eingabe einer Roh-Transaktion ersetzt die Anzeige
+    if let Some(input) = input {
+        return Paragraph::new(vec![
+            Line::from(vec![
+                Span::styled("Roh-Transaktion laden", Style::default().fg(theme().title))
+            ]),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled("Hex oder Dateipfad: ", Style::default().fg(theme().label)),
+                Span::styled(format!(">{}█", input), Style::default().fg(theme().title)),
+            ]),
+            Line::from(""),
+            Line::from(Span::styled("Enter: Dekodieren | Esc: Abbrechen", Style::default().fg(theme().muted))),
+        ])
+        .wrap(Wrap { trim: false })
+        .block(Block::default()
+            .borders(Borders::ALL)
+            .title(" Transaction Details "));
+    }
+
     match (mode, rpc_client) {
         (Some(tx_mode), Some(client)) => {
-            match client.get_raw_transaction(&tx_mode.txid) {
+            let result = match &tx_mode.decoded {
+                Some(tx) => Ok(tx.clone()),
+                None => client.get_raw_transaction(&tx_mode.txid),
+            };
+            match result {
                 Ok(tx) => {
                     let mut lines = vec![
                         Line::from(vec![
                             Span::styled("Transaction Details", 
-                                Style::default().fg(Color::Yellow))
+                                Style::default().fg(theme().title))
                         ]),
                         Line::from(""),
                     ];
 
+                    if tx_mode.raw_hex.is_some() {
+                        lines.insert(1, Line::from(Span::styled(
+                            "Dekodierte Roh-Transaktion (nicht gesendet) | T: testmempoolaccept | S: Senden",
+                            Style::default().fg(theme().muted)
+                        )));
+                    }
+
                     // TXID
                     lines.push(Line::from(vec![
                         Span::raw("TXID: "),
                         Span::styled(
                             tx_mode.txid.clone(),
-                            Style::default().fg(Color::White)
+                            Style::default().fg(theme().value)
                         )
                     ]));
 
@@ -48,7 +110,7 @@ pub fn render(mode: Option<&TxMode>, rpc_client: &Option<BitcoinRPC>) -> Paragra
                         lines.push(Line::from(""));
                         lines.push(Line::from(vec![
                             Span::styled("🌟 Coinbase Transaktion (Block Belohnung + Gebühren)", 
-                                Style::default().fg(Color::Yellow))
+                                Style::default().fg(theme().title))
                         ]));
                     }
 
@@ -56,7 +118,7 @@ pub fn render(mode: Option<&TxMode>, rpc_client: &Option<BitcoinRPC>) -> Paragra
                     if !tx.vin.is_empty() {
                         lines.push(Line::from(""));
                         lines.push(Line::from(vec![
-                            Span::styled("Inputs:", Style::default().fg(Color::Cyan))
+                            Span::styled("Inputs:", Style::default().fg(theme().label))
                         ]));
                         if is_coinbase {
                             lines.push(Line::from(" • Neue Bitcoins (Block Belohnung)"));
@@ -95,7 +157,7 @@ pub fn render(mode: Option<&TxMode>, rpc_client: &Option<BitcoinRPC>) -> Paragra
                     if !tx.vout.is_empty() {
                         lines.push(Line::from(""));
                         lines.push(Line::from(vec![
-                            Span::styled("Outputs:", Style::default().fg(Color::Cyan))
+                            Span::styled("Outputs:", Style::default().fg(theme().label))
                         ]));
                         for (index, output) in tx.vout.iter().enumerate() {
                             let value = output.get("value").cloned();
@@ -141,42 +203,47 @@ pub fn render(mode: Option<&TxMode>, rpc_client: &Option<BitcoinRPC>) -> Paragra
                     lines.extend_from_slice(&[
                         Line::from(""),
                         Line::from(vec![
-                            Span::styled("Details:", Style::default().fg(Color::Cyan))
+                            Span::styled("Details:", Style::default().fg(theme().label))
                         ]),
                         Line::from(vec![
-                            Span::styled("Größe: ", Style::default().fg(Color::Cyan)),
+                            Span::styled("Größe: ", Style::default().fg(theme().label)),
                             Span::styled(
                                 format!("{} bytes", tx.size),
-                                Style::default().fg(Color::White)
+                                Style::default().fg(theme().value)
                             )
                         ]),
                         Line::from(vec![
-                            Span::styled("Virtuelle Größe: ", Style::default().fg(Color::Cyan)),
+                            Span::styled("Virtuelle Größe: ", Style::default().fg(theme().label)),
                             Span::styled(
-                                format!("{} vbytes", tx.size),
-                                Style::default().fg(Color::White)
+                                format!("{} vbytes", tx.weight.div_ceil(4)),
+                                Style::default().fg(theme().value)
                             )
                         ]),
                         Line::from(vec![
-                            Span::styled("Gewicht: ", Style::default().fg(Color::Cyan)),
+                            Span::styled("Gewicht: ", Style::default().fg(theme().label)),
                             Span::styled(
                                 format!("{} WU", tx.weight),
-                                Style::default().fg(Color::White)
+                                Style::default().fg(theme().value)
                             )
                         ]),
                         Line::from(vec![
-                            Span::styled("Zeit: ", Style::default().fg(Color::Cyan)),
-                            Span::styled(dt, Style::default().fg(Color::White)),
+                            Span::styled("Zeit: ", Style::default().fg(theme().label)),
+                            Span::styled(dt, Style::default().fg(theme().value)),
                         ]),
                         Line::from(vec![
-                            Span::styled("Block: ", Style::default().fg(Color::Cyan)),
+                            Span::styled("Block: ", Style::default().fg(theme().label)),
                             Span::styled(
                                 tx.blockhash.unwrap_or_else(|| "Noch nicht bestätigt".to_string()),
-                                Style::default().fg(Color::White)
+                                Style::default().fg(theme().value)
                             ),
                         ]),
                     ]);
 
+                    if let Some(accept) = &tx_mode.mempool_accept {
+                        lines.push(Line::from(""));
+                        lines.extend(mempool_accept_lines(accept));
+                    }
+
                     Paragraph::new(lines)
                 },
                 Err(e) => Paragraph::new(format!("Fehler beim Laden der Transaktion: {}", e))
@@ -189,6 +256,116 @@ pub fn render(mode: Option<&TxMode>, rpc_client: &Option<BitcoinRPC>) -> Paragra
         .title(" Transaction Details "))
 }
 
+/// Bestätigungsdialog vor sendrawtransaction
+pub fn render_broadcast_dialog(confirm: &BroadcastConfirm) -> Paragraph<'static> {
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("⚠️ Transaktion wirklich senden?",
+                Style::default().fg(theme().title).add_modifier(Modifier::BOLD))
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Outputs:", Style::default().fg(theme().label))
+        ]),
+    ];
+
+    for (address, amount) in &confirm.outputs {
+        lines.push(Line::from(format!(" • {:.8} BTC → {}", amount, address)));
+    }
+
+    lines.extend_from_slice(&[
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Gebühr: ", Style::default().fg(theme().label)),
+            Span::styled(
+                confirm.fee.map(|f| format!("{:.8} BTC", f)).unwrap_or_else(|| "Unbekannt".to_string()),
+                Style::default().fg(theme().value)
+            ),
+            Span::raw(" | "),
+            Span::styled("Feerate: ", Style::default().fg(theme().label)),
+            Span::styled(
+                confirm.feerate.map(|r| format!("{:.2} sat/vB", r)).unwrap_or_else(|| "-".to_string()),
+                Style::default().fg(theme().value)
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("Maximal erlaubt: ", Style::default().fg(theme().label)),
+            Span::styled(format!("{:.2} sat/vB", confirm.max_feerate), Style::default().fg(theme().value)),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(
+            "Das Senden kann nicht rückgängig gemacht werden!",
+            Style::default().fg(theme().error)
+        )),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("J", Style::default().fg(theme().ok).add_modifier(Modifier::BOLD)),
+            Span::raw(": Senden | "),
+            Span::styled("N/Esc", Style::default().fg(theme().error).add_modifier(Modifier::BOLD)),
+            Span::raw(": Abbrechen"),
+        ]),
+    ]);
+
+    Paragraph::new(lines)
+        .wrap(Wrap { trim: false })
+        .block(Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme().warn))
+            .title(" Senden bestätigen "))
+}
+
+fn mempool_accept_lines(accept: &Result<MempoolAcceptResult, String>) -> Vec<Line<'static>> {
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("Policy-Check (testmempoolaccept):", Style::default().fg(theme().label))
+        ]),
+    ];
+
+    match accept {
+        Ok(result) => {
+            lines.push(Line::from(vec![
+                Span::styled("Ergebnis: ", Style::default().fg(theme().label)),
+                if result.allowed {
+                    Span::styled("Akzeptiert ✓", Style::default().fg(theme().ok))
+                } else {
+                    Span::styled("Abgelehnt ✗", Style::default().fg(theme().error))
+                },
+            ]));
+            if let Some(reason) = &result.reject_reason {
+                lines.push(Line::from(vec![
+                    Span::styled("Grund: ", Style::default().fg(theme().label)),
+                    Span::styled(reason.clone(), Style::default().fg(theme().error)),
+                ]));
+            }
+            lines.push(Line::from(vec![
+                Span::styled("Effektive Feerate: ", Style::default().fg(theme().label)),
+                Span::styled(
+                    result.effective_feerate.map(|r| format!("{:.2} sat/vB", r)).unwrap_or_else(|| "-".to_string()),
+                    Style::default().fg(theme().value)
+                ),
+                Span::raw(" | "),
+                Span::styled("Gebühr: ", Style::default().fg(theme().label)),
+                Span::styled(
+                    result.fee.map(|f| format!("{:.8} BTC", f)).unwrap_or_else(|| "-".to_string()),
+                    Style::default().fg(theme().value)
+                ),
+                Span::raw(" | "),
+                Span::styled("vsize: ", Style::default().fg(theme().label)),
+                Span::styled(
+                    result.vsize.map(|v| format!("{} vB", v)).unwrap_or_else(|| "-".to_string()),
+                    Style::default().fg(theme().value)
+                ),
+            ]));
+        }
+        Err(e) => lines.push(Line::from(Span::styled(
+            format!("Prüfung fehlgeschlagen: {}", e),
+            Style::default().fg(theme().error)
+        ))),
+    }
+
+    lines
+}
+
 #[allow(dead_code)]
 pub fn some_unused_function() {
     // Funktionaler Code hier
@@ -0,0 +1,198 @@
+use crate::ui::common::*;
+use crate::rpc::{BitcoinRPC, WalletTransaction};
+use num_format::{Locale, ToFormattedString};
+use ratatui::widgets::{Table, Row};
+
+#[derive(Clone, Default)]
+pub struct WalletMode {
+    pub selected: usize,
+    pub scroll: usize,
+}
+
+impl WalletMode {
+    pub fn next_wallet(&mut self, wallet_count: usize) {
+        if wallet_count > 0 {
+            self.selected = (self.selected + 1) % wallet_count;
+            self.scroll = 0;
+        }
+    }
+
+    pub fn previous_wallet(&mut self, wallet_count: usize) {
+        if wallet_count > 0 {
+            self.selected = (self.selected + wallet_count - 1) % wallet_count;
+            self.scroll = 0;
+        }
+    }
+
+    pub fn scroll_down(&mut self, item_count: usize) {
+        if self.scroll + 1 < item_count {
+            self.scroll += 1;
+        }
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.scroll = self.scroll.saturating_sub(1);
+    }
+}
+
+pub fn render(mode: &WalletMode, rpc_client: &Option<BitcoinRPC>) -> (Paragraph<'static>, Table<'static>) {
+    let client = match rpc_client {
+        Some(client) => client,
+        None => return error_view("Keine Verbindung zum Bitcoin Node".to_string()),
+    };
+
+    let wallets = match client.list_wallets() {
+        Ok(wallets) => wallets,
+        Err(e) => return error_view(format!("Wallets konnten nicht geladen werden: {}", e)),
+    };
+
+    if wallets.is_empty() {
+        return error_view("Keine Wallet geladen (bitcoin-cli loadwallet <name>)".to_string());
+    }
+
+    let selected = mode.selected % wallets.len();
+    let overview = match client.get_wallet_overview(&wallets[selected]) {
+        Ok(overview) => overview,
+        Err(e) => return error_view(format!("Wallet '{}' konnte nicht geladen werden: {}", wallets[selected], e)),
+    };
+
+    // Wallet-Auswahl
+    let mut selector = vec![Span::styled("Wallets: ", Style::default().fg(theme().label))];
+    for (index, name) in wallets.iter().enumerate() {
+        let display = if name.is_empty() { "<default>".to_string() } else { name.clone() };
+        if index == selected {
+            selector.push(Span::styled(
+                format!("[{}]", display),
+                Style::default().fg(theme().title).add_modifier(Modifier::BOLD),
+            ));
+        } else {
+            selector.push(Span::styled(display, Style::default().fg(theme().value)));
+        }
+        selector.push(Span::raw(" "));
+    }
+    selector.push(Span::styled("(←/→ wechseln, ↑/↓ scrollen)", Style::default().fg(theme().muted)));
+
+    let info = &overview.info;
+    let balances = &overview.balances;
+
+    let content = vec![
+        Line::from(vec![
+            Span::styled("👛 Wallet Übersicht",
+                Style::default().fg(theme().title).add_modifier(Modifier::BOLD))
+        ]),
+        Line::from(selector),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Verfügbar: ", Style::default().fg(theme().label)),
+            Span::styled(format!("{:.8} BTC", balances.trusted), Style::default().fg(theme().ok)),
+            Span::raw(" | "),
+            Span::styled("Ausstehend: ", Style::default().fg(theme().label)),
+            Span::styled(format!("{:.8} BTC", balances.untrusted_pending), Style::default().fg(theme().warn)),
+            Span::raw(" | "),
+            Span::styled("Unreif: ", Style::default().fg(theme().label)),
+            Span::styled(format!("{:.8} BTC", balances.immature), Style::default().fg(theme().muted)),
+        ]),
+        Line::from(vec![
+            Span::styled("Gesamt: ", Style::default().fg(theme().label)),
+            Span::styled(
+                format!("{:.8} BTC", balances.total()),
+                Style::default().fg(theme().value).add_modifier(Modifier::BOLD)
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("Transaktionen: ", Style::default().fg(theme().label)),
+            Span::styled(info.tx_count.to_formatted_string(&Locale::de), Style::default().fg(theme().value)),
+            Span::raw(" | "),
+            Span::styled("Typ: ", Style::default().fg(theme().label)),
+            Span::styled(
+                if info.descriptors { "Descriptor" } else { "Legacy" },
+                Style::default().fg(theme().value)
+            ),
+            Span::raw(" | "),
+            Span::styled("Schlüssel: ", Style::default().fg(theme().label)),
+            if info.private_keys_enabled {
+                Span::styled(format!("Privat (Keypool {})", info.keypool_size), Style::default().fg(theme().value))
+            } else {
+                Span::styled("Watch-only", Style::default().fg(theme().warn))
+            },
+            if info.scanning {
+                Span::styled(" | Rescan läuft...", Style::default().fg(theme().warn))
+            } else {
+                Span::raw("")
+            },
+        ]),
+    ];
+
+    let header = Paragraph::new(content)
+        .block(Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme().value))
+            .title(format!(" Wallet: {} ", info.name)));
+
+    (header, transaction_table(&overview.transactions, mode.scroll))
+}
+
+fn transaction_table(transactions: &[WalletTransaction], scroll: usize) -> Table<'static> {
+    let header = Row::new(vec![
+        "Zeit",
+        "Kategorie",
+        "Betrag",
+        "Bestätigungen",
+        "Label",
+        "TXID",
+    ])
+    .style(Style::default().fg(theme().title).add_modifier(Modifier::BOLD));
+
+    let rows: Vec<Row> = transactions.iter().skip(scroll).map(|tx| {
+        let time = DateTime::<Utc>::from_timestamp(tx.time, 0)
+            .unwrap_or_default()
+            .format("%Y-%m-%d %H:%M")
+            .to_string();
+
+        let color = match tx.category.as_str() {
+            "receive" => theme().ok,
+            "send" => theme().error,
+            "generate" => theme().label,
+            "immature" | "orphan" => theme().muted,
+            _ => theme().value,
+        };
+
+        Row::new(vec![
+            time,
+            tx.category.clone(),
+            format!("{:+.8}", tx.amount),
+            if tx.confirmations < 0 {
+                "Konflikt".to_string()
+            } else {
+                tx.confirmations.to_formatted_string(&Locale::de)
+            },
+            tx.label.clone(),
+            tx.txid.clone(),
+        ])
+        .style(Style::default().fg(if tx.confirmations == 0 { theme().warn } else { color }))
+    }).collect();
+
+    Table::new(rows)
+        .header(header)
+        .block(Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" Letzte Transaktionen ({}) ", transactions.len())))
+        .widths(&[
+            Constraint::Length(16), // Zeit
+            Constraint::Length(10), // Kategorie
+            Constraint::Length(14), // Betrag
+            Constraint::Length(13), // Bestätigungen
+            Constraint::Percentage(20), // Label
+            Constraint::Min(20),    // TXID
+        ])
+        .column_spacing(1)
+}
+
+fn error_view(message: String) -> (Paragraph<'static>, Table<'static>) {
+    let paragraph = Paragraph::new(message)
+        .style(Style::default().fg(theme().error))
+        .block(Block::default()
+            .borders(Borders::ALL)
+            .title(" Wallet "));
+    (paragraph, transaction_table(&[], 0))
+}
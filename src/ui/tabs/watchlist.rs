@@ -0,0 +1,115 @@
+use crate::ui::common::*;
+use crate::rpc::TxStatus;
+use crate::watchlist::{WatchEntry, WatchTarget, Watchlist};
+use num_format::{Locale, ToFormattedString};
+use ratatui::widgets::{Table, Row};
+
+#[derive(Clone, Default)]
+pub struct WatchlistMode {
+    pub selected: usize,
+    // Some(..) solange eine neue TXID/Adresse eingegeben wird
+    pub input: Option<String>,
+}
+
+impl WatchlistMode {
+    pub fn select_next(&mut self, entry_count: usize) {
+        if self.selected + 1 < entry_count {
+            self.selected += 1;
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+}
+
+pub fn render(watchlist: &Watchlist, mode: &WatchlistMode) -> (Paragraph<'static>, Table<'static>) {
+    let input_line = match &mode.input {
+        Some(input) => Line::from(vec![
+            Span::styled("➕ TXID oder Adresse: ", Style::default().fg(theme().label)),
+            Span::styled(format!(">{}█", input), Style::default().fg(theme().title)),
+            Span::styled("  (Enter: hinzufügen, Esc: abbrechen)", Style::default().fg(theme().muted)),
+        ]),
+        None => Line::from(vec![
+            Span::styled(
+                "A: hinzufügen | D: entfernen | Enter: Details öffnen | ↑/↓: auswählen",
+                Style::default().fg(theme().muted)
+            ),
+        ]),
+    };
+
+    let content = vec![
+        Line::from(vec![
+            Span::styled("👁 Beobachtete Transaktionen & Adressen",
+                Style::default().fg(theme().title).add_modifier(Modifier::BOLD))
+        ]),
+        Line::from("Wird bei jeder Aktualisierung geprüft (R)."),
+        input_line,
+    ];
+
+    let header = Paragraph::new(content)
+        .block(Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme().value))
+            .title(" Watchlist "));
+
+    let table_header = Row::new(vec!["Typ", "ID", "Status", "Bestätigungen / Guthaben"])
+        .style(Style::default().fg(theme().title).add_modifier(Modifier::BOLD));
+
+    let rows: Vec<Row> = watchlist.entries().iter().enumerate().map(|(index, entry)| {
+        let (status, value, color) = describe_entry(entry);
+        let kind = match entry.target {
+            WatchTarget::Transaction(_) => "TX",
+            WatchTarget::Address(_) => "Adresse",
+        };
+
+        let mut style = Style::default().fg(color);
+        if index == mode.selected {
+            style = style.add_modifier(Modifier::REVERSED);
+        }
+
+        Row::new(vec![kind.to_string(), entry.target.id().to_string(), status, value]).style(style)
+    }).collect();
+
+    let table = Table::new(rows)
+        .header(table_header)
+        .block(Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" Einträge ({}) ", watchlist.entries().len())))
+        .widths(&[
+            Constraint::Length(8),  // Typ
+            Constraint::Min(40),    // ID
+            Constraint::Length(22), // Status
+            Constraint::Length(26), // Wert
+        ])
+        .column_spacing(1);
+
+    (header, table)
+}
+
+fn describe_entry(entry: &WatchEntry) -> (String, String, Color) {
+    if let Some(error) = &entry.last_error {
+        return (format!("Fehler: {}", error), "-".to_string(), theme().error);
+    }
+
+    match &entry.target {
+        WatchTarget::Transaction(_) => match &entry.tx_status {
+            None => ("Noch nicht geprüft".to_string(), "-".to_string(), theme().muted),
+            Some(TxStatus::Unconfirmed) => ("Im Mempool".to_string(), "0".to_string(), theme().warn),
+            Some(TxStatus::Confirmed(n)) => (
+                "Bestätigt".to_string(),
+                n.to_formatted_string(&Locale::de),
+                if *n >= 6 { theme().ok } else { theme().label },
+            ),
+            Some(TxStatus::Missing) => ("Nicht gefunden / ersetzt".to_string(), "-".to_string(), theme().error),
+        },
+        WatchTarget::Address(_) => match entry.balance {
+            None => ("Noch nicht geprüft".to_string(), "-".to_string(), theme().muted),
+            Some(balance) => (
+                "Aktiv".to_string(),
+                format!("{:.8} BTC", balance),
+                if balance > 0.0 { theme().ok } else { theme().value },
+            ),
+        },
+    }
+}
@@ -0,0 +1,156 @@
+// Farbschema der Oberfläche mit semantischen Rollen statt fester Farben
+use anyhow::Result;
+use once_cell::sync::Lazy;
+use ratatui::style::Color;
+use serde::Deserialize;
+use std::env;
+use std::fs;
+use std::str::FromStr;
+
+const DEFAULT_THEME_FILE: &str = "theme.json";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Theme {
+    pub name: String,
+    // Überschriften, Tabellenköpfe und Tastenhinweise
+    pub title: Color,
+    pub label: Color,
+    pub value: Color,
+    pub ok: Color,
+    pub warn: Color,
+    pub error: Color,
+    // Hinweise und Nebensächliches
+    pub muted: Color,
+    // Hervorhebungen ohne Bewertung, z.B. zweite Chart-Reihe
+    pub accent: Color,
+    // Hintergrund von Dialogen
+    pub background: Color,
+}
+
+impl Theme {
+    pub fn dark() -> Self {
+        Self {
+            name: "dark".to_string(),
+            title: Color::Yellow,
+            label: Color::Cyan,
+            value: Color::White,
+            ok: Color::Green,
+            warn: Color::Yellow,
+            error: Color::Red,
+            muted: Color::DarkGray,
+            accent: Color::Blue,
+            background: Color::Black,
+        }
+    }
+
+    /// Für helle Terminals: dunkle, gesättigte Farben statt Gelb/Cyan auf Weiß
+    pub fn light() -> Self {
+        Self {
+            name: "light".to_string(),
+            title: Color::Rgb(0x87, 0x00, 0x87),
+            label: Color::Rgb(0x00, 0x5f, 0x87),
+            value: Color::Black,
+            ok: Color::Rgb(0x00, 0x6e, 0x00),
+            warn: Color::Rgb(0xaf, 0x5f, 0x00),
+            error: Color::Rgb(0xc0, 0x00, 0x00),
+            muted: Color::Rgb(0x6c, 0x6c, 0x6c),
+            accent: Color::Rgb(0x00, 0x5f, 0xd7),
+            background: Color::White,
+        }
+    }
+
+    pub fn high_contrast() -> Self {
+        Self {
+            name: "high-contrast".to_string(),
+            title: Color::LightYellow,
+            label: Color::LightCyan,
+            value: Color::White,
+            ok: Color::LightGreen,
+            warn: Color::Rgb(0xff, 0xaf, 0x00),
+            error: Color::LightRed,
+            muted: Color::Gray,
+            accent: Color::LightMagenta,
+            background: Color::Black,
+        }
+    }
+
+    pub fn builtin(name: &str) -> Option<Self> {
+        match name {
+            "dark" => Some(Self::dark()),
+            "light" => Some(Self::light()),
+            "high-contrast" => Some(Self::high_contrast()),
+            _ => None,
+        }
+    }
+
+    /// Eigenes Theme: "base" wählt ein eingebautes Theme, alle anderen Rollen überschreiben es
+    pub fn parse(json: &str, default_base: &str) -> Result<Self> {
+        let file: ThemeFile = serde_json::from_str(json)?;
+        let base = file.base.as_deref().unwrap_or(default_base);
+        let mut theme = Self::builtin(base)
+            .ok_or_else(|| anyhow::anyhow!("Unbekanntes Basis-Theme: {}", base))?;
+        theme.name = file.name.unwrap_or_else(|| format!("{} (angepasst)", theme.name));
+
+        for (role, color) in [
+            (&mut theme.title, file.title),
+            (&mut theme.label, file.label),
+            (&mut theme.value, file.value),
+            (&mut theme.ok, file.ok),
+            (&mut theme.warn, file.warn),
+            (&mut theme.error, file.error),
+            (&mut theme.muted, file.muted),
+            (&mut theme.accent, file.accent),
+            (&mut theme.background, file.background),
+        ] {
+            if let Some(color) = color {
+                // Namen wie "lightblue", Indizes wie "208" oder "#rrggbb"
+                *role = Color::from_str(&color)
+                    .map_err(|_| anyhow::anyhow!("Ungültige Farbe: {}", color))?;
+            }
+        }
+        Ok(theme)
+    }
+
+    /// THEME (dark, light, high-contrast) und optional THEME_FILE (Standard: theme.json)
+    pub fn load() -> Result<Self> {
+        let name = env::var("THEME").unwrap_or_else(|_| "dark".to_string());
+        let path = env::var("THEME_FILE").unwrap_or_else(|_| DEFAULT_THEME_FILE.to_string());
+        match fs::read_to_string(&path) {
+            Ok(content) => Self::parse(&content, &name)
+                .map_err(|e| anyhow::anyhow!("{} ist ungültig: {}", path, e)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Self::builtin(&name)
+                .ok_or_else(|| anyhow::anyhow!("Unbekanntes Theme: {}", name)),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ThemeFile {
+    name: Option<String>,
+    base: Option<String>,
+    title: Option<String>,
+    label: Option<String>,
+    value: Option<String>,
+    ok: Option<String>,
+    warn: Option<String>,
+    error: Option<String>,
+    muted: Option<String>,
+    accent: Option<String>,
+    background: Option<String>,
+}
+
+static THEME: Lazy<Theme> = Lazy::new(|| {
+    Theme::load().unwrap_or_else(|e| {
+        log::warn!("Theme konnte nicht geladen werden: {}", e);
+        Theme::dark()
+    })
+});
+
+/// Einmal geladenes Theme für den gesamten Prozess
+pub fn theme() -> &'static Theme {
+    &THEME
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,42 @@
+use super::*;
+
+#[test]
+fn builtin_themes_by_name() {
+    assert_eq!(Theme::builtin("dark"), Some(Theme::dark()));
+    assert_eq!(Theme::builtin("light").unwrap().value, Color::Black);
+    assert_eq!(Theme::builtin("high-contrast").unwrap().name, "high-contrast");
+    assert!(Theme::builtin("solarized").is_none());
+}
+
+#[test]
+fn user_theme_overrides_base() {
+    let theme = Theme::parse(r##"{
+        "name": "Büro",
+        "base": "light",
+        "title": "magenta",
+        "label": "#005f87",
+        "muted": "244"
+    }"##, "dark").unwrap();
+
+    assert_eq!(theme.name, "Büro");
+    assert_eq!(theme.title, Color::Magenta);
+    assert_eq!(theme.label, Color::Rgb(0x00, 0x5f, 0x87));
+    assert_eq!(theme.muted, Color::Indexed(244));
+    // Nicht gesetzte Rollen kommen aus dem Basis-Theme
+    assert_eq!(theme.value, Theme::light().value);
+}
+
+#[test]
+fn user_theme_without_base_uses_default() {
+    let theme = Theme::parse(r#"{ "ok": "lightgreen" }"#, "high-contrast").unwrap();
+    assert_eq!(theme.name, "high-contrast (angepasst)");
+    assert_eq!(theme.ok, Color::LightGreen);
+    assert_eq!(theme.error, Theme::high_contrast().error);
+}
+
+#[test]
+fn rejects_invalid_themes() {
+    assert!(Theme::parse(r#"{ "title": "kein-farbname" }"#, "dark").is_err());
+    assert!(Theme::parse(r#"{ "base": "solarized" }"#, "dark").is_err());
+    assert!(Theme::parse("kein json", "dark").is_err());
+}
@@ -0,0 +1,225 @@
+// Beobachtungsliste für Transaktionen und Adressen
+use anyhow::Result;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use crate::rpc::{BitcoinRPC, TxStatus};
+
+const DEFAULT_WATCHLIST_FILE: &str = "watchlist.txt";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum WatchTarget {
+    Transaction(String),
+    Address(String),
+}
+
+impl WatchTarget {
+    /// Erkennt TXIDs (64 Hex-Zeichen), alles andere wird als Adresse behandelt
+    pub fn parse(input: &str) -> Option<Self> {
+        let input = input.trim();
+        if input.is_empty() || input.contains(char::is_whitespace) {
+            return None;
+        }
+
+        if input.len() == 64 && input.chars().all(|c| c.is_ascii_hexdigit()) {
+            Some(WatchTarget::Transaction(input.to_lowercase()))
+        } else {
+            Some(WatchTarget::Address(input.to_string()))
+        }
+    }
+
+    pub fn id(&self) -> &str {
+        match self {
+            WatchTarget::Transaction(txid) => txid,
+            WatchTarget::Address(address) => address,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct WatchEntry {
+    pub target: WatchTarget,
+    pub tx_status: Option<TxStatus>,
+    pub received: Option<f64>,
+    pub balance: Option<f64>,
+    pub last_error: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum WatchEvent {
+    Confirmed { txid: String, confirmations: u64 },
+    Replaced { txid: String },
+    Received { address: String, amount: f64 },
+    BalanceChanged { address: String, balance: f64 },
+}
+
+impl WatchEvent {
+    pub fn message(&self) -> String {
+        match self {
+            WatchEvent::Confirmed { txid, confirmations } =>
+                format!("👁 TX {} bestätigt ({} Bestätigungen)", short_id(txid), confirmations),
+            WatchEvent::Replaced { txid } =>
+                format!("👁 TX {} nicht mehr im Mempool (ersetzt oder verworfen)", short_id(txid)),
+            WatchEvent::Received { address, amount } =>
+                format!("👁 Adresse {} hat {:.8} BTC empfangen", short_id(address), amount),
+            WatchEvent::BalanceChanged { address, balance } =>
+                format!("👁 Guthaben von {} geändert: {:.8} BTC", short_id(address), balance),
+        }
+    }
+
+    pub fn is_warning(&self) -> bool {
+        matches!(self, WatchEvent::Replaced { .. })
+    }
+}
+
+impl WatchEntry {
+    pub fn new(target: WatchTarget) -> Self {
+        Self {
+            target,
+            tx_status: None,
+            received: None,
+            balance: None,
+            last_error: None,
+        }
+    }
+
+    /// Übernimmt einen neuen TX-Status und meldet relevante Übergänge
+    pub fn apply_tx_status(&mut self, status: TxStatus) -> Option<WatchEvent> {
+        let txid = self.target.id().to_string();
+        let event = match (&self.tx_status, &status) {
+            (Some(TxStatus::Unconfirmed), TxStatus::Confirmed(n))
+            | (Some(TxStatus::Missing), TxStatus::Confirmed(n)) =>
+                Some(WatchEvent::Confirmed { txid, confirmations: *n }),
+            (Some(TxStatus::Unconfirmed), TxStatus::Missing) =>
+                Some(WatchEvent::Replaced { txid }),
+            _ => None,
+        };
+        self.tx_status = Some(status);
+        event
+    }
+
+    /// Übernimmt neue Adresswerte; beim ersten Abruf wird nur der Ausgangswert gesetzt
+    pub fn apply_address_values(&mut self, received: f64, balance: f64) -> Option<WatchEvent> {
+        let address = self.target.id().to_string();
+        let event = match (self.received, self.balance) {
+            (Some(old_received), _) if received > old_received + f64::EPSILON =>
+                Some(WatchEvent::Received { address, amount: received - old_received }),
+            (_, Some(old_balance)) if (balance - old_balance).abs() > f64::EPSILON =>
+                Some(WatchEvent::BalanceChanged { address, balance }),
+            _ => None,
+        };
+        self.received = Some(received);
+        self.balance = Some(balance);
+        event
+    }
+}
+
+pub struct Watchlist {
+    entries: Vec<WatchEntry>,
+    path: PathBuf,
+}
+
+impl Watchlist {
+    /// Lädt die Liste aus WATCHLIST_FILE (Standard: watchlist.txt)
+    pub fn load() -> Self {
+        let path = env::var("WATCHLIST_FILE").unwrap_or_else(|_| DEFAULT_WATCHLIST_FILE.to_string());
+        Self::load_from(PathBuf::from(path))
+    }
+
+    pub fn load_from(path: PathBuf) -> Self {
+        let entries = fs::read_to_string(&path)
+            .map(|content| parse_entries(&content))
+            .unwrap_or_default();
+        Self { entries, path }
+    }
+
+    pub fn entries(&self) -> &[WatchEntry] {
+        &self.entries
+    }
+
+    /// Fügt ein Ziel hinzu; liefert false, wenn es bereits beobachtet wird
+    pub fn add(&mut self, target: WatchTarget) -> Result<bool> {
+        if self.entries.iter().any(|e| e.target == target) {
+            return Ok(false);
+        }
+        self.entries.push(WatchEntry::new(target));
+        self.save()?;
+        Ok(true)
+    }
+
+    pub fn remove(&mut self, index: usize) -> Result<()> {
+        if index < self.entries.len() {
+            self.entries.remove(index);
+            self.save()?;
+        }
+        Ok(())
+    }
+
+    fn save(&self) -> Result<()> {
+        let mut content = String::from("# NodeBridge Watchlist\n");
+        for entry in &self.entries {
+            let prefix = match entry.target {
+                WatchTarget::Transaction(_) => "tx",
+                WatchTarget::Address(_) => "addr",
+            };
+            content.push_str(&format!("{} {}\n", prefix, entry.target.id()));
+        }
+        fs::write(&self.path, content)?;
+        Ok(())
+    }
+
+    /// Prüft alle Einträge gegen Node bzw. Explorer
+    pub fn refresh(&mut self, client: &BitcoinRPC, tip_height: u64) -> Vec<WatchEvent> {
+        let mut events = Vec::new();
+
+        for entry in &mut self.entries {
+            let result = match &entry.target {
+                WatchTarget::Transaction(txid) => client.get_tx_status(txid, tip_height)
+                    .map(|status| entry.apply_tx_status(status)),
+                WatchTarget::Address(address) => client.get_address_details(address)
+                    .map(|details| entry.apply_address_values(details.received, details.balance)),
+            };
+
+            match result {
+                Ok(event) => {
+                    entry.last_error = None;
+                    events.extend(event);
+                }
+                Err(e) => entry.last_error = Some(e.to_string()),
+            }
+        }
+
+        events
+    }
+}
+
+fn parse_entries(content: &str) -> Vec<WatchEntry> {
+    content.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let (kind, id) = line.split_once(' ')?;
+            let target = match kind {
+                "tx" => WatchTarget::Transaction(id.trim().to_string()),
+                "addr" => WatchTarget::Address(id.trim().to_string()),
+                _ => return None,
+            };
+            Some(WatchEntry::new(target))
+        })
+        .collect()
+}
+
+/// Kürzt lange IDs auf Anfang und Ende; zählt Zeichen statt Bytes
+pub fn short_id(id: &str) -> String {
+    let chars: Vec<char> = id.chars().collect();
+    if chars.len() > 16 {
+        let head: String = chars[..8].iter().collect();
+        let tail: String = chars[chars.len() - 6..].iter().collect();
+        format!("{}…{}", head, tail)
+    } else {
+        id.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,81 @@
+use super::*;
+
+const TXID: &str = "b8ba9eb64978b378e7b03e25d14062c10ea844a284d87552c808ab4f4365c958";
+
+#[test]
+fn parse_detects_txid_and_address() {
+    assert_eq!(WatchTarget::parse(TXID), Some(WatchTarget::Transaction(TXID.to_string())));
+    assert_eq!(
+        WatchTarget::parse(" bc1qexample "),
+        Some(WatchTarget::Address("bc1qexample".to_string()))
+    );
+    assert_eq!(WatchTarget::parse(""), None);
+    assert_eq!(WatchTarget::parse("zwei worte"), None);
+}
+
+#[test]
+fn tx_confirmation_is_reported_once() {
+    let mut entry = WatchEntry::new(WatchTarget::Transaction(TXID.to_string()));
+
+    assert_eq!(entry.apply_tx_status(TxStatus::Unconfirmed), None);
+    assert_eq!(
+        entry.apply_tx_status(TxStatus::Confirmed(1)),
+        Some(WatchEvent::Confirmed { txid: TXID.to_string(), confirmations: 1 })
+    );
+    assert_eq!(entry.apply_tx_status(TxStatus::Confirmed(2)), None);
+}
+
+#[test]
+fn tx_leaving_mempool_is_reported_as_replaced() {
+    let mut entry = WatchEntry::new(WatchTarget::Transaction(TXID.to_string()));
+
+    entry.apply_tx_status(TxStatus::Unconfirmed);
+    let event = entry.apply_tx_status(TxStatus::Missing);
+
+    assert_eq!(event, Some(WatchEvent::Replaced { txid: TXID.to_string() }));
+    assert!(event.unwrap().is_warning());
+}
+
+#[test]
+fn address_reports_received_funds_after_baseline() {
+    let mut entry = WatchEntry::new(WatchTarget::Address("bc1qexample".to_string()));
+
+    assert_eq!(entry.apply_address_values(1.0, 0.5), None);
+    assert_eq!(
+        entry.apply_address_values(1.25, 0.75),
+        Some(WatchEvent::Received { address: "bc1qexample".to_string(), amount: 0.25 })
+    );
+    assert_eq!(
+        entry.apply_address_values(1.25, 0.0),
+        Some(WatchEvent::BalanceChanged { address: "bc1qexample".to_string(), balance: 0.0 })
+    );
+}
+
+#[test]
+fn watchlist_is_persisted() {
+    let path = env::temp_dir().join(format!("nodebridge-watchlist-{}.txt", std::process::id()));
+    let _ = fs::remove_file(&path);
+
+    let mut watchlist = Watchlist::load_from(path.clone());
+    assert!(watchlist.add(WatchTarget::Transaction(TXID.to_string())).unwrap());
+    assert!(watchlist.add(WatchTarget::Address("bc1qexample".to_string())).unwrap());
+    assert!(!watchlist.add(WatchTarget::Address("bc1qexample".to_string())).unwrap());
+
+    let reloaded = Watchlist::load_from(path.clone());
+    let targets: Vec<_> = reloaded.entries().iter().map(|e| e.target.clone()).collect();
+    assert_eq!(targets, vec![
+        WatchTarget::Transaction(TXID.to_string()),
+        WatchTarget::Address("bc1qexample".to_string()),
+    ]);
+
+    let _ = fs::remove_file(&path);
+}
+
+#[test]
+fn short_id_handles_multibyte_input() {
+    assert_eq!(short_id(TXID).chars().count(), 15);
+    assert_eq!(short_id("bc1qexample"), "bc1qexample");
+    // Umlaute und Emojis dürfen nicht mitten in einem Zeichen geschnitten werden
+    assert_eq!(short_id("äöüäöüäöüäöüäöüäöü"), "äöüäöüäö…äöüäöü");
+    assert_eq!(short_id("🔑🔑🔑🔑🔑🔑🔑🔑🔑🔑🔑🔑🔑🔑🔑🔑🔑"), "🔑🔑🔑🔑🔑🔑🔑🔑…🔑🔑🔑🔑🔑🔑");
+}
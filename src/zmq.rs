@@ -0,0 +1,234 @@
+use bitcoincore_rpc::bitcoin::{self, consensus};
+use std::collections::{BTreeMap, VecDeque};
+use std::env;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::time::{Duration, Instant};
+use zeromq::{Socket, SocketRecv, SubSocket, ZmqMessage};
+
+const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+// Zeitfenster für den Transaktionszähler
+const RATE_WINDOW: Duration = Duration::from_secs(60);
+
+pub const TOPIC_HASHBLOCK: &str = "hashblock";
+pub const TOPIC_RAWTX: &str = "rawtx";
+pub const TOPIC_SEQUENCE: &str = "sequence";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SequenceEvent {
+    BlockConnected(String),
+    BlockDisconnected(String),
+    TxAdded(String),
+    TxRemoved(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ZmqEvent {
+    Block(String),
+    Transaction(String),
+    Sequence(SequenceEvent),
+    Error(String),
+}
+
+impl ZmqEvent {
+    /// Neue Blöcke (oder getrennte) lösen eine sofortige Aktualisierung aus
+    pub fn is_block_event(&self) -> bool {
+        matches!(
+            self,
+            ZmqEvent::Block(_)
+                | ZmqEvent::Sequence(SequenceEvent::BlockConnected(_))
+                | ZmqEvent::Sequence(SequenceEvent::BlockDisconnected(_))
+        )
+    }
+}
+
+/// Zerlegt eine ZMQ-Nachricht von bitcoind: [Topic, Inhalt, Sequenznummer]
+pub fn parse_message(topic: &[u8], body: &[u8]) -> Option<ZmqEvent> {
+    // Hashes kommen bereits in der von RPC bekannten Byte-Reihenfolge
+    match topic {
+        b"hashblock" if body.len() == 32 => Some(ZmqEvent::Block(hex::encode(body))),
+        b"rawtx" => consensus::deserialize::<bitcoin::Transaction>(body)
+            .ok()
+            .map(|tx| ZmqEvent::Transaction(tx.txid().to_string())),
+        b"sequence" if body.len() >= 33 => {
+            let hash = hex::encode(&body[..32]);
+            let event = match body[32] {
+                b'C' => SequenceEvent::BlockConnected(hash),
+                b'D' => SequenceEvent::BlockDisconnected(hash),
+                b'A' => SequenceEvent::TxAdded(hash),
+                b'R' => SequenceEvent::TxRemoved(hash),
+                _ => return None,
+            };
+            Some(ZmqEvent::Sequence(event))
+        }
+        _ => None,
+    }
+}
+
+/// Endpunkte aus ZMQ_HASHBLOCK, ZMQ_RAWTX und ZMQ_SEQUENCE (entsprechen zmqpub* in bitcoin.conf)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ZmqConfig {
+    // Endpunkt -> abonnierte Topics; mehrere Topics teilen sich oft einen Endpunkt
+    pub subscriptions: BTreeMap<String, Vec<&'static str>>,
+}
+
+impl ZmqConfig {
+    pub fn from_env() -> Option<Self> {
+        let mut config = Self::default();
+        for (var, topic) in [("ZMQ_HASHBLOCK", TOPIC_HASHBLOCK), ("ZMQ_RAWTX", TOPIC_RAWTX), ("ZMQ_SEQUENCE", TOPIC_SEQUENCE)] {
+            if let Some(endpoint) = env::var(var).ok().filter(|e| !e.trim().is_empty()) {
+                config.add(endpoint.trim(), topic);
+            }
+        }
+        (!config.subscriptions.is_empty()).then_some(config)
+    }
+
+    pub fn add(&mut self, endpoint: &str, topic: &'static str) {
+        self.subscriptions.entry(endpoint.to_string()).or_default().push(topic);
+    }
+
+    fn has_topic(&self, topic: &str) -> bool {
+        self.subscriptions.values().any(|topics| topics.contains(&topic))
+    }
+
+    /// Neue Mempool-TXs kommen nur über rawtx oder sequence, nicht über hashblock
+    pub fn counts_transactions(&self) -> bool {
+        self.has_topic(TOPIC_RAWTX) || self.has_topic(TOPIC_SEQUENCE)
+    }
+}
+
+/// Empfängt ZMQ-Nachrichten in einem eigenen Thread
+pub struct ZmqListener {
+    events: Receiver<ZmqEvent>,
+    // Mit sequence werden neue Mempool-TXs darüber gezählt, sonst über rawtx
+    count_sequence: bool,
+    // false, wenn nur hashblock abonniert ist
+    counts_transactions: bool,
+}
+
+impl ZmqListener {
+    pub fn start(config: ZmqConfig) -> Self {
+        let (sender, events) = mpsc::channel();
+        let count_sequence = config.has_topic(TOPIC_SEQUENCE);
+        let counts_transactions = config.counts_transactions();
+
+        std::thread::spawn(move || {
+            let runtime = match tokio::runtime::Builder::new_current_thread().enable_all().build() {
+                Ok(runtime) => runtime,
+                Err(e) => {
+                    let _ = sender.send(ZmqEvent::Error(format!("ZMQ Runtime Fehler: {}", e)));
+                    return;
+                }
+            };
+            runtime.block_on(futures::future::join_all(
+                config.subscriptions.into_iter()
+                    .map(|(endpoint, topics)| subscribe(endpoint, topics, sender.clone()))
+            ));
+        });
+
+        Self { events, count_sequence, counts_transactions }
+    }
+
+    /// Alle seit dem letzten Aufruf eingegangenen Ereignisse
+    pub fn drain(&self) -> Vec<ZmqEvent> {
+        self.events.try_iter().collect()
+    }
+
+    pub fn counts_transactions(&self) -> bool {
+        self.counts_transactions
+    }
+
+    pub fn is_new_mempool_tx(&self, event: &ZmqEvent) -> bool {
+        match event {
+            ZmqEvent::Sequence(SequenceEvent::TxAdded(_)) => self.count_sequence,
+            ZmqEvent::Transaction(_) => !self.count_sequence,
+            _ => false,
+        }
+    }
+}
+
+// Verbindet sich mit einem Endpunkt und verbindet nach Fehlern automatisch neu
+async fn subscribe(endpoint: String, topics: Vec<&'static str>, sender: Sender<ZmqEvent>) {
+    loop {
+        let mut socket = SubSocket::new();
+        let mut result = socket.connect(&endpoint).await;
+        for topic in &topics {
+            if result.is_ok() {
+                result = socket.subscribe(topic).await;
+            }
+        }
+
+        if let Err(e) = result {
+            if sender.send(ZmqEvent::Error(format!("ZMQ {}: {}", endpoint, e))).is_err() {
+                return;
+            }
+            tokio::time::sleep(RECONNECT_DELAY).await;
+            continue;
+        }
+
+        loop {
+            let message: ZmqMessage = match socket.recv().await {
+                Ok(message) => message,
+                Err(e) => {
+                    if sender.send(ZmqEvent::Error(format!("ZMQ {}: {}", endpoint, e))).is_err() {
+                        return;
+                    }
+                    break;
+                }
+            };
+
+            let (Some(topic), Some(body)) = (message.get(0), message.get(1)) else { continue };
+            if let Some(event) = parse_message(topic, body) {
+                // Empfänger weg: UI wurde beendet
+                if sender.send(event).is_err() {
+                    return;
+                }
+            }
+        }
+
+        tokio::time::sleep(RECONNECT_DELAY).await;
+    }
+}
+
+/// Zählt neue Mempool-Transaktionen über ein gleitendes Zeitfenster
+#[derive(Debug, Clone, Default)]
+pub struct TxRateCounter {
+    events: VecDeque<Instant>,
+    started: Option<Instant>,
+}
+
+impl TxRateCounter {
+    /// Startet die Messung, auch wenn noch keine TX eingegangen ist
+    pub fn start(&mut self) {
+        self.started.get_or_insert_with(Instant::now);
+    }
+
+    pub fn record(&mut self) {
+        self.record_at(Instant::now());
+    }
+
+    fn record_at(&mut self, at: Instant) {
+        self.started.get_or_insert(at);
+        self.events.push_back(at);
+    }
+
+    pub fn per_second(&mut self) -> f64 {
+        self.per_second_at(Instant::now())
+    }
+
+    fn per_second_at(&mut self, now: Instant) -> f64 {
+        while self.events.front().is_some_and(|at| now.duration_since(*at) > RATE_WINDOW) {
+            self.events.pop_front();
+        }
+        let Some(started) = self.started else { return 0.0 };
+
+        // Am Anfang nur über die bisher gemessene Zeit mitteln
+        let elapsed = now.duration_since(started).min(RATE_WINDOW).as_secs_f64();
+        if elapsed <= 0.0 {
+            return 0.0;
+        }
+        self.events.len() as f64 / elapsed
+    }
+}
+
+#[cfg(test)]
+mod tests;
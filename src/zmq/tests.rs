@@ -0,0 +1,83 @@
+use super::*;
+use zeromq::{PubSocket, SocketSend};
+
+const BLOCK_HASH: &str = "00000000000000000001a2b3c4d5e6f708192a3b4c5d6e7f8091a2b3c4d5e6f7";
+
+#[test]
+fn parses_hashblock_and_sequence() {
+    let hash = hex::decode(BLOCK_HASH).unwrap();
+    assert_eq!(parse_message(b"hashblock", &hash), Some(ZmqEvent::Block(BLOCK_HASH.to_string())));
+
+    let mut body = hash.clone();
+    body.push(b'C');
+    assert_eq!(
+        parse_message(b"sequence", &body),
+        Some(ZmqEvent::Sequence(SequenceEvent::BlockConnected(BLOCK_HASH.to_string())))
+    );
+
+    let mut body = hash;
+    body.push(b'A');
+    body.extend_from_slice(&42u64.to_le_bytes());
+    assert!(matches!(parse_message(b"sequence", &body), Some(ZmqEvent::Sequence(SequenceEvent::TxAdded(_)))));
+
+    assert_eq!(parse_message(b"hashblock", b"kurz"), None);
+    assert_eq!(parse_message(b"rawtx", b"kein tx"), None);
+}
+
+#[test]
+fn block_events_trigger_refresh() {
+    assert!(ZmqEvent::Block(String::new()).is_block_event());
+    assert!(ZmqEvent::Sequence(SequenceEvent::BlockDisconnected(String::new())).is_block_event());
+    assert!(!ZmqEvent::Sequence(SequenceEvent::TxAdded(String::new())).is_block_event());
+}
+
+#[test]
+fn tx_rate_needs_rawtx_or_sequence() {
+    let mut config = ZmqConfig::default();
+    config.add("tcp://127.0.0.1:28332", TOPIC_HASHBLOCK);
+    assert!(!config.counts_transactions());
+
+    config.add("tcp://127.0.0.1:28333", TOPIC_RAWTX);
+    assert!(config.counts_transactions());
+}
+
+#[test]
+fn tx_rate_over_window() {
+    let start = Instant::now();
+    let mut counter = TxRateCounter::default();
+    assert_eq!(counter.per_second_at(start), 0.0);
+
+    for i in 0..20 {
+        counter.record_at(start + Duration::from_millis(i * 500));
+    }
+    assert_eq!(counter.per_second_at(start + Duration::from_secs(10)), 2.0);
+
+    // Nach Ablauf des Fensters zählen alte TXs nicht mehr
+    assert_eq!(counter.per_second_at(start + Duration::from_secs(120)), 0.0);
+}
+
+#[test]
+fn receives_blocks_from_local_publisher() {
+    let runtime = tokio::runtime::Builder::new_multi_thread().enable_all().build().unwrap();
+    let mut publisher = PubSocket::new();
+    let endpoint = runtime.block_on(publisher.bind("tcp://127.0.0.1:0")).unwrap().to_string();
+
+    let mut config = ZmqConfig::default();
+    config.add(&endpoint, TOPIC_HASHBLOCK);
+    let listener = ZmqListener::start(config);
+
+    // Der Subscriber braucht einen Moment zum Verbinden, daher wiederholt senden
+    let deadline = Instant::now() + Duration::from_secs(10);
+    let mut received = Vec::new();
+    while received.is_empty() && Instant::now() < deadline {
+        let mut message = ZmqMessage::from("hashblock");
+        message.push_back(hex::decode(BLOCK_HASH).unwrap().into());
+        message.push_back(1u32.to_le_bytes().to_vec().into());
+        runtime.block_on(publisher.send(message)).unwrap();
+
+        std::thread::sleep(Duration::from_millis(100));
+        received = listener.drain();
+    }
+
+    assert_eq!(received.first(), Some(&ZmqEvent::Block(BLOCK_HASH.to_string())));
+}